@@ -10,6 +10,22 @@ mod m20250306_000006_extend_outreach_log;
 mod m20250310_000007_contact_trash_and_owner;
 mod m20250320_000008_weekly_activity_tracking;
 mod m20250325_000009_create_email_conversations;
+mod m20250401_000010_startup_trash;
+mod m20250405_000011_custom_fields;
+mod m20250410_000012_create_tags;
+mod m20250415_000013_startup_owner;
+mod m20250420_000014_create_field_revisions;
+mod m20250425_000015_startup_signal_score;
+mod m20250430_000016_create_tasks;
+mod m20250505_000017_task_links;
+mod m20250510_000018_url_health;
+mod m20250515_000019_newsroom_claims;
+mod m20250520_000020_saved_views;
+mod m20250525_000021_search_vectors;
+mod m20250601_000022_contact_identities;
+mod m20250605_000023_intro_requests;
+mod m20250610_000024_contact_trash_retention;
+mod m20250615_000025_owner_rotation;
 
 pub struct Migrator;
 
@@ -27,6 +43,22 @@ impl MigratorTrait for Migrator {
             Box::new(m20250310_000007_contact_trash_and_owner::Migration),
             Box::new(m20250320_000008_weekly_activity_tracking::Migration),
             Box::new(m20250325_000009_create_email_conversations::Migration),
+            Box::new(m20250401_000010_startup_trash::Migration),
+            Box::new(m20250405_000011_custom_fields::Migration),
+            Box::new(m20250410_000012_create_tags::Migration),
+            Box::new(m20250415_000013_startup_owner::Migration),
+            Box::new(m20250420_000014_create_field_revisions::Migration),
+            Box::new(m20250425_000015_startup_signal_score::Migration),
+            Box::new(m20250430_000016_create_tasks::Migration),
+            Box::new(m20250505_000017_task_links::Migration),
+            Box::new(m20250510_000018_url_health::Migration),
+            Box::new(m20250515_000019_newsroom_claims::Migration),
+            Box::new(m20250520_000020_saved_views::Migration),
+            Box::new(m20250525_000021_search_vectors::Migration),
+            Box::new(m20250601_000022_contact_identities::Migration),
+            Box::new(m20250605_000023_intro_requests::Migration),
+            Box::new(m20250610_000024_contact_trash_retention::Migration),
+            Box::new(m20250615_000025_owner_rotation::Migration),
         ]
     }
 }
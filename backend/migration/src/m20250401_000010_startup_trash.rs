@@ -0,0 +1,62 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .add_column_if_not_exists(boolean(Startup::IsTrashed).default(false))
+                    .add_column_if_not_exists(timestamp_null(Startup::TrashedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_startup_is_trashed")
+                    .table(Startup::Table)
+                    .col(Startup::IsTrashed)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_startup_is_trashed")
+                    .table(Startup::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .drop_column(Startup::TrashedAt)
+                    .drop_column(Startup::IsTrashed)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Startup {
+    Table,
+    IsTrashed,
+    TrashedAt,
+}
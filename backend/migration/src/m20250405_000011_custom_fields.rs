@@ -0,0 +1,139 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomFieldDefinition::Table)
+                    .if_not_exists()
+                    .col(uuid(CustomFieldDefinition::Id).primary_key())
+                    .col(string(CustomFieldDefinition::EntityType))
+                    .col(string(CustomFieldDefinition::Key))
+                    .col(string(CustomFieldDefinition::Label))
+                    .col(string(CustomFieldDefinition::FieldType))
+                    .col(json_binary_null(CustomFieldDefinition::Options))
+                    .col(boolean(CustomFieldDefinition::IsRequired).default(false))
+                    .col(integer(CustomFieldDefinition::SortOrder).default(0))
+                    .col(timestamp(CustomFieldDefinition::CreatedAt))
+                    .col(timestamp(CustomFieldDefinition::UpdatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_custom_field_definition_entity_key")
+                    .table(CustomFieldDefinition::Table)
+                    .col(CustomFieldDefinition::EntityType)
+                    .col(CustomFieldDefinition::Key)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .add_column_if_not_exists(
+                        json_binary(Startup::CustomFields).default(Expr::cust("'{}'::jsonb")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Contact::Table)
+                    .add_column_if_not_exists(
+                        json_binary(Contact::CustomFields).default(Expr::cust("'{}'::jsonb")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // GIN indexes back the `@>` containment filters on the list endpoints.
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_startup_custom_fields ON startup USING GIN (custom_fields)",
+        )
+        .await?;
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_contact_custom_fields ON contact USING GIN (custom_fields)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_contact_custom_fields")
+            .await?;
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_startup_custom_fields")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Contact::Table)
+                    .drop_column(Contact::CustomFields)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .drop_column(Startup::CustomFields)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(CustomFieldDefinition::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustomFieldDefinition {
+    Table,
+    Id,
+    EntityType,
+    Key,
+    Label,
+    FieldType,
+    Options,
+    IsRequired,
+    SortOrder,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Startup {
+    Table,
+    CustomFields,
+}
+
+#[derive(DeriveIden)]
+enum Contact {
+    Table,
+    CustomFields,
+}
@@ -0,0 +1,122 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tag::Table)
+                    .if_not_exists()
+                    .col(uuid(Tag::Id).primary_key())
+                    .col(string(Tag::Name))
+                    .col(string_null(Tag::Color))
+                    .col(timestamp(Tag::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Tag names are unique regardless of case.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_tag_name_lower ON tag (LOWER(name))",
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TagAssignment::Table)
+                    .if_not_exists()
+                    .col(uuid(TagAssignment::Id).primary_key())
+                    .col(uuid(TagAssignment::TagId))
+                    .col(string(TagAssignment::EntityType))
+                    .col(uuid(TagAssignment::EntityId))
+                    .col(uuid_null(TagAssignment::CreatedBy))
+                    .col(timestamp(TagAssignment::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TagAssignment::Table, TagAssignment::TagId)
+                            .to(Tag::Table, Tag::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TagAssignment::Table, TagAssignment::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_tag_assignment_unique")
+                    .table(TagAssignment::Table)
+                    .col(TagAssignment::TagId)
+                    .col(TagAssignment::EntityType)
+                    .col(TagAssignment::EntityId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_tag_assignment_entity")
+                    .table(TagAssignment::Table)
+                    .col(TagAssignment::EntityType)
+                    .col(TagAssignment::EntityId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TagAssignment::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Tag::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Tag {
+    Table,
+    Id,
+    Name,
+    Color,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum TagAssignment {
+    Table,
+    Id,
+    TagId,
+    EntityType,
+    EntityId,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,88 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .add_column_if_not_exists(uuid_null(Startup::OwnerId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-startup-owner")
+                            .from_tbl(Startup::Table)
+                            .from_col(Startup::OwnerId)
+                            .to_tbl(Users::Table)
+                            .to_col(Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_startup_owner_id")
+                    .table(Startup::Table)
+                    .col(Startup::OwnerId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Seed owners from the contacts, preferring the primary contact's owner.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"UPDATE startup SET owner_id = (
+                    SELECT contact.owner_id FROM contact
+                    WHERE contact.startup_id = startup.id AND contact.owner_id IS NOT NULL
+                    ORDER BY contact.is_primary DESC, contact.is_trashed ASC
+                    LIMIT 1
+                ) WHERE owner_id IS NULL"#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_startup_owner_id")
+                    .table(Startup::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .drop_foreign_key(Alias::new("fk-startup-owner"))
+                    .drop_column(Startup::OwnerId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Startup {
+    Table,
+    OwnerId,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,101 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FieldRevision::Table)
+                    .if_not_exists()
+                    .col(uuid(FieldRevision::Id).primary_key())
+                    .col(string(FieldRevision::EntityType))
+                    .col(uuid(FieldRevision::EntityId))
+                    .col(uuid_null(FieldRevision::StartupId))
+                    .col(string(FieldRevision::Field))
+                    .col(json_binary_null(FieldRevision::OldValue))
+                    .col(json_binary_null(FieldRevision::NewValue))
+                    .col(uuid_null(FieldRevision::UserId))
+                    .col(string_null(FieldRevision::UserName))
+                    .col(timestamp(FieldRevision::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(FieldRevision::Table, FieldRevision::StartupId)
+                            .to(Startup::Table, Startup::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(FieldRevision::Table, FieldRevision::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_field_revision_startup")
+                    .table(FieldRevision::Table)
+                    .col(FieldRevision::StartupId)
+                    .col(FieldRevision::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_field_revision_entity")
+                    .table(FieldRevision::Table)
+                    .col(FieldRevision::EntityType)
+                    .col(FieldRevision::EntityId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FieldRevision::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum FieldRevision {
+    Table,
+    Id,
+    EntityType,
+    EntityId,
+    StartupId,
+    Field,
+    OldValue,
+    NewValue,
+    UserId,
+    UserName,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Startup {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .add_column_if_not_exists(integer(Startup::SignalScore).default(0))
+                    .add_column_if_not_exists(
+                        json_binary(Startup::SignalBreakdown).default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_startup_signal_score")
+                    .table(Startup::Table)
+                    .col(Startup::SignalScore)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_startup_signal_score")
+                    .table(Startup::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .drop_column(Startup::SignalBreakdown)
+                    .drop_column(Startup::SignalScore)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Startup {
+    Table,
+    SignalScore,
+    SignalBreakdown,
+}
@@ -0,0 +1,119 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Task::Table)
+                    .if_not_exists()
+                    .col(uuid(Task::Id).primary_key())
+                    .col(string(Task::Title))
+                    .col(date(Task::DueDate))
+                    .col(uuid_null(Task::AssigneeId))
+                    .col(string(Task::Status).default("open"))
+                    .col(string(Task::Source).default("manual"))
+                    .col(uuid_null(Task::StartupId))
+                    .col(uuid_null(Task::ContactId))
+                    .col(timestamp_null(Task::CompletedAt))
+                    .col(timestamp(Task::CreatedAt))
+                    .col(timestamp(Task::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-task-assignee")
+                            .from(Task::Table, Task::AssigneeId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-task-startup")
+                            .from(Task::Table, Task::StartupId)
+                            .to(Startup::Table, Startup::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-task-contact")
+                            .from(Task::Table, Task::ContactId)
+                            .to(Contact::Table, Contact::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_task_assignee_status_due")
+                    .table(Task::Table)
+                    .col(Task::AssigneeId)
+                    .col(Task::Status)
+                    .col(Task::DueDate)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_task_startup_status")
+                    .table(Task::Table)
+                    .col(Task::StartupId)
+                    .col(Task::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Task::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Task {
+    Table,
+    Id,
+    Title,
+    DueDate,
+    AssigneeId,
+    Status,
+    Source,
+    StartupId,
+    ContactId,
+    CompletedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Startup {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Contact {
+    Table,
+    Id,
+}
@@ -0,0 +1,93 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .add_column_if_not_exists(uuid_null(Task::InterviewId))
+                    .add_column_if_not_exists(uuid_null(Task::ConversationId))
+                    .add_column_if_not_exists(uuid_null(Task::CreatedById))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-task-interview")
+                            .from_tbl(Task::Table)
+                            .from_col(Task::InterviewId)
+                            .to_tbl(Interview::Table)
+                            .to_col(Interview::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-task-conversation")
+                            .from_tbl(Task::Table)
+                            .from_col(Task::ConversationId)
+                            .to_tbl(Conversations::Table)
+                            .to_col(Conversations::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-task-created-by")
+                            .from_tbl(Task::Table)
+                            .from_col(Task::CreatedById)
+                            .to_tbl(Users::Table)
+                            .to_col(Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .drop_foreign_key(Alias::new("fk-task-interview"))
+                    .drop_foreign_key(Alias::new("fk-task-conversation"))
+                    .drop_foreign_key(Alias::new("fk-task-created-by"))
+                    .drop_column(Task::InterviewId)
+                    .drop_column(Task::ConversationId)
+                    .drop_column(Task::CreatedById)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Task {
+    Table,
+    InterviewId,
+    ConversationId,
+    CreatedById,
+}
+
+#[derive(DeriveIden)]
+enum Interview {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Conversations {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
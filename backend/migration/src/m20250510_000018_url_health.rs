@@ -0,0 +1,105 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UrlCheck::Table)
+                    .if_not_exists()
+                    .col(uuid(UrlCheck::Id).primary_key())
+                    .col(uuid(UrlCheck::StartupId))
+                    .col(string(UrlCheck::Kind))
+                    .col(string(UrlCheck::Url))
+                    .col(integer_null(UrlCheck::StatusCode))
+                    .col(integer_null(UrlCheck::LatencyMs))
+                    .col(string_null(UrlCheck::RedirectUrl))
+                    .col(string_null(UrlCheck::Error))
+                    .col(boolean(UrlCheck::IsBroken).default(false))
+                    .col(integer(UrlCheck::ConsecutiveFailures).default(0))
+                    .col(timestamp(UrlCheck::CheckedAt))
+                    .col(timestamp_null(UrlCheck::LastOkAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-url-check-startup")
+                            .from(UrlCheck::Table, UrlCheck::StartupId)
+                            .to(Startup::Table, Startup::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_url_check_startup_kind")
+                    .table(UrlCheck::Table)
+                    .col(UrlCheck::StartupId)
+                    .col(UrlCheck::Kind)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .add_column_if_not_exists(boolean(Startup::WebsiteBroken).default(false))
+                    .add_column_if_not_exists(boolean(Startup::NewsroomUrlBroken).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .drop_column(Startup::NewsroomUrlBroken)
+                    .drop_column(Startup::WebsiteBroken)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UrlCheck::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum UrlCheck {
+    Table,
+    Id,
+    StartupId,
+    Kind,
+    Url,
+    StatusCode,
+    LatencyMs,
+    RedirectUrl,
+    Error,
+    IsBroken,
+    ConsecutiveFailures,
+    CheckedAt,
+    LastOkAt,
+}
+
+#[derive(DeriveIden)]
+enum Startup {
+    Table,
+    Id,
+    WebsiteBroken,
+    NewsroomUrlBroken,
+}
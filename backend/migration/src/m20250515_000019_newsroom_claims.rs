@@ -0,0 +1,134 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NewsroomClaim::Table)
+                    .if_not_exists()
+                    .col(uuid(NewsroomClaim::Id).primary_key())
+                    .col(uuid(NewsroomClaim::StartupId))
+                    // Kept when the contact is deleted so the claim stays on record.
+                    .col(uuid_null(NewsroomClaim::ContactId))
+                    .col(string(NewsroomClaim::Email))
+                    .col(uuid_null(NewsroomClaim::CreatedById))
+                    .col(string_null(NewsroomClaim::MessageId))
+                    .col(timestamp(NewsroomClaim::ExpiresAt))
+                    .col(timestamp_null(NewsroomClaim::ClaimedAt))
+                    .col(timestamp(NewsroomClaim::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-newsroom-claim-startup")
+                            .from(NewsroomClaim::Table, NewsroomClaim::StartupId)
+                            .to(Startup::Table, Startup::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-newsroom-claim-contact")
+                            .from(NewsroomClaim::Table, NewsroomClaim::ContactId)
+                            .to(Contact::Table, Contact::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-newsroom-claim-created-by")
+                            .from(NewsroomClaim::Table, NewsroomClaim::CreatedById)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_newsroom_claim_startup")
+                    .table(NewsroomClaim::Table)
+                    .col(NewsroomClaim::StartupId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .add_column_if_not_exists(timestamp_null(Startup::ClaimedAt))
+                    .add_column_if_not_exists(uuid_null(Startup::ClaimedByContactId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-startup-claimed-by-contact")
+                            .from_tbl(Startup::Table)
+                            .from_col(Startup::ClaimedByContactId)
+                            .to_tbl(Contact::Table)
+                            .to_col(Contact::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Startup::Table)
+                    .drop_foreign_key(Alias::new("fk-startup-claimed-by-contact"))
+                    .drop_column(Startup::ClaimedByContactId)
+                    .drop_column(Startup::ClaimedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(NewsroomClaim::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum NewsroomClaim {
+    Table,
+    Id,
+    StartupId,
+    ContactId,
+    Email,
+    CreatedById,
+    MessageId,
+    ExpiresAt,
+    ClaimedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Startup {
+    Table,
+    Id,
+    ClaimedAt,
+    ClaimedByContactId,
+}
+
+#[derive(DeriveIden)]
+enum Contact {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,76 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SavedView::Table)
+                    .if_not_exists()
+                    .col(uuid(SavedView::Id).primary_key())
+                    .col(uuid(SavedView::UserId))
+                    .col(string(SavedView::Name))
+                    .col(string(SavedView::EntityType))
+                    .col(json_binary(SavedView::Filters).default(Expr::cust("'{}'::jsonb")))
+                    .col(boolean(SavedView::IsShared).default(false))
+                    .col(timestamp(SavedView::CreatedAt))
+                    .col(timestamp(SavedView::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-saved-view-user")
+                            .from(SavedView::Table, SavedView::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_saved_view_user_entity_name")
+                    .table(SavedView::Table)
+                    .col(SavedView::UserId)
+                    .col(SavedView::EntityType)
+                    .col(SavedView::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SavedView::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SavedView {
+    Table,
+    Id,
+    UserId,
+    Name,
+    EntityType,
+    Filters,
+    IsShared,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,80 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// `(table, tsvector expression)` for each searchable table. The columns are
+/// generated, so they stay current without triggers or application code.
+/// Message bodies are capped at 100k characters so a huge email cannot push
+/// its tsvector past Postgres's 1MB limit and fail the insert.
+const SEARCH_VECTORS: &[(&str, &str)] = &[
+    (
+        "startup",
+        "setweight(to_tsvector('english', coalesce(name, '')), 'A') || \
+         setweight(to_tsvector('english', coalesce(website, '')), 'B') || \
+         setweight(to_tsvector('english', coalesce(newsroom_url, '')), 'C') || \
+         setweight(to_tsvector('english', coalesce(category, '')), 'C')",
+    ),
+    (
+        "contact",
+        "setweight(to_tsvector('english', coalesce(name, '')), 'A') || \
+         setweight(to_tsvector('english', coalesce(email, '')), 'A') || \
+         setweight(to_tsvector('english', coalesce(role, '')), 'C') || \
+         setweight(to_tsvector('english', coalesce(notes, '')), 'D')",
+    ),
+    (
+        "messages",
+        "setweight(to_tsvector('english', coalesce(subject, '')), 'A') || \
+         setweight(to_tsvector('english', coalesce(sender_name, '') || ' ' || sender_email), 'B') || \
+         setweight(to_tsvector('english', left(coalesce(body_text, ''), 100000)), 'D')",
+    ),
+    (
+        "interview",
+        "to_tsvector('english', coalesce(summary, ''))",
+    ),
+    (
+        "interview_insight",
+        "setweight(to_tsvector('english', coalesce(current_workflow, '') || ' ' || \
+         coalesce(jtbd_functional, '') || ' ' || coalesce(jtbd_social, '') || ' ' || \
+         coalesce(jtbd_emotional, '')), 'B') || \
+         setweight(to_tsvector('english', coalesce(biggest_pains::text, '') || ' ' || \
+         coalesce(desired_outcomes::text, '') || ' ' || coalesce(main_objections::text, '')), 'B') || \
+         setweight(to_tsvector('english', coalesce(excited_features::text, '') || ' ' || \
+         coalesce(ignored_features::text, '') || ' ' || coalesce(real_owner_role, '') || ' ' || \
+         coalesce(willing_to_use_monthly, '')), 'C')",
+    ),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for (table, expression) in SEARCH_VECTORS {
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} ADD COLUMN IF NOT EXISTS search_vector tsvector \
+                 GENERATED ALWAYS AS ({expression}) STORED"
+            ))
+            .await?;
+            db.execute_unprepared(&format!(
+                "CREATE INDEX IF NOT EXISTS idx_{table}_search_vector ON {table} USING GIN (search_vector)"
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for (table, _) in SEARCH_VECTORS {
+            db.execute_unprepared(&format!("DROP INDEX IF EXISTS idx_{table}_search_vector"))
+                .await?;
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} DROP COLUMN IF EXISTS search_vector"
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+}
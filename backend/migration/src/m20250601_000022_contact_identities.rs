@@ -0,0 +1,107 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ContactIdentity::Table)
+                    .if_not_exists()
+                    .col(uuid(ContactIdentity::Id).primary_key())
+                    .col(uuid(ContactIdentity::ContactId))
+                    .col(string(ContactIdentity::Kind))
+                    .col(string(ContactIdentity::Value))
+                    .col(string(ContactIdentity::NormalizedValue))
+                    .col(string_null(ContactIdentity::Label))
+                    .col(boolean(ContactIdentity::IsPrimary).default(false))
+                    .col(timestamp(ContactIdentity::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-contact-identity-contact")
+                            .from(ContactIdentity::Table, ContactIdentity::ContactId)
+                            .to(Contact::Table, Contact::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_contact_identity_contact_kind_value")
+                    .table(ContactIdentity::Table)
+                    .col(ContactIdentity::ContactId)
+                    .col(ContactIdentity::Kind)
+                    .col(ContactIdentity::NormalizedValue)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_contact_identity_kind_value")
+                    .table(ContactIdentity::Table)
+                    .col(ContactIdentity::Kind)
+                    .col(ContactIdentity::NormalizedValue)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing emails and phone numbers become each contact's primary
+        // identities.
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "INSERT INTO contact_identity (id, contact_id, kind, value, normalized_value, is_primary, created_at) \
+             SELECT gen_random_uuid(), id, 'email', trim(email), lower(trim(email)), true, now() \
+             FROM contact WHERE email IS NOT NULL AND position('@' IN email) > 0",
+        )
+        .await?;
+        db.execute_unprepared(
+            "INSERT INTO contact_identity (id, contact_id, kind, value, normalized_value, is_primary, created_at) \
+             SELECT gen_random_uuid(), id, 'phone', trim(phone), \
+                 regexp_replace(regexp_replace(replace(phone, '(0)', ''), '[^0-9]', '', 'g'), '^00', ''), true, now() \
+             FROM contact \
+             WHERE phone IS NOT NULL \
+                 AND length(regexp_replace(regexp_replace(replace(phone, '(0)', ''), '[^0-9]', '', 'g'), '^00', '')) >= 7",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ContactIdentity::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum ContactIdentity {
+    Table,
+    Id,
+    ContactId,
+    Kind,
+    Value,
+    NormalizedValue,
+    Label,
+    IsPrimary,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Contact {
+    Table,
+    Id,
+}
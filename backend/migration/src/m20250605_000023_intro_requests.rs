@@ -0,0 +1,185 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IntroRequest::Table)
+                    .if_not_exists()
+                    .col(uuid(IntroRequest::Id).primary_key())
+                    .col(uuid(IntroRequest::StartupId))
+                    .col(uuid_null(IntroRequest::RequesterContactId))
+                    .col(uuid_null(IntroRequest::TargetContactId))
+                    .col(string_null(IntroRequest::TargetRole))
+                    .col(string(IntroRequest::Status).default("requested"))
+                    .col(text_null(IntroRequest::Notes))
+                    .col(string_null(IntroRequest::IntroducedEmail))
+                    .col(uuid_null(IntroRequest::ConversationId))
+                    .col(uuid_null(IntroRequest::RequestedById))
+                    .col(timestamp(IntroRequest::RequestedAt))
+                    .col(timestamp_null(IntroRequest::MadeAt))
+                    .col(timestamp(IntroRequest::CreatedAt))
+                    .col(timestamp(IntroRequest::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-intro-request-startup")
+                            .from(IntroRequest::Table, IntroRequest::StartupId)
+                            .to(Startup::Table, Startup::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-intro-request-requester")
+                            .from(IntroRequest::Table, IntroRequest::RequesterContactId)
+                            .to(Contact::Table, Contact::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-intro-request-target")
+                            .from(IntroRequest::Table, IntroRequest::TargetContactId)
+                            .to(Contact::Table, Contact::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-intro-request-conversation")
+                            .from(IntroRequest::Table, IntroRequest::ConversationId)
+                            .to(Conversations::Table, Conversations::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-intro-request-requested-by")
+                            .from(IntroRequest::Table, IntroRequest::RequestedById)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_intro_request_startup_status")
+                    .table(IntroRequest::Table)
+                    .col(IntroRequest::StartupId)
+                    .col(IntroRequest::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(IntroRequestOutreach::Table)
+                    .if_not_exists()
+                    .col(uuid(IntroRequestOutreach::IntroRequestId))
+                    .col(uuid(IntroRequestOutreach::OutreachLogId))
+                    .primary_key(
+                        Index::create()
+                            .col(IntroRequestOutreach::IntroRequestId)
+                            .col(IntroRequestOutreach::OutreachLogId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-intro-request-outreach-intro")
+                            .from(
+                                IntroRequestOutreach::Table,
+                                IntroRequestOutreach::IntroRequestId,
+                            )
+                            .to(IntroRequest::Table, IntroRequest::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-intro-request-outreach-log")
+                            .from(
+                                IntroRequestOutreach::Table,
+                                IntroRequestOutreach::OutreachLogId,
+                            )
+                            .to(OutreachLog::Table, OutreachLog::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(IntroRequestOutreach::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(IntroRequest::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum IntroRequest {
+    Table,
+    Id,
+    StartupId,
+    RequesterContactId,
+    TargetContactId,
+    TargetRole,
+    Status,
+    Notes,
+    IntroducedEmail,
+    ConversationId,
+    RequestedById,
+    RequestedAt,
+    MadeAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum IntroRequestOutreach {
+    #[sea_orm(iden = "intro_request_outreach_log")]
+    Table,
+    IntroRequestId,
+    OutreachLogId,
+}
+
+#[derive(DeriveIden)]
+enum Startup {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Contact {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Conversations {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum OutreachLog {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
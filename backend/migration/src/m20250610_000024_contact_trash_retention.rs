@@ -0,0 +1,119 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Contact::Table)
+                    .add_column_if_not_exists(timestamp_null(Contact::TrashedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // When contacts were trashed was never recorded; start their
+        // retention window now rather than purging them on the first run.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE contact SET trashed_at = now() WHERE is_trashed AND trashed_at IS NULL",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_contact_trashed_at")
+                    .table(Contact::Table)
+                    .col(Contact::TrashedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ContactPurgeRun::Table)
+                    .if_not_exists()
+                    .col(uuid(ContactPurgeRun::Id).primary_key())
+                    .col(string(ContactPurgeRun::Trigger))
+                    .col(uuid_null(ContactPurgeRun::TriggeredById))
+                    .col(integer(ContactPurgeRun::RetentionDays))
+                    .col(timestamp(ContactPurgeRun::Cutoff))
+                    .col(integer(ContactPurgeRun::PurgedCount).default(0))
+                    .col(json_binary(ContactPurgeRun::Contacts))
+                    .col(text_null(ContactPurgeRun::Error))
+                    .col(timestamp(ContactPurgeRun::StartedAt))
+                    .col(timestamp(ContactPurgeRun::FinishedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-contact-purge-run-triggered-by")
+                            .from(ContactPurgeRun::Table, ContactPurgeRun::TriggeredById)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ContactPurgeRun::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_contact_trashed_at")
+                    .table(Contact::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Contact::Table)
+                    .drop_column(Contact::TrashedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Contact {
+    Table,
+    TrashedAt,
+}
+
+#[derive(DeriveIden)]
+enum ContactPurgeRun {
+    Table,
+    Id,
+    Trigger,
+    TriggeredById,
+    RetentionDays,
+    Cutoff,
+    PurgedCount,
+    Contacts,
+    Error,
+    StartedAt,
+    FinishedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
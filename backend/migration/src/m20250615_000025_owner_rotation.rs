@@ -0,0 +1,65 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OwnerRotation::Table)
+                    .if_not_exists()
+                    .col(small_integer(OwnerRotation::Id).primary_key())
+                    .col(uuid_null(OwnerRotation::LastOwnerId))
+                    .col(timestamp(OwnerRotation::UpdatedAt))
+                    .check(Expr::col(OwnerRotation::Id).eq(1))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-owner-rotation-last-owner")
+                            .from(OwnerRotation::Table, OwnerRotation::LastOwnerId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Continue the rotation from the owner of the newest startup, the
+        // best guess available before the cursor was stored.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO owner_rotation (id, last_owner_id, updated_at) \
+                 SELECT 1, (SELECT owner_id FROM startup WHERE owner_id IS NOT NULL \
+                 ORDER BY created_at DESC LIMIT 1), now() \
+                 ON CONFLICT (id) DO NOTHING",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OwnerRotation::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum OwnerRotation {
+    Table,
+    Id,
+    LastOwnerId,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
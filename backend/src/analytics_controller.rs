@@ -0,0 +1,442 @@
+use crate::auth::middleware::AuthUser;
+use crate::entities::{activity_event, startup};
+use crate::pipeline::{PipelineStage, ALL_STAGES};
+use crate::{non_empty, normalize_to_week_start, parse_date_str, AppState, ACTIVITY_STAGE_MOVED};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+const DEFAULT_RANGE_DAYS: i64 = 90;
+
+#[derive(Deserialize, Default)]
+pub struct FunnelQuery {
+    /// Inclusive `YYYY-MM-DD` bounds; defaults to the last 90 days.
+    pub from: Option<String>,
+    pub to: Option<String>,
+    /// `week` or `category`.
+    pub cohort: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CohortBy {
+    Week,
+    Category,
+}
+
+/// One continuous period a startup spent in a stage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageStay {
+    pub stage: PipelineStage,
+    pub entered_at: NaiveDateTime,
+    pub exited_at: Option<NaiveDateTime>,
+    pub next: Option<PipelineStage>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageMove {
+    pub from: PipelineStage,
+    pub to: PipelineStage,
+    pub occurred_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct TransitionMetrics {
+    pub to: PipelineStage,
+    pub count: u64,
+    /// Share of startups that entered the stage in range and moved here next.
+    pub rate: f64,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct StageMetrics {
+    pub stage: PipelineStage,
+    pub entered: u64,
+    /// Moved on to a later stage.
+    pub advanced: u64,
+    /// Moved to Closed Lost or Not a Fit.
+    pub dropped_off: u64,
+    /// Moved back to an earlier stage.
+    pub moved_back: u64,
+    pub still_in_stage: u64,
+    pub conversion_rate: f64,
+    /// Time in stage for stays that have ended, in days.
+    pub median_days: Option<f64>,
+    pub p90_days: Option<f64>,
+    pub transitions: Vec<TransitionMetrics>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StageCount {
+    pub stage: PipelineStage,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CohortMetrics {
+    /// Week start (`YYYY-MM-DD`) or category name.
+    pub cohort: String,
+    pub startups: u64,
+    /// How many startups in the cohort ever reached each stage.
+    pub reached: Vec<StageCount>,
+    pub dropped_off: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FunnelResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub stages: Vec<StageMetrics>,
+    pub cohorts: Vec<CohortMetrics>,
+}
+
+fn is_drop_off(stage: PipelineStage) -> bool {
+    matches!(stage, PipelineStage::ClosedLost | PipelineStage::NotAFit)
+}
+
+/// Rebuild the stages a startup went through from its stage-move events,
+/// oldest first. The first stay starts at creation in the stage the first
+/// move left from, or the current stage when it never moved.
+pub fn build_stays(
+    created_at: NaiveDateTime,
+    current: PipelineStage,
+    moves: &[StageMove],
+) -> Vec<StageStay> {
+    let mut stays = vec![StageStay {
+        stage: moves.first().map(|first| first.from).unwrap_or(current),
+        entered_at: created_at,
+        exited_at: None,
+        next: None,
+    }];
+
+    for stage_move in moves {
+        if let Some(open) = stays.last_mut() {
+            open.exited_at = Some(stage_move.occurred_at);
+            open.next = Some(stage_move.to);
+        }
+        stays.push(StageStay {
+            stage: stage_move.to,
+            entered_at: stage_move.occurred_at,
+            exited_at: None,
+            next: None,
+        });
+    }
+
+    stays
+}
+
+/// Nearest-rank percentile of already sorted values.
+fn percentile(sorted: &[f64], pct: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+fn ratio(count: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+/// Per-stage funnel numbers for stays entered between `from` and `to`.
+pub fn stage_metrics(
+    stays: &[StageStay],
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+) -> Vec<StageMetrics> {
+    ALL_STAGES
+        .iter()
+        .map(|&stage| {
+            let in_range: Vec<&StageStay> = stays
+                .iter()
+                .filter(|stay| {
+                    stay.stage == stage && stay.entered_at >= from && stay.entered_at <= to
+                })
+                .collect();
+
+            let mut metrics = StageMetrics {
+                stage,
+                entered: in_range.len() as u64,
+                advanced: 0,
+                dropped_off: 0,
+                moved_back: 0,
+                still_in_stage: 0,
+                conversion_rate: 0.0,
+                median_days: None,
+                p90_days: None,
+                transitions: Vec::new(),
+            };
+            let mut next_counts: BTreeMap<usize, (PipelineStage, u64)> = BTreeMap::new();
+            let mut durations = Vec::new();
+
+            for stay in &in_range {
+                let (Some(next), Some(exited_at)) = (stay.next, stay.exited_at) else {
+                    metrics.still_in_stage += 1;
+                    continue;
+                };
+                if is_drop_off(next) {
+                    metrics.dropped_off += 1;
+                } else if next.position() > stage.position() {
+                    metrics.advanced += 1;
+                } else {
+                    metrics.moved_back += 1;
+                }
+                next_counts.entry(next.position()).or_insert((next, 0)).1 += 1;
+                durations.push((exited_at - stay.entered_at).num_seconds() as f64 / 86_400.0);
+            }
+
+            durations.sort_by(f64::total_cmp);
+            metrics.median_days = percentile(&durations, 50.0);
+            metrics.p90_days = percentile(&durations, 90.0);
+            metrics.conversion_rate = ratio(metrics.advanced, metrics.entered);
+            metrics.transitions = next_counts
+                .into_values()
+                .map(|(to, count)| TransitionMetrics {
+                    to,
+                    count,
+                    rate: ratio(count, metrics.entered),
+                })
+                .collect();
+            metrics
+        })
+        .collect()
+}
+
+struct StartupTimeline {
+    cohort_key: String,
+    created_at: NaiveDateTime,
+    stays: Vec<StageStay>,
+}
+
+fn cohort_metrics(
+    timelines: &[StartupTimeline],
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+) -> Vec<CohortMetrics> {
+    let mut cohorts: BTreeMap<&str, Vec<&StartupTimeline>> = BTreeMap::new();
+    for timeline in timelines
+        .iter()
+        .filter(|timeline| timeline.created_at >= from && timeline.created_at <= to)
+    {
+        cohorts
+            .entry(timeline.cohort_key.as_str())
+            .or_default()
+            .push(timeline);
+    }
+
+    cohorts
+        .into_iter()
+        .map(|(cohort, members)| CohortMetrics {
+            cohort: cohort.to_string(),
+            startups: members.len() as u64,
+            reached: ALL_STAGES
+                .iter()
+                .map(|&stage| StageCount {
+                    stage,
+                    count: members
+                        .iter()
+                        .filter(|timeline| timeline.stays.iter().any(|stay| stay.stage == stage))
+                        .count() as u64,
+                })
+                .collect(),
+            dropped_off: members
+                .iter()
+                .filter(|timeline| {
+                    timeline
+                        .stays
+                        .last()
+                        .is_some_and(|stay| is_drop_off(stay.stage))
+                })
+                .count() as u64,
+        })
+        .collect()
+}
+
+/// GET /api/analytics/funnel
+/// Stage conversion, time in stage and drop-off computed from `stage_moved`
+/// events. Trashed startups are left out; events naming a stage that no
+/// longer exists are skipped.
+pub async fn get_pipeline_funnel(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    query: Option<Query<FunnelQuery>>,
+) -> Result<Json<FunnelResponse>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    let today = Utc::now().date_naive();
+    let to = match non_empty(params.to.as_deref()) {
+        Some(value) => parse_date_str(value)?,
+        None => today,
+    };
+    let from = match non_empty(params.from.as_deref()) {
+        Some(value) => parse_date_str(value)?,
+        None => to - Duration::days(DEFAULT_RANGE_DAYS),
+    };
+    if from > to {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let cohort_by = match non_empty(params.cohort.as_deref()) {
+        None => None,
+        Some("week") => Some(CohortBy::Week),
+        Some("category") => Some(CohortBy::Category),
+        Some(_) => return Err(StatusCode::BAD_REQUEST),
+    };
+    let range_start = from.and_hms_opt(0, 0, 0).unwrap();
+    let range_end = to.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap();
+
+    let startups = startup::Entity::find()
+        .filter(startup::Column::IsTrashed.eq(false))
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let events = activity_event::Entity::find()
+        .filter(activity_event::Column::ActivityType.eq(ACTIVITY_STAGE_MOVED))
+        .filter(activity_event::Column::StartupId.is_not_null())
+        .order_by_asc(activity_event::Column::OccurredAt)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut moves: HashMap<Uuid, Vec<StageMove>> = HashMap::new();
+    for event in events {
+        let parsed = (
+            event.startup_id,
+            event.stage_from.as_deref().and_then(PipelineStage::parse),
+            event.stage_to.as_deref().and_then(PipelineStage::parse),
+        );
+        if let (Some(startup_id), Some(from), Some(to)) = parsed {
+            moves.entry(startup_id).or_default().push(StageMove {
+                from,
+                to,
+                occurred_at: event.occurred_at,
+            });
+        }
+    }
+
+    let timelines: Vec<StartupTimeline> = startups
+        .into_iter()
+        .map(|startup| {
+            let current = PipelineStage::parse(&startup.status).unwrap_or(PipelineStage::Lead);
+            let startup_moves = moves.remove(&startup.id).unwrap_or_default();
+            let cohort_key = match cohort_by {
+                Some(CohortBy::Category) => non_empty(startup.category.as_deref())
+                    .unwrap_or("Uncategorized")
+                    .to_string(),
+                _ => normalize_to_week_start(startup.created_at.date()).to_string(),
+            };
+            StartupTimeline {
+                cohort_key,
+                created_at: startup.created_at,
+                stays: build_stays(startup.created_at, current, &startup_moves),
+            }
+        })
+        .collect();
+
+    let all_stays: Vec<StageStay> = timelines
+        .iter()
+        .flat_map(|timeline| timeline.stays.iter().cloned())
+        .collect();
+
+    Ok(Json(FunnelResponse {
+        from,
+        to,
+        stages: stage_metrics(&all_stays, range_start, range_end),
+        cohorts: match cohort_by {
+            Some(_) => cohort_metrics(&timelines, range_start, range_end),
+            None => Vec::new(),
+        },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 4, day)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    }
+
+    fn stage_move(from: PipelineStage, to: PipelineStage, day: u32) -> StageMove {
+        StageMove {
+            from,
+            to,
+            occurred_at: at(day),
+        }
+    }
+
+    #[test]
+    fn test_build_stays_follows_moves() {
+        use PipelineStage::*;
+
+        let stays = build_stays(
+            at(1),
+            CallBooked,
+            &[
+                stage_move(Lead, Contacted, 3),
+                stage_move(Contacted, CallBooked, 10),
+            ],
+        );
+        assert_eq!(stays.len(), 3);
+        assert_eq!(stays[0].stage, Lead);
+        assert_eq!(stays[0].exited_at, Some(at(3)));
+        assert_eq!(stays[1].next, Some(CallBooked));
+        assert_eq!(stays[2].exited_at, None);
+
+        let untouched = build_stays(at(1), Contacted, &[]);
+        assert_eq!(untouched.len(), 1);
+        assert_eq!(untouched[0].stage, Contacted);
+    }
+
+    #[test]
+    fn test_stage_metrics_rates_and_durations() {
+        use PipelineStage::*;
+
+        let mut stays = build_stays(at(1), Contacted, &[stage_move(Lead, Contacted, 3)]);
+        stays.extend(build_stays(
+            at(2),
+            NotAFit,
+            &[stage_move(Lead, NotAFit, 12)],
+        ));
+        stays.extend(build_stays(at(4), Lead, &[]));
+        // Entered before the range, so it is not counted.
+        stays.extend(build_stays(
+            at(1) - Duration::days(30),
+            Contacted,
+            &[stage_move(Lead, Contacted, 2)],
+        ));
+
+        let metrics = stage_metrics(&stays, at(1), at(30));
+        let lead = &metrics[Lead.position()];
+        assert_eq!(lead.entered, 3);
+        assert_eq!(lead.advanced, 1);
+        assert_eq!(lead.dropped_off, 1);
+        assert_eq!(lead.still_in_stage, 1);
+        assert!((lead.conversion_rate - 1.0 / 3.0).abs() < f64::EPSILON);
+        assert_eq!(lead.median_days, Some(2.0));
+        assert_eq!(lead.p90_days, Some(10.0));
+        assert_eq!(
+            lead.transitions
+                .iter()
+                .map(|item| (item.to, item.count))
+                .collect::<Vec<_>>(),
+            vec![(Contacted, 1), (NotAFit, 1)]
+        );
+
+        let contacted = &metrics[Contacted.position()];
+        assert_eq!(contacted.entered, 2);
+        assert_eq!(contacted.still_in_stage, 2);
+        assert_eq!(contacted.median_days, None);
+    }
+}
@@ -0,0 +1,492 @@
+use crate::auth::middleware::AuthUser;
+use crate::entities::{startup, tag, tag_assignment, user};
+use crate::history_controller::record_startup_revisions;
+use crate::owners_controller::{find_assignable_owner, set_startup_owner};
+use crate::pipeline::{PipelineStage, TransitionGraph};
+use crate::tags_controller::{find_or_create_tag, find_tag_by_name, unique_ids, TaggableEntity};
+use crate::{
+    check_stage_transition, find_active_startup, non_empty, record_activity_event,
+    stage_move_event, user_display_name, ActivityEventInput, AppState, ACTIVITY_STARTUP_UPDATED,
+};
+use axum::{extract::State, http::StatusCode, Json};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter, QuerySelect, Set,
+    TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+const MAX_BULK_STARTUPS: usize = 500;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum BulkStartupAction {
+    Transition {
+        to_stage: String,
+        reason: Option<String>,
+    },
+    /// `owner_id: null` unassigns.
+    Owner {
+        owner_id: Option<Uuid>,
+    },
+    Category {
+        category: Option<String>,
+    },
+    Tags {
+        #[serde(default)]
+        add_tag_ids: Vec<Uuid>,
+        /// Unknown names are created.
+        #[serde(default)]
+        add_tag_names: Vec<String>,
+        #[serde(default)]
+        remove_tag_ids: Vec<Uuid>,
+        #[serde(default)]
+        remove_tag_names: Vec<String>,
+    },
+    Trash,
+}
+
+#[derive(Deserialize)]
+pub struct BulkStartupRequest {
+    pub startup_ids: Vec<Uuid>,
+    #[serde(flatten)]
+    pub action: BulkStartupAction,
+}
+
+#[derive(Serialize)]
+pub struct BulkStartupResult {
+    pub startup_id: Uuid,
+    pub ok: bool,
+    /// False when the startup already matched, e.g. it was in the stage.
+    pub changed: bool,
+    /// The HTTP status the single-startup endpoint would have returned.
+    pub status: u16,
+    pub error: Option<String>,
+    pub startup: Option<startup::Model>,
+}
+
+#[derive(Serialize)]
+pub struct BulkStartupResponse {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BulkStartupResult>,
+}
+
+/// The action with its request-wide inputs resolved once up front.
+enum ResolvedAction {
+    Transition {
+        stage: PipelineStage,
+        reason: Option<String>,
+    },
+    Owner(Option<user::Model>),
+    Category(Option<String>),
+    Tags {
+        add: Vec<tag::Model>,
+        remove: Vec<tag::Model>,
+    },
+    Trash,
+}
+
+/// Tags to insert and delete for one startup given the tags it already has.
+fn tag_changes(
+    existing: &HashSet<Uuid>,
+    add: &[tag::Model],
+    remove: &[tag::Model],
+) -> (Vec<Uuid>, Vec<Uuid>) {
+    let to_add = add
+        .iter()
+        .map(|tag| tag.id)
+        .filter(|id| !existing.contains(id))
+        .collect();
+    let to_remove = remove
+        .iter()
+        .map(|tag| tag.id)
+        .filter(|id| existing.contains(id))
+        .collect();
+    (to_add, to_remove)
+}
+
+fn tag_names(tags: &[tag::Model], ids: &[Uuid]) -> Vec<String> {
+    tags.iter()
+        .filter(|tag| ids.contains(&tag.id))
+        .map(|tag| tag.name.clone())
+        .collect()
+}
+
+async fn resolve_action(
+    txn: &DatabaseTransaction,
+    action: BulkStartupAction,
+) -> Result<ResolvedAction, StatusCode> {
+    Ok(match action {
+        BulkStartupAction::Transition { to_stage, reason } => ResolvedAction::Transition {
+            stage: PipelineStage::parse(&to_stage).ok_or(StatusCode::BAD_REQUEST)?,
+            reason: non_empty(reason.as_deref()).map(str::to_string),
+        },
+        BulkStartupAction::Owner { owner_id } => ResolvedAction::Owner(match owner_id {
+            Some(owner_id) => Some(find_assignable_owner(txn, owner_id).await?),
+            None => None,
+        }),
+        BulkStartupAction::Category { category } => {
+            ResolvedAction::Category(non_empty(category.as_deref()).map(str::to_string))
+        }
+        BulkStartupAction::Tags {
+            add_tag_ids,
+            add_tag_names,
+            remove_tag_ids,
+            remove_tag_names,
+        } => {
+            let mut add = find_tags(txn, &add_tag_ids).await?;
+            for name in add_tag_names
+                .iter()
+                .filter_map(|name| non_empty(Some(name)))
+            {
+                let model = find_or_create_tag(txn, name)
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                if !add.iter().any(|existing| existing.id == model.id) {
+                    add.push(model);
+                }
+            }
+            let mut remove = find_tags(txn, &remove_tag_ids).await?;
+            for name in remove_tag_names
+                .iter()
+                .filter_map(|name| non_empty(Some(name)))
+            {
+                // Like /api/tags/unassign, unknown names are ignored.
+                if let Some(model) = find_tag_by_name(txn, name)
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                {
+                    remove.push(model);
+                }
+            }
+            if add.is_empty() && remove.is_empty() {
+                return Err(StatusCode::BAD_REQUEST);
+            }
+            if add.iter().any(|tag| remove.iter().any(|r| r.id == tag.id)) {
+                return Err(StatusCode::UNPROCESSABLE_ENTITY);
+            }
+            ResolvedAction::Tags { add, remove }
+        }
+        BulkStartupAction::Trash => ResolvedAction::Trash,
+    })
+}
+
+async fn find_tags(txn: &DatabaseTransaction, ids: &[Uuid]) -> Result<Vec<tag::Model>, StatusCode> {
+    let ids = unique_ids(ids.to_vec());
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let tags = tag::Entity::find()
+        .filter(tag::Column::Id.is_in(ids.clone()))
+        .all(txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if tags.len() != ids.len() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    Ok(tags)
+}
+
+/// POST /api/startups/bulk
+/// Apply one action to many startups in a single transaction. Each startup is
+/// handled in its own savepoint, so one that fails (not found, a move the
+/// pipeline does not allow, no permission) is reported and skipped while the
+/// rest go through. Every changed startup gets one activity event.
+pub async fn bulk_update_startups(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Json(payload): Json<BulkStartupRequest>,
+) -> Result<Json<BulkStartupResponse>, StatusCode> {
+    let startup_ids = unique_ids(payload.startup_ids);
+    if startup_ids.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    if startup_ids.len() > MAX_BULK_STARTUPS {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let action = resolve_action(&txn, payload.action).await?;
+
+    let mut results = Vec::with_capacity(startup_ids.len());
+    for startup_id in startup_ids {
+        let savepoint = txn
+            .begin()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let outcome = apply_action(&savepoint, &state.pipeline, &user, startup_id, &action).await;
+        let result = match outcome {
+            Ok((startup, changed)) => {
+                savepoint
+                    .commit()
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                BulkStartupResult {
+                    startup_id,
+                    ok: true,
+                    changed,
+                    status: StatusCode::OK.as_u16(),
+                    error: None,
+                    startup: Some(startup),
+                }
+            }
+            Err(status) => {
+                savepoint
+                    .rollback()
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                BulkStartupResult {
+                    startup_id,
+                    ok: false,
+                    changed: false,
+                    status: status.as_u16(),
+                    error: status.canonical_reason().map(str::to_string),
+                    startup: None,
+                }
+            }
+        };
+        results.push(result);
+    }
+
+    txn.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let succeeded = results.iter().filter(|result| result.ok).count();
+    Ok(Json(BulkStartupResponse {
+        succeeded,
+        failed: results.len() - succeeded,
+        results,
+    }))
+}
+
+/// Returns the startup after the action and whether anything changed.
+async fn apply_action(
+    txn: &DatabaseTransaction,
+    pipeline: &TransitionGraph,
+    user: &user::Model,
+    startup_id: Uuid,
+    action: &ResolvedAction,
+) -> Result<(startup::Model, bool), StatusCode> {
+    let db_err = |err: sea_orm::DbErr| {
+        tracing::error!(error = ?err, "bulk startup action failed");
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+    let existing = find_active_startup(txn, startup_id).await?;
+    let before = existing.clone();
+    let now = Utc::now().naive_utc();
+
+    let (updated, event) = match action {
+        ResolvedAction::Transition { stage, reason } => {
+            if existing.status == stage.as_str() {
+                return Ok((existing, false));
+            }
+            let direction =
+                check_stage_transition(pipeline, &existing.status, *stage, reason.as_deref())?;
+            let previous_status = existing.status.clone();
+            let mut active: startup::ActiveModel = existing.into();
+            active.status = Set(stage.as_str().to_string());
+            active.updated_at = Set(now);
+            let updated = active.update(txn).await.map_err(db_err)?;
+            let event =
+                stage_move_event(user, &updated, previous_status, direction, reason.clone());
+            (updated, event)
+        }
+        ResolvedAction::Owner(owner) => {
+            // Same rule as PUT /api/startups/:id/owner.
+            if !user.is_admin() && existing.owner_id.is_some_and(|owner| owner != user.id) {
+                return Err(StatusCode::FORBIDDEN);
+            }
+            let unchanged = existing.owner_id == owner.as_ref().map(|owner| owner.id);
+            // Records its own revisions and `owner_changed` event.
+            let updated = set_startup_owner(txn, existing, owner.as_ref(), user)
+                .await
+                .map_err(db_err)?;
+            return Ok((updated, !unchanged));
+        }
+        ResolvedAction::Category(category) => {
+            if existing.category == *category {
+                return Ok((existing, false));
+            }
+            let previous = existing.category.clone();
+            let mut active: startup::ActiveModel = existing.into();
+            active.category = Set(category.clone());
+            active.updated_at = Set(now);
+            let updated = active.update(txn).await.map_err(db_err)?;
+            let description = match category {
+                Some(category) => format!("Set category of {} to {}", updated.name, category),
+                None => format!("Cleared category of {}", updated.name),
+            };
+            let event = updated_event(
+                user,
+                &updated,
+                description,
+                json!({ "action": "category", "from": previous, "to": category }),
+            );
+            (updated, event)
+        }
+        ResolvedAction::Tags { add, remove } => {
+            let existing_tags: HashSet<Uuid> = tag_assignment::Entity::find()
+                .select_only()
+                .column(tag_assignment::Column::TagId)
+                .filter(tag_assignment::Column::EntityType.eq(TaggableEntity::Startup.as_str()))
+                .filter(tag_assignment::Column::EntityId.eq(existing.id))
+                .into_tuple::<Uuid>()
+                .all(txn)
+                .await
+                .map_err(db_err)?
+                .into_iter()
+                .collect();
+            let (to_add, to_remove) = tag_changes(&existing_tags, add, remove);
+            if to_add.is_empty() && to_remove.is_empty() {
+                return Ok((existing, false));
+            }
+
+            if !to_add.is_empty() {
+                let rows = to_add.iter().map(|tag_id| tag_assignment::ActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    tag_id: Set(*tag_id),
+                    entity_type: Set(TaggableEntity::Startup.as_str().to_string()),
+                    entity_id: Set(existing.id),
+                    created_by: Set(Some(user.id)),
+                    created_at: Set(now),
+                });
+                tag_assignment::Entity::insert_many(rows)
+                    .exec_without_returning(txn)
+                    .await
+                    .map_err(db_err)?;
+            }
+            if !to_remove.is_empty() {
+                tag_assignment::Entity::delete_many()
+                    .filter(tag_assignment::Column::EntityType.eq(TaggableEntity::Startup.as_str()))
+                    .filter(tag_assignment::Column::EntityId.eq(existing.id))
+                    .filter(tag_assignment::Column::TagId.is_in(to_remove.clone()))
+                    .exec(txn)
+                    .await
+                    .map_err(db_err)?;
+            }
+
+            let added = tag_names(add, &to_add);
+            let removed = tag_names(remove, &to_remove);
+            let description = match (added.is_empty(), removed.is_empty()) {
+                (false, true) => format!("Tagged {} with {}", existing.name, added.join(", ")),
+                (true, false) => format!("Removed {} from {}", removed.join(", "), existing.name),
+                _ => format!(
+                    "Tagged {} with {} and removed {}",
+                    existing.name,
+                    added.join(", "),
+                    removed.join(", ")
+                ),
+            };
+            let event = updated_event(
+                user,
+                &existing,
+                description,
+                json!({ "action": "tags", "added": added, "removed": removed }),
+            );
+            (existing, event)
+        }
+        ResolvedAction::Trash => {
+            let mut active: startup::ActiveModel = existing.into();
+            active.is_trashed = Set(true);
+            active.trashed_at = Set(Some(now));
+            let updated = active.update(txn).await.map_err(db_err)?;
+            let event = updated_event(
+                user,
+                &updated,
+                format!("Moved {} to the trash", updated.name),
+                json!({ "action": "trash" }),
+            );
+            (updated, event)
+        }
+    };
+
+    record_startup_revisions(txn, &before, &updated, Some(user))
+        .await
+        .map_err(db_err)?;
+    record_activity_event(txn, event).await.map_err(db_err)?;
+
+    Ok((updated, true))
+}
+
+fn updated_event(
+    user: &user::Model,
+    startup: &startup::Model,
+    description: String,
+    metadata: serde_json::Value,
+) -> ActivityEventInput {
+    ActivityEventInput {
+        activity_type: ACTIVITY_STARTUP_UPDATED,
+        description,
+        user_id: Some(user.id),
+        user_name: Some(user_display_name(user)),
+        startup_id: Some(startup.id),
+        startup_name: Some(startup.name.clone()),
+        contact_id: None,
+        contact_name: None,
+        stage_from: None,
+        stage_to: None,
+        metadata: Some(metadata),
+        occurred_at: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag_model(name: &str) -> tag::Model {
+        tag::Model {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            color: None,
+            created_at: Utc::now().naive_utc(),
+        }
+    }
+
+    #[test]
+    fn test_request_action_is_tagged() {
+        let owner_id = Uuid::new_v4();
+        let raw = format!(r#"{{"startup_ids": [], "action": "owner", "owner_id": "{owner_id}"}}"#);
+        let request: BulkStartupRequest = serde_json::from_str(&raw).unwrap();
+        assert_eq!(
+            request.action,
+            BulkStartupAction::Owner {
+                owner_id: Some(owner_id)
+            }
+        );
+
+        let request: BulkStartupRequest =
+            serde_json::from_str(r#"{"startup_ids": [], "action": "trash"}"#).unwrap();
+        assert_eq!(request.action, BulkStartupAction::Trash);
+        assert!(serde_json::from_str::<BulkStartupRequest>(
+            r#"{"startup_ids": [], "action": "explode"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_tag_changes_skip_noops() {
+        let fintech = tag_model("Fintech");
+        let hot = tag_model("Hot");
+        let cold = tag_model("Cold");
+        let existing = HashSet::from([fintech.id, cold.id]);
+
+        let (to_add, to_remove) = tag_changes(
+            &existing,
+            &[fintech.clone(), hot.clone()],
+            &[cold.clone(), tag_model("Unused")],
+        );
+
+        assert_eq!(to_add, vec![hot.id]);
+        assert_eq!(to_remove, vec![cold.id]);
+    }
+}
@@ -0,0 +1,560 @@
+//! Newsroom claim links. A teammate sends a contact a signed, expiring link;
+//! opening it lets the contact claim the startup's newsroom without an
+//! account. The token carries the claim id and expiry and is signed with
+//! `CLAIM_LINK_SECRET`, so forged or edited links are rejected before touching
+//! the database.
+
+use crate::auth::middleware::AuthUser;
+use crate::email_service::EmailServiceError;
+use crate::entities::{contact, newsroom_claim, outreach_log, startup};
+use crate::history_controller::record_startup_revisions;
+use crate::services::stale_leads::clear_stale_follow_ups;
+use crate::services::startup_activity::refresh_startup_contact_fields;
+use crate::{
+    find_active_startup, record_activity_event, ActivityEventInput, AppState,
+    ACTIVITY_NEWSROOM_CLAIMED, NEWSROOM_CLAIMED_STAGE,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::{DateTime, Duration, NaiveDateTime, Timelike, Utc};
+use ring::hmac;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set, TransactionTrait};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::env;
+use uuid::Uuid;
+
+const DEFAULT_EXPIRY_DAYS: i64 = 7;
+const MAX_EXPIRY_DAYS: i64 = 30;
+
+#[derive(Deserialize, Default)]
+pub struct SendClaimLinkRequest {
+    /// Defaults to 7, at most 30.
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct SendClaimLinkResponse {
+    pub claim: newsroom_claim::Model,
+    pub claim_url: String,
+}
+
+#[derive(Serialize)]
+pub struct ClaimPreview {
+    pub startup_name: String,
+    pub contact_name: String,
+    pub expires_at: NaiveDateTime,
+    pub claimed_at: Option<NaiveDateTime>,
+}
+
+/// The key claim links are signed with, loaded once at startup. It has its
+/// own secret rather than reusing `ENCRYPTION_KEY`, which protects stored
+/// mailbox passwords. Without a secret the claim endpoints answer 500 instead
+/// of taking the server down.
+#[derive(Clone)]
+pub struct ClaimSigningKey(Option<hmac::Key>);
+
+impl ClaimSigningKey {
+    pub fn from_env() -> Self {
+        let secret = env::var("CLAIM_LINK_SECRET")
+            .ok()
+            .filter(|secret| !secret.trim().is_empty());
+        if secret.is_none() {
+            tracing::warn!("CLAIM_LINK_SECRET is unset, claim links are disabled");
+        }
+        Self(secret.map(|secret| hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes())))
+    }
+
+    fn key(&self) -> Result<&hmac::Key, StatusCode> {
+        self.0.as_ref().ok_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+/// `<claim id>.<expiry as unix seconds>.<signature>`.
+pub fn sign_claim_token(key: &hmac::Key, claim_id: Uuid, expires_at: NaiveDateTime) -> String {
+    let payload = format!("{}.{}", claim_id.simple(), expires_at.and_utc().timestamp());
+    let signature = hmac::sign(key, payload.as_bytes());
+    format!("{payload}.{}", URL_SAFE_NO_PAD.encode(signature.as_ref()))
+}
+
+/// The claim id and expiry of a token, if the signature checks out. Expiry is
+/// left to the caller so an expired link can be told apart from a bad one.
+pub fn verify_claim_token(key: &hmac::Key, token: &str) -> Option<(Uuid, NaiveDateTime)> {
+    let (payload, signature) = token.rsplit_once('.')?;
+    let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+    hmac::verify(key, payload.as_bytes(), &signature).ok()?;
+
+    let (claim_id, expires_at) = payload.split_once('.')?;
+    let claim_id = Uuid::parse_str(claim_id).ok()?;
+    let expires_at = DateTime::from_timestamp(expires_at.parse().ok()?, 0)?.naive_utc();
+    Some((claim_id, expires_at))
+}
+
+fn claim_url(token: &str) -> String {
+    let frontend_url =
+        env::var("FRONTEND_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
+    format!("{frontend_url}/claim?token={token}")
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Names come from imports and user input, so they are escaped in the HTML
+/// body. The text body is written out separately so it keeps the link.
+fn claim_email(contact_name: &str, startup_name: &str, url: &str, days: i64) -> (String, String) {
+    let html_body = format!(
+        "<p>Hi {},</p>\
+         <p>You can claim the {} newsroom on Poblysh using the link below.</p>\
+         <p><a href=\"{}\">Claim your newsroom</a></p>\
+         <p>The link expires in {days} days and can only be used once.</p>\
+         <p>Best,<br/>The Poblysh team</p>",
+        escape_html(contact_name),
+        escape_html(startup_name),
+        escape_html(url),
+    );
+    let text_body = format!(
+        "Hi {contact_name},\n\n\
+         You can claim the {startup_name} newsroom on Poblysh using the link below.\n\n\
+         {url}\n\n\
+         The link expires in {days} days and can only be used once.\n\n\
+         Best,\nThe Poblysh team"
+    );
+    (html_body, text_body)
+}
+
+/// POST /api/startups/:id/contacts/:contact_id/claim-link
+/// Email a contact a link to claim the startup's newsroom.
+pub async fn send_claim_link(
+    State(state): State<AppState>,
+    AuthUser(sender): AuthUser,
+    Path((startup_id, contact_id)): Path<(Uuid, Uuid)>,
+    payload: Option<Json<SendClaimLinkRequest>>,
+) -> Result<Json<SendClaimLinkResponse>, StatusCode> {
+    let payload = payload.map(|Json(payload)| payload).unwrap_or_default();
+    let days = payload.expires_in_days.unwrap_or(DEFAULT_EXPIRY_DAYS);
+    if !(1..=MAX_EXPIRY_DAYS).contains(&days) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let key = state.claim_signing_key.key()?;
+
+    let startup = find_active_startup(&state.db, startup_id).await?;
+    if startup.admin_claimed {
+        return Err(StatusCode::CONFLICT);
+    }
+    let contact = contact::Entity::find()
+        .filter(contact::Column::Id.eq(contact_id))
+        .filter(contact::Column::StartupId.eq(startup_id))
+        .filter(contact::Column::IsTrashed.eq(false))
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let email = contact.email.clone().ok_or(StatusCode::BAD_REQUEST)?;
+
+    let now = Utc::now().naive_utc();
+    // Whole seconds, to match what the token carries.
+    let expires_at = (now + Duration::days(days))
+        .with_nanosecond(0)
+        .unwrap_or(now);
+    let claim = newsroom_claim::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        startup_id: Set(startup_id),
+        contact_id: Set(Some(contact_id)),
+        email: Set(email.clone()),
+        created_by_id: Set(Some(sender.id)),
+        message_id: Set(None),
+        expires_at: Set(expires_at),
+        claimed_at: Set(None),
+        created_at: Set(now),
+    }
+    .insert(&state.db)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let url = claim_url(&sign_claim_token(key, claim.id, expires_at));
+    let subject = format!("Claim the {} newsroom on Poblysh", startup.name);
+    let (html_body, text_body) = claim_email(&contact.name, &startup.name, &url, days);
+    let sender_email = sender.email.clone();
+    let sender_name = sender.name.clone().unwrap_or_else(|| sender_email.clone());
+
+    let send_result = match state
+        .email_service
+        .send_contact_email(
+            Some(&email),
+            &sender_name,
+            &sender_email,
+            &subject,
+            &html_body,
+            &text_body,
+        )
+        .await
+    {
+        Ok(result) => result,
+        Err(err) => {
+            tracing::error!("failed to send claim link: {}", err);
+            // A link that never went out should not stay usable.
+            if let Err(err) = newsroom_claim::Entity::delete_by_id(claim.id)
+                .exec(&state.db)
+                .await
+            {
+                tracing::warn!(error = ?err, "failed to remove unsent claim link");
+            }
+            return Err(match err {
+                EmailServiceError::MissingRecipient => StatusCode::BAD_REQUEST,
+                EmailServiceError::Transport(_) => StatusCode::BAD_GATEWAY,
+            });
+        }
+    };
+
+    let mut active: newsroom_claim::ActiveModel = claim.into();
+    active.message_id = Set(Some(send_result.message_id.clone()));
+    let claim = active
+        .update(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    outreach_log::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        startup_id: Set(startup_id),
+        contact_id: Set(Some(contact_id)),
+        channel: Set("email".to_string()),
+        direction: Set("outbound".to_string()),
+        message_summary: Set(Some("Newsroom claim link sent".to_string())),
+        message_id: Set(Some(send_result.message_id)),
+        subject: Set(Some(subject)),
+        delivery_status: Set(Some(send_result.delivery_status)),
+        date: Set(now),
+        outcome: Set("Claim link sent".to_string()),
+    }
+    .insert(&state.db)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if let Err(err) = refresh_startup_contact_fields(&state.db, startup_id).await {
+        tracing::warn!(error = ?err, "failed to refresh startup contact fields");
+    }
+    if let Err(err) = clear_stale_follow_ups(&state.db, startup_id).await {
+        tracing::warn!(error = ?err, "failed to clear stale-lead follow-ups");
+    }
+
+    Ok(Json(SendClaimLinkResponse {
+        claim,
+        claim_url: url,
+    }))
+}
+
+/// Resolve a token to its claim. Bad signatures and unknown claims are 404,
+/// expired links 410.
+async fn find_claim(
+    state: &AppState,
+    token: &str,
+) -> Result<(newsroom_claim::Model, startup::Model), StatusCode> {
+    let (claim_id, expires_at) =
+        verify_claim_token(state.claim_signing_key.key()?, token).ok_or(StatusCode::NOT_FOUND)?;
+    let claim = newsroom_claim::Entity::find_by_id(claim_id)
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    if claim.expires_at != expires_at {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let startup = find_active_startup(&state.db, claim.startup_id).await?;
+
+    Ok((claim, startup))
+}
+
+/// GET /api/claims/:token
+/// Public. What the claim page shows before the contact confirms.
+pub async fn get_claim(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<ClaimPreview>, StatusCode> {
+    let (claim, startup) = find_claim(&state, &token).await?;
+    if claim.claimed_at.is_none() && claim.is_expired() {
+        return Err(StatusCode::GONE);
+    }
+    let contact_id = claim.contact_id.ok_or(StatusCode::NOT_FOUND)?;
+    let contact = contact::Entity::find_by_id(contact_id)
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(Json(ClaimPreview {
+        startup_name: startup.name,
+        contact_name: contact.name,
+        expires_at: claim.expires_at,
+        claimed_at: claim.claimed_at,
+    }))
+}
+
+/// The `newsroom_claimed` event recorded when a claim is accepted.
+fn claim_activity_event(
+    claim: &newsroom_claim::Model,
+    contact: &contact::Model,
+    startup: &startup::Model,
+    claimed_at: NaiveDateTime,
+) -> ActivityEventInput {
+    ActivityEventInput {
+        activity_type: ACTIVITY_NEWSROOM_CLAIMED,
+        description: format!("{} claimed the {} newsroom", contact.name, startup.name),
+        user_id: None,
+        user_name: None,
+        startup_id: Some(startup.id),
+        startup_name: Some(startup.name.clone()),
+        contact_id: Some(contact.id),
+        contact_name: Some(contact.name.clone()),
+        stage_from: None,
+        // Counted by output metrics targeting the claimed stage.
+        stage_to: Some(NEWSROOM_CLAIMED_STAGE.to_string()),
+        metadata: Some(json!({
+            "claim_id": claim.id,
+            "email": claim.email,
+            "sent_by_id": claim.created_by_id,
+        })),
+        occurred_at: Some(claimed_at),
+    }
+}
+
+/// POST /api/claims/:token
+/// Public. Marks the startup claimed by the link's contact. A link works once,
+/// and not at all once the startup has been claimed some other way.
+pub async fn accept_claim(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<ClaimPreview>, StatusCode> {
+    let (claim, startup) = find_claim(&state, &token).await?;
+    if claim.claimed_at.is_some() || startup.admin_claimed {
+        return Err(StatusCode::CONFLICT);
+    }
+    if claim.is_expired() {
+        return Err(StatusCode::GONE);
+    }
+    let contact_id = claim.contact_id.ok_or(StatusCode::NOT_FOUND)?;
+    let contact = contact::Entity::find_by_id(contact_id)
+        .filter(contact::Column::IsTrashed.eq(false))
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let now = Utc::now().naive_utc();
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    // Conditional updates so concurrent accepts of the same claim, or of two
+    // claims for one startup, cannot both succeed.
+    let claimed = newsroom_claim::Entity::update_many()
+        .col_expr(newsroom_claim::Column::ClaimedAt, Expr::value(Some(now)))
+        .filter(newsroom_claim::Column::Id.eq(claim.id))
+        .filter(newsroom_claim::Column::ClaimedAt.is_null())
+        .exec(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if claimed.rows_affected != 1 {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    let claimed = startup::Entity::update_many()
+        .col_expr(startup::Column::AdminClaimed, Expr::value(true))
+        .col_expr(startup::Column::ClaimedAt, Expr::value(Some(now)))
+        .col_expr(
+            startup::Column::ClaimedByContactId,
+            Expr::value(Some(contact.id)),
+        )
+        .col_expr(startup::Column::UpdatedAt, Expr::value(now))
+        .filter(startup::Column::Id.eq(startup.id))
+        .filter(startup::Column::AdminClaimed.eq(false))
+        .exec(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if claimed.rows_affected != 1 {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    let before = startup;
+    let result = startup::Model {
+        admin_claimed: true,
+        claimed_at: Some(now),
+        claimed_by_contact_id: Some(contact.id),
+        updated_at: now,
+        ..before.clone()
+    };
+    record_startup_revisions(&txn, &before, &result, None)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    record_activity_event(&txn, claim_activity_event(&claim, &contact, &result, now))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    txn.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(ClaimPreview {
+        startup_name: result.name,
+        contact_name: contact.name,
+        expires_at: claim.expires_at,
+        claimed_at: Some(now),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::weekly_metric_definition;
+    use chrono::NaiveDate;
+
+    fn key() -> hmac::Key {
+        hmac::Key::new(hmac::HMAC_SHA256, b"test-secret")
+    }
+
+    #[test]
+    fn test_claim_token_round_trip() {
+        let claim_id = Uuid::new_v4();
+        let expires_at = NaiveDate::from_ymd_opt(2025, 5, 22)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let token = sign_claim_token(&key(), claim_id, expires_at);
+
+        assert_eq!(
+            verify_claim_token(&key(), &token),
+            Some((claim_id, expires_at))
+        );
+    }
+
+    #[test]
+    fn test_claim_token_rejects_tampering() {
+        let expires_at = NaiveDate::from_ymd_opt(2025, 5, 22)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let token = sign_claim_token(&key(), Uuid::new_v4(), expires_at);
+        let (payload, signature) = token.rsplit_once('.').unwrap();
+        let (claim_id, _) = payload.split_once('.').unwrap();
+        let extended = format!("{claim_id}.{}.{signature}", i64::MAX / 2);
+
+        assert_eq!(verify_claim_token(&key(), &extended), None);
+        let other_key = hmac::Key::new(hmac::HMAC_SHA256, b"another-secret");
+        assert_eq!(verify_claim_token(&other_key, &token), None);
+        assert_eq!(verify_claim_token(&key(), "not-a-token"), None);
+    }
+
+    #[test]
+    fn test_claim_email_escapes_names() {
+        let url = "https://app.poblysh.com/claim?token=abc.123.sig";
+        let (html, text) = claim_email(
+            "Ada <a href=\"https://evil.example\">",
+            "Tom & Jerry's",
+            url,
+            7,
+        );
+
+        assert!(html.contains("Hi Ada &lt;a href=&quot;https://evil.example&quot;&gt;,"));
+        assert!(html.contains("the Tom &amp; Jerry&#39;s newsroom"));
+        assert!(!html.contains("evil.example\">"));
+        assert!(html.contains(&format!("<a href=\"{url}\">")));
+        assert!(text.contains("Hi Ada <a href=\"https://evil.example\">,"));
+        assert!(text.contains(url));
+    }
+
+    #[test]
+    fn test_claim_counts_for_newsroom_claimed_output_metric() {
+        let now = NaiveDate::from_ymd_opt(2025, 5, 22)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let startup_id = Uuid::new_v4();
+        let startup = startup::Model {
+            id: startup_id,
+            name: "Paystack".to_string(),
+            category: None,
+            website: None,
+            newsroom_url: None,
+            status: "Lead".to_string(),
+            last_contact_date: None,
+            next_step: None,
+            admin_claimed: false,
+            owner_id: None,
+            is_trashed: false,
+            trashed_at: None,
+            custom_fields: json!({}),
+            signal_score: 0,
+            signal_breakdown: json!([]),
+            website_broken: false,
+            newsroom_url_broken: false,
+            claimed_at: None,
+            claimed_by_contact_id: None,
+            created_at: now,
+            updated_at: now,
+        };
+        let contact = contact::Model {
+            id: Uuid::new_v4(),
+            startup_id,
+            name: "Ada".to_string(),
+            role: "CEO".to_string(),
+            email: Some("ada@paystack.com".to_string()),
+            phone: None,
+            linkedin_url: None,
+            is_primary: true,
+            notes: None,
+            is_trashed: false,
+            trashed_at: None,
+            owner_id: None,
+            custom_fields: json!({}),
+        };
+        let claim = newsroom_claim::Model {
+            id: Uuid::new_v4(),
+            startup_id,
+            contact_id: Some(contact.id),
+            email: "ada@paystack.com".to_string(),
+            created_by_id: None,
+            message_id: None,
+            expires_at: now,
+            claimed_at: None,
+            created_at: now,
+        };
+        let stage_name = crate::parse_metric_stage("newsroom claimed");
+        assert_eq!(stage_name.as_deref(), Some(NEWSROOM_CLAIMED_STAGE));
+        let metric = weekly_metric_definition::Model {
+            id: Uuid::new_v4(),
+            plan_id: Uuid::new_v4(),
+            metric_type: crate::METRIC_TYPE_OUTPUT.to_string(),
+            name: "Newsrooms claimed".to_string(),
+            unit_label: "newsrooms".to_string(),
+            owner_name: None,
+            owner_id: None,
+            target_value: 3,
+            actual_value: 0,
+            activity_type: None,
+            stage_name,
+            sort_order: 0,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let event = claim_activity_event(&claim, &contact, &startup, now);
+
+        assert!(crate::metric_counts_event(
+            &metric,
+            event.activity_type,
+            event.stage_to.as_deref()
+        ));
+    }
+}
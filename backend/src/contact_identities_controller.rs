@@ -0,0 +1,487 @@
+use crate::auth::middleware::AuthUser;
+use crate::entities::{contact, contact_identity};
+use crate::history_controller::record_contact_revisions;
+use crate::{can_edit_contact, AppState};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// What a contact identity holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityKind {
+    Email,
+    Phone,
+    Handle,
+}
+
+impl IdentityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdentityKind::Email => "email",
+            IdentityKind::Phone => "phone",
+            IdentityKind::Handle => "handle",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "email" => Some(IdentityKind::Email),
+            "phone" => Some(IdentityKind::Phone),
+            "handle" => Some(IdentityKind::Handle),
+            _ => None,
+        }
+    }
+
+    /// The contact column the primary identity of this kind is mirrored to.
+    fn mirrored_value(&self, contact: &contact::Model) -> Option<String> {
+        match self {
+            IdentityKind::Email => contact.email.clone(),
+            IdentityKind::Phone => contact.phone.clone(),
+            IdentityKind::Handle => None,
+        }
+    }
+}
+
+/// Lowercased, trimmed email, or `None` when there is nothing to compare.
+pub fn normalize_email(email: &str) -> Option<String> {
+    let email = email.trim().to_lowercase();
+    (email.contains('@')).then_some(email)
+}
+
+/// Digits only, with a leading `00` treated like `+` and a parenthesised
+/// national trunk `(0)` dropped, so "+44 (0)20 ..." matches "+44 20 ...".
+/// Numbers shorter than seven digits are too ambiguous to match on.
+pub fn normalize_phone(phone: &str) -> Option<String> {
+    let digits: String = phone
+        .replace("(0)", "")
+        .chars()
+        .filter(|ch| ch.is_ascii_digit())
+        .collect();
+    let digits = digits.strip_prefix("00").unwrap_or(&digits).to_string();
+    (digits.len() >= 7).then_some(digits)
+}
+
+/// The value lookups compare against, or `None` when `value` is not a
+/// valid identity of that kind.
+pub fn normalize_identity(kind: IdentityKind, value: &str) -> Option<String> {
+    match kind {
+        IdentityKind::Email => normalize_email(value),
+        IdentityKind::Phone => normalize_phone(value),
+        IdentityKind::Handle => {
+            let handle = value.trim().trim_start_matches('@').to_lowercase();
+            (!handle.is_empty()).then_some(handle)
+        }
+    }
+}
+
+/// Makes the contact's `email` and `phone` its primary identities, adding
+/// them when missing. Call after anything that writes those columns.
+pub async fn sync_primary_identities<C: ConnectionTrait>(
+    db: &C,
+    contact: &contact::Model,
+) -> Result<(), DbErr> {
+    for kind in [IdentityKind::Email, IdentityKind::Phone] {
+        let value = kind.mirrored_value(contact);
+        let normalized = value
+            .as_deref()
+            .and_then(|value| normalize_identity(kind, value));
+
+        let primary_id = match (value, normalized) {
+            (Some(value), Some(normalized)) => {
+                let existing = contact_identity::Entity::find()
+                    .filter(contact_identity::Column::ContactId.eq(contact.id))
+                    .filter(contact_identity::Column::Kind.eq(kind.as_str()))
+                    .filter(contact_identity::Column::NormalizedValue.eq(normalized.clone()))
+                    .one(db)
+                    .await?;
+                match existing {
+                    Some(identity) => Some(identity.id),
+                    None => Some(
+                        contact_identity::ActiveModel {
+                            id: Set(Uuid::new_v4()),
+                            contact_id: Set(contact.id),
+                            kind: Set(kind.as_str().to_string()),
+                            value: Set(value.trim().to_string()),
+                            normalized_value: Set(normalized),
+                            label: Set(None),
+                            is_primary: Set(true),
+                            created_at: Set(Utc::now().naive_utc()),
+                        }
+                        .insert(db)
+                        .await?
+                        .id,
+                    ),
+                }
+            }
+            _ => None,
+        };
+
+        let mut demote = contact_identity::Entity::update_many()
+            .col_expr(contact_identity::Column::IsPrimary, Expr::value(false))
+            .filter(contact_identity::Column::ContactId.eq(contact.id))
+            .filter(contact_identity::Column::Kind.eq(kind.as_str()));
+        if let Some(id) = primary_id {
+            demote = demote.filter(contact_identity::Column::Id.ne(id));
+            contact_identity::Entity::update_many()
+                .col_expr(contact_identity::Column::IsPrimary, Expr::value(true))
+                .filter(contact_identity::Column::Id.eq(id))
+                .exec(db)
+                .await?;
+        }
+        demote.exec(db).await?;
+    }
+
+    Ok(())
+}
+
+/// Active contacts with `email` among their addresses, those using it as
+/// their primary address first.
+pub async fn find_contacts_by_email<C: ConnectionTrait>(
+    db: &C,
+    email: &str,
+) -> Result<Vec<contact::Model>, DbErr> {
+    let Some(normalized) = normalize_email(email) else {
+        return Ok(Vec::new());
+    };
+
+    let identities = contact_identity::Entity::find()
+        .filter(contact_identity::Column::Kind.eq(IdentityKind::Email.as_str()))
+        .filter(contact_identity::Column::NormalizedValue.eq(normalized))
+        .order_by_desc(contact_identity::Column::IsPrimary)
+        .order_by_asc(contact_identity::Column::CreatedAt)
+        .all(db)
+        .await?;
+    let ids: Vec<Uuid> = identities
+        .iter()
+        .map(|identity| identity.contact_id)
+        .collect();
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut contacts = contact::Entity::find()
+        .filter(contact::Column::Id.is_in(ids.clone()))
+        .filter(contact::Column::IsTrashed.eq(false))
+        .all(db)
+        .await?;
+    contacts.sort_by_key(|model| ids.iter().position(|id| *id == model.id));
+    Ok(contacts)
+}
+
+/// Every normalized email address of a contact.
+pub async fn contact_emails<C: ConnectionTrait>(
+    db: &C,
+    contact_id: Uuid,
+) -> Result<Vec<String>, DbErr> {
+    Ok(contact_identity::Entity::find()
+        .filter(contact_identity::Column::ContactId.eq(contact_id))
+        .filter(contact_identity::Column::Kind.eq(IdentityKind::Email.as_str()))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|identity| identity.normalized_value)
+        .collect())
+}
+
+#[derive(Deserialize)]
+pub struct CreateIdentityRequest {
+    pub kind: IdentityKind,
+    pub value: String,
+    pub label: Option<String>,
+    #[serde(default)]
+    pub is_primary: bool,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateIdentityRequest {
+    pub value: Option<String>,
+    pub label: Option<String>,
+    pub is_primary: Option<bool>,
+}
+
+async fn find_contact(state: &AppState, contact_id: Uuid) -> Result<contact::Model, StatusCode> {
+    contact::Entity::find_by_id(contact_id)
+        .filter(contact::Column::IsTrashed.eq(false))
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn find_identity(
+    state: &AppState,
+    contact_id: Uuid,
+    identity_id: Uuid,
+) -> Result<contact_identity::Model, StatusCode> {
+    contact_identity::Entity::find_by_id(identity_id)
+        .filter(contact_identity::Column::ContactId.eq(contact_id))
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn ensure_unique(
+    state: &AppState,
+    contact_id: Uuid,
+    kind: IdentityKind,
+    normalized: &str,
+    except: Option<Uuid>,
+) -> Result<(), StatusCode> {
+    let mut query = contact_identity::Entity::find()
+        .filter(contact_identity::Column::ContactId.eq(contact_id))
+        .filter(contact_identity::Column::Kind.eq(kind.as_str()))
+        .filter(contact_identity::Column::NormalizedValue.eq(normalized));
+    if let Some(id) = except {
+        query = query.filter(contact_identity::Column::Id.ne(id));
+    }
+    let exists = query
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .is_some();
+
+    if exists {
+        Err(StatusCode::CONFLICT)
+    } else {
+        Ok(())
+    }
+}
+
+/// Makes `identity` (or, with `None`, nothing) the contact's primary of its
+/// kind and mirrors emails and phone numbers onto the contact row.
+async fn set_primary(
+    state: &AppState,
+    user: &crate::entities::user::Model,
+    contact: &contact::Model,
+    kind: IdentityKind,
+    identity: Option<&contact_identity::Model>,
+) -> Result<(), StatusCode> {
+    let mut demote = contact_identity::Entity::update_many()
+        .col_expr(contact_identity::Column::IsPrimary, Expr::value(false))
+        .filter(contact_identity::Column::ContactId.eq(contact.id))
+        .filter(contact_identity::Column::Kind.eq(kind.as_str()));
+    if let Some(identity) = identity {
+        demote = demote.filter(contact_identity::Column::Id.ne(identity.id));
+        contact_identity::Entity::update_many()
+            .col_expr(contact_identity::Column::IsPrimary, Expr::value(true))
+            .filter(contact_identity::Column::Id.eq(identity.id))
+            .exec(&state.db)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+    demote
+        .exec(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let value = identity.map(|identity| identity.value.clone());
+    if kind == IdentityKind::Handle || kind.mirrored_value(contact) == value {
+        return Ok(());
+    }
+
+    let mut active: contact::ActiveModel = contact.clone().into();
+    match kind {
+        IdentityKind::Email => active.email = Set(value),
+        IdentityKind::Phone => active.phone = Set(value),
+        IdentityKind::Handle => {}
+    }
+    let updated = active
+        .update(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if let Err(err) = record_contact_revisions(&state.db, contact, &updated, Some(user)).await {
+        tracing::warn!(error = ?err, "failed to record contact revisions");
+    }
+
+    Ok(())
+}
+
+async fn list_for_contact(
+    state: &AppState,
+    contact_id: Uuid,
+) -> Result<Vec<contact_identity::Model>, StatusCode> {
+    contact_identity::Entity::find()
+        .filter(contact_identity::Column::ContactId.eq(contact_id))
+        .order_by_asc(contact_identity::Column::Kind)
+        .order_by_desc(contact_identity::Column::IsPrimary)
+        .order_by_asc(contact_identity::Column::CreatedAt)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// GET /api/contacts/:id/identities
+pub async fn list_identities(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    Path(contact_id): Path<Uuid>,
+) -> Result<Json<Vec<contact_identity::Model>>, StatusCode> {
+    find_contact(&state, contact_id).await?;
+    Ok(Json(list_for_contact(&state, contact_id).await?))
+}
+
+/// POST /api/contacts/:id/identities
+/// Adds an email address, phone number or handle. The first of its kind, or
+/// one sent with `is_primary`, becomes the primary.
+pub async fn create_identity(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(contact_id): Path<Uuid>,
+    Json(payload): Json<CreateIdentityRequest>,
+) -> Result<(StatusCode, Json<contact_identity::Model>), StatusCode> {
+    let contact = find_contact(&state, contact_id).await?;
+    if !can_edit_contact(&user, &contact) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let normalized =
+        normalize_identity(payload.kind, &payload.value).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    ensure_unique(&state, contact_id, payload.kind, &normalized, None).await?;
+
+    let has_primary = contact_identity::Entity::find()
+        .filter(contact_identity::Column::ContactId.eq(contact_id))
+        .filter(contact_identity::Column::Kind.eq(payload.kind.as_str()))
+        .filter(contact_identity::Column::IsPrimary.eq(true))
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .is_some();
+
+    let identity = contact_identity::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        contact_id: Set(contact_id),
+        kind: Set(payload.kind.as_str().to_string()),
+        value: Set(payload.value.trim().to_string()),
+        normalized_value: Set(normalized),
+        label: Set(payload.label.filter(|label| !label.trim().is_empty())),
+        is_primary: Set(false),
+        created_at: Set(Utc::now().naive_utc()),
+    }
+    .insert(&state.db)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if payload.is_primary || !has_primary {
+        set_primary(&state, &user, &contact, payload.kind, Some(&identity)).await?;
+    }
+
+    let created = find_identity(&state, contact_id, identity.id).await?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// PUT /api/contacts/:id/identities/:identity_id
+/// Setting `is_primary: false` on the primary leaves the kind without one
+/// and clears the mirrored contact field.
+pub async fn update_identity(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path((contact_id, identity_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateIdentityRequest>,
+) -> Result<Json<contact_identity::Model>, StatusCode> {
+    let contact = find_contact(&state, contact_id).await?;
+    if !can_edit_contact(&user, &contact) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let existing = find_identity(&state, contact_id, identity_id).await?;
+    let kind = IdentityKind::parse(&existing.kind).ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut active: contact_identity::ActiveModel = existing.clone().into();
+    if let Some(value) = payload.value.as_deref() {
+        let normalized = normalize_identity(kind, value).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+        ensure_unique(&state, contact_id, kind, &normalized, Some(identity_id)).await?;
+        active.value = Set(value.trim().to_string());
+        active.normalized_value = Set(normalized);
+    }
+    if let Some(label) = payload.label {
+        active.label = Set(Some(label).filter(|label| !label.trim().is_empty()));
+    }
+    let updated = active
+        .update(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    match payload.is_primary.unwrap_or(existing.is_primary) {
+        true => set_primary(&state, &user, &contact, kind, Some(&updated)).await?,
+        false if existing.is_primary => set_primary(&state, &user, &contact, kind, None).await?,
+        false => {}
+    }
+
+    Ok(Json(find_identity(&state, contact_id, identity_id).await?))
+}
+
+/// DELETE /api/contacts/:id/identities/:identity_id
+/// Removing the primary promotes the oldest remaining identity of the same
+/// kind.
+pub async fn delete_identity(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path((contact_id, identity_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, StatusCode> {
+    let contact = find_contact(&state, contact_id).await?;
+    if !can_edit_contact(&user, &contact) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let existing = find_identity(&state, contact_id, identity_id).await?;
+    let kind = IdentityKind::parse(&existing.kind).ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    contact_identity::Entity::delete_by_id(identity_id)
+        .exec(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if existing.is_primary {
+        let next = contact_identity::Entity::find()
+            .filter(contact_identity::Column::ContactId.eq(contact_id))
+            .filter(contact_identity::Column::Kind.eq(kind.as_str()))
+            .order_by_asc(contact_identity::Column::CreatedAt)
+            .one(&state.db)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        set_primary(&state, &user, &contact, kind, next.as_ref()).await?;
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_identity() {
+        assert_eq!(
+            normalize_identity(IdentityKind::Email, " Ada@Example.COM "),
+            Some("ada@example.com".to_string())
+        );
+        assert_eq!(normalize_identity(IdentityKind::Email, "ada"), None);
+        assert_eq!(
+            normalize_identity(IdentityKind::Phone, "+44 (0)20 7946 0958"),
+            Some("442079460958".to_string())
+        );
+        assert_eq!(
+            normalize_identity(IdentityKind::Phone, "0044 20 7946 0958"),
+            normalize_identity(IdentityKind::Phone, "+44 (0)20 7946 0958")
+        );
+        assert_eq!(
+            normalize_identity(IdentityKind::Phone, "(020) 7946 0958"),
+            Some("02079460958".to_string())
+        );
+        assert_eq!(normalize_identity(IdentityKind::Phone, "12-34"), None);
+        assert_eq!(
+            normalize_identity(IdentityKind::Handle, "@AdaL"),
+            Some("adal".to_string())
+        );
+    }
+}
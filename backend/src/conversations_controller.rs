@@ -1,10 +1,15 @@
 use crate::auth::middleware::{AdminUser, AuthUser};
+use crate::contact_identities_controller::contact_emails;
 use crate::entities::{
-    conversation, email_attachment, email_credential, email_provider_setting, message, user,
+    conversation, email_attachment, email_credential, email_provider_setting, message, startup,
+    user,
 };
 use crate::services::encryption_service::EncryptionService;
 use crate::services::imap_service::ImapService;
 use crate::services::smtp_service::{OutgoingAttachment, SmtpService};
+use crate::services::stale_leads::clear_stale_follow_ups;
+use crate::services::startup_activity::refresh_startup_contact_fields;
+use crate::tags_controller::{tag_filter, TaggableEntity};
 use crate::AppState;
 use axum::{
     extract::{Path, Query, State},
@@ -15,7 +20,8 @@ use axum::{
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use chrono::{FixedOffset, Utc};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, QueryTrait, Set,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -95,9 +101,15 @@ pub struct ConversationListQuery {
     pub has_attachments: Option<bool>,
     pub search: Option<String>,
     pub participant: Option<String>,
+    /// Conversations with any of this contact's email addresses.
+    pub contact_id: Option<Uuid>,
     pub page: Option<u64>,
     pub page_size: Option<u64>,
     pub archived: Option<bool>,
+    /// Comma-separated tag ids or names.
+    pub tags: Option<String>,
+    /// Comma-separated pipeline stages of the linked startup.
+    pub startup_status: Option<String>,
 }
 
 #[derive(Clone, Serialize)]
@@ -486,6 +498,34 @@ pub async fn list_conversations(
     let archived = params.archived.unwrap_or(false);
     query = query.filter(conversation::Column::IsArchived.eq(archived));
 
+    let stages: Vec<&str> = params
+        .startup_status
+        .as_deref()
+        .unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|stage| !stage.is_empty())
+        .collect();
+    if !stages.is_empty() {
+        query = query.filter(
+            conversation::Column::StartupId.in_subquery(
+                startup::Entity::find()
+                    .select_only()
+                    .column(startup::Column::Id)
+                    .filter(startup::Column::Status.is_in(stages))
+                    .into_query(),
+            ),
+        );
+    }
+
+    if let Some(tags) = params.tags.as_deref().filter(|t| !t.trim().is_empty()) {
+        query = query.filter(tag_filter(
+            conversation::Column::Id,
+            TaggableEntity::Conversation,
+            tags,
+        ));
+    }
+
     query = query.order_by_desc(conversation::Column::LatestMessageAt);
 
     let results = query
@@ -494,7 +534,18 @@ pub async fn list_conversations(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    let filtered = in_memory_filter(results, &params);
+    let contact_emails = match params.contact_id {
+        Some(contact_id) => Some(
+            contact_emails(&state.db, contact_id)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .into_iter()
+                .collect::<HashSet<_>>(),
+        ),
+        None => None,
+    };
+
+    let filtered = in_memory_filter(results, &params, contact_emails.as_ref());
 
     Ok(Json(filtered))
 }
@@ -691,22 +742,32 @@ fn utc_now() -> chrono::DateTime<FixedOffset> {
 fn in_memory_filter(
     items: Vec<(conversation::Model, Option<user::Model>)>,
     params: &ConversationListQuery,
+    contact_emails: Option<&HashSet<String>>,
 ) -> Vec<ConversationSummary> {
     let mut filtered = Vec::new();
     for (conversation, owner) in items {
+        let participants = conversation
+            .participants
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        let participant_emails = participants
+            .iter()
+            .filter_map(|value| value.get("email").and_then(Value::as_str));
+
         if let Some(participant) = params.participant.as_ref() {
-            let participants = conversation
-                .participants
-                .as_array()
-                .cloned()
-                .unwrap_or_default();
-            let matches = participants.iter().any(|value| {
-                value
-                    .get("email")
-                    .and_then(Value::as_str)
-                    .map(|email| email.contains(participant))
-                    .unwrap_or(false)
-            });
+            let matches = participant_emails
+                .clone()
+                .any(|email| email.contains(participant));
+            if !matches {
+                continue;
+            }
+        }
+
+        if let Some(emails) = contact_emails {
+            let matches = participant_emails
+                .clone()
+                .any(|email| emails.contains(&email.trim().to_lowercase()));
             if !matches {
                 continue;
             }
@@ -781,7 +842,7 @@ async fn send_message(
     let attachments = payload
         .attachments
         .iter()
-        .map(|upload| to_outgoing_attachment(upload))
+        .map(to_outgoing_attachment)
         .collect::<Result<Vec<_>, StatusCode>>()?;
 
     smtp_service
@@ -830,9 +891,7 @@ fn determine_recipients(
         if participant.email.eq_ignore_ascii_case(user_email) {
             continue;
         }
-        if matches!(kind, ReplyKind::Reply) && participant.role == "from" {
-            targets.insert(participant.email);
-        } else if matches!(kind, ReplyKind::Forward) {
+        if matches!(kind, ReplyKind::Forward) || participant.role == "from" {
             targets.insert(participant.email);
         }
     }
@@ -866,6 +925,7 @@ fn parse_participants(value: &Value) -> Vec<Participant> {
 struct Participant {
     email: String,
     role: String,
+    #[allow(dead_code)]
     name: Option<String>,
 }
 
@@ -883,6 +943,7 @@ fn to_outgoing_attachment(payload: &AttachmentUpload) -> Result<OutgoingAttachme
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn persist_outgoing_message(
     db: &DatabaseConnection,
     conversation: &conversation::Model,
@@ -965,6 +1026,15 @@ async fn persist_outgoing_message(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    if let Some(startup_id) = conversation.startup_id {
+        if let Err(err) = refresh_startup_contact_fields(db, startup_id).await {
+            warn!(error = ?err, "failed to refresh startup contact fields");
+        }
+        if let Err(err) = clear_stale_follow_ups(db, startup_id).await {
+            warn!(error = ?err, "failed to clear stale-lead follow-ups");
+        }
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,614 @@
+use crate::auth::middleware::{AdminUser, AuthUser};
+use crate::entities::{contact, custom_field_definition, startup};
+use crate::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{NaiveDate, Utc};
+use sea_orm::sea_query::extension::postgres::PgExpr;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::fmt;
+use uuid::Uuid;
+
+pub const ENTITY_STARTUP: &str = "startup";
+pub const ENTITY_CONTACT: &str = "contact";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomFieldType {
+    Text,
+    Number,
+    Date,
+    SingleSelect,
+    MultiSelect,
+}
+
+impl CustomFieldType {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "text" => Some(CustomFieldType::Text),
+            "number" => Some(CustomFieldType::Number),
+            "date" => Some(CustomFieldType::Date),
+            "single_select" => Some(CustomFieldType::SingleSelect),
+            "multi_select" => Some(CustomFieldType::MultiSelect),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomFieldType::Text => "text",
+            CustomFieldType::Number => "number",
+            CustomFieldType::Date => "date",
+            CustomFieldType::SingleSelect => "single_select",
+            CustomFieldType::MultiSelect => "multi_select",
+        }
+    }
+
+    fn is_select(&self) -> bool {
+        matches!(
+            self,
+            CustomFieldType::SingleSelect | CustomFieldType::MultiSelect
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomFieldError {
+    UnknownField(String),
+    Required(String),
+    InvalidValue(String),
+}
+
+impl fmt::Display for CustomFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CustomFieldError::UnknownField(key) => write!(f, "unknown custom field: {}", key),
+            CustomFieldError::Required(key) => write!(f, "custom field {} is required", key),
+            CustomFieldError::InvalidValue(key) => {
+                write!(f, "invalid value for custom field {}", key)
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CreateCustomFieldRequest {
+    pub entity_type: String,
+    pub key: String,
+    pub label: String,
+    pub field_type: String,
+    pub options: Option<Vec<String>>,
+    pub is_required: Option<bool>,
+    pub sort_order: Option<i32>,
+}
+
+/// Key, entity and type are fixed once created so stored values stay valid.
+#[derive(Deserialize)]
+pub struct UpdateCustomFieldRequest {
+    pub label: Option<String>,
+    pub options: Option<Vec<String>>,
+    pub is_required: Option<bool>,
+    pub sort_order: Option<i32>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct CustomFieldListQuery {
+    pub entity_type: Option<String>,
+}
+
+fn parse_entity_type(value: &str) -> Option<&'static str> {
+    match value.trim() {
+        ENTITY_STARTUP => Some(ENTITY_STARTUP),
+        ENTITY_CONTACT => Some(ENTITY_CONTACT),
+        _ => None,
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= 64
+        && key.starts_with(|c: char| c.is_ascii_lowercase())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn clean_options(options: Vec<String>) -> Vec<String> {
+    let mut cleaned: Vec<String> = Vec::new();
+    for option in options {
+        let option = option.trim().to_string();
+        if !option.is_empty() && !cleaned.iter().any(|existing| existing == &option) {
+            cleaned.push(option);
+        }
+    }
+    cleaned
+}
+
+pub async fn list_custom_fields(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    query: Option<Query<CustomFieldListQuery>>,
+) -> Result<Json<Vec<custom_field_definition::Model>>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    let mut select = custom_field_definition::Entity::find()
+        .order_by_asc(custom_field_definition::Column::EntityType)
+        .order_by_asc(custom_field_definition::Column::SortOrder)
+        .order_by_asc(custom_field_definition::Column::Label);
+
+    if let Some(entity_type) = params.entity_type.as_deref() {
+        let entity_type = parse_entity_type(entity_type).ok_or(StatusCode::BAD_REQUEST)?;
+        select = select.filter(custom_field_definition::Column::EntityType.eq(entity_type));
+    }
+
+    let definitions = select
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(definitions))
+}
+
+pub async fn create_custom_field(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    Json(payload): Json<CreateCustomFieldRequest>,
+) -> Result<Json<custom_field_definition::Model>, StatusCode> {
+    let entity_type = parse_entity_type(&payload.entity_type).ok_or(StatusCode::BAD_REQUEST)?;
+    let field_type = CustomFieldType::parse(&payload.field_type).ok_or(StatusCode::BAD_REQUEST)?;
+    let key = payload.key.trim().to_string();
+    let label = payload.label.trim().to_string();
+    if !is_valid_key(&key) || label.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let options = clean_options(payload.options.unwrap_or_default());
+    if field_type.is_select() && options.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let existing = custom_field_definition::Entity::find()
+        .filter(custom_field_definition::Column::EntityType.eq(entity_type))
+        .filter(custom_field_definition::Column::Key.eq(key.clone()))
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if existing.is_some() {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    let now = Utc::now().naive_utc();
+    let definition = custom_field_definition::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entity_type: Set(entity_type.to_string()),
+        key: Set(key),
+        label: Set(label),
+        field_type: Set(field_type.as_str().to_string()),
+        options: Set(field_type.is_select().then(|| json!(options))),
+        is_required: Set(payload.is_required.unwrap_or(false)),
+        sort_order: Set(payload.sort_order.unwrap_or(0)),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(&state.db)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(definition))
+}
+
+pub async fn update_custom_field(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateCustomFieldRequest>,
+) -> Result<Json<custom_field_definition::Model>, StatusCode> {
+    let existing = custom_field_definition::Entity::find_by_id(id)
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let field_type =
+        CustomFieldType::parse(&existing.field_type).ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut active: custom_field_definition::ActiveModel = existing.into();
+    if let Some(label) = payload.label {
+        let label = label.trim().to_string();
+        if label.is_empty() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        active.label = Set(label);
+    }
+    if let Some(options) = payload.options {
+        let options = clean_options(options);
+        if !field_type.is_select() || options.is_empty() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        active.options = Set(Some(json!(options)));
+    }
+    if let Some(is_required) = payload.is_required {
+        active.is_required = Set(is_required);
+    }
+    if let Some(sort_order) = payload.sort_order {
+        active.sort_order = Set(sort_order);
+    }
+    active.updated_at = Set(Utc::now().naive_utc());
+
+    let updated = active
+        .update(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(updated))
+}
+
+/// Deleting a definition also strips its stored values from every record.
+pub async fn delete_custom_field(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let existing = custom_field_definition::Entity::find_by_id(id)
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let strip = Expr::cust_with_values("custom_fields - $1", [existing.key.clone()]);
+    if existing.entity_type == ENTITY_STARTUP {
+        startup::Entity::update_many()
+            .col_expr(startup::Column::CustomFields, strip)
+            .exec(&txn)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    } else {
+        contact::Entity::update_many()
+            .col_expr(contact::Column::CustomFields, strip)
+            .exec(&txn)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
+    custom_field_definition::Entity::delete_by_id(existing.id)
+        .exec(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    txn.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn load_definitions<C: ConnectionTrait>(
+    db: &C,
+    entity_type: &str,
+) -> Result<Vec<custom_field_definition::Model>, DbErr> {
+    custom_field_definition::Entity::find()
+        .filter(custom_field_definition::Column::EntityType.eq(entity_type))
+        .all(db)
+        .await
+}
+
+/// Validate `incoming` against the entity's definitions and merge it over the
+/// stored values. `None` leaves the stored values untouched.
+pub async fn resolve_custom_fields<C: ConnectionTrait>(
+    db: &C,
+    entity_type: &str,
+    current: &Value,
+    incoming: Option<&Map<String, Value>>,
+) -> Result<Value, StatusCode> {
+    let Some(incoming) = incoming else {
+        return Ok(current.clone());
+    };
+
+    let definitions = load_definitions(db, entity_type)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    apply_custom_field_values(&definitions, current, incoming).map_err(|err| {
+        tracing::debug!(error = %err, "rejected custom field values");
+        StatusCode::UNPROCESSABLE_ENTITY
+    })
+}
+
+/// Merge `incoming` values over `current`. A `null` (or blank text) removes
+/// the value; every value is checked against its definition's type.
+pub fn apply_custom_field_values(
+    definitions: &[custom_field_definition::Model],
+    current: &Value,
+    incoming: &Map<String, Value>,
+) -> Result<Value, CustomFieldError> {
+    let mut values = current.as_object().cloned().unwrap_or_default();
+
+    for (key, value) in incoming {
+        let definition = definitions
+            .iter()
+            .find(|definition| &definition.key == key)
+            .ok_or_else(|| CustomFieldError::UnknownField(key.clone()))?;
+
+        match normalize_value(definition, value)? {
+            Some(normalized) => {
+                values.insert(key.clone(), normalized);
+            }
+            None => {
+                values.remove(key);
+            }
+        }
+    }
+
+    for definition in definitions.iter().filter(|d| d.is_required) {
+        if !values.contains_key(&definition.key) {
+            return Err(CustomFieldError::Required(definition.key.clone()));
+        }
+    }
+
+    Ok(Value::Object(values))
+}
+
+fn normalize_value(
+    definition: &custom_field_definition::Model,
+    value: &Value,
+) -> Result<Option<Value>, CustomFieldError> {
+    let invalid = || CustomFieldError::InvalidValue(definition.key.clone());
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    let field_type = CustomFieldType::parse(&definition.field_type).ok_or_else(invalid)?;
+    let options: Vec<&str> = definition
+        .options
+        .as_ref()
+        .and_then(Value::as_array)
+        .map(|options| options.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    match field_type {
+        CustomFieldType::Text => {
+            let text = value.as_str().ok_or_else(invalid)?.trim();
+            Ok((!text.is_empty()).then(|| json!(text)))
+        }
+        CustomFieldType::Number => {
+            if value.is_number() {
+                Ok(Some(value.clone()))
+            } else {
+                Err(invalid())
+            }
+        }
+        CustomFieldType::Date => {
+            let raw = value.as_str().ok_or_else(invalid)?.trim();
+            let date = NaiveDate::parse_from_str(raw, "%Y-%m-%d").map_err(|_| invalid())?;
+            Ok(Some(json!(date.format("%Y-%m-%d").to_string())))
+        }
+        CustomFieldType::SingleSelect => {
+            let choice = value.as_str().ok_or_else(invalid)?;
+            if options.contains(&choice) {
+                Ok(Some(json!(choice)))
+            } else {
+                Err(invalid())
+            }
+        }
+        CustomFieldType::MultiSelect => {
+            let mut choices: Vec<&str> = Vec::new();
+            for item in value.as_array().ok_or_else(invalid)? {
+                let choice = item.as_str().ok_or_else(invalid)?;
+                if !options.contains(&choice) {
+                    return Err(invalid());
+                }
+                if !choices.contains(&choice) {
+                    choices.push(choice);
+                }
+            }
+            Ok((!choices.is_empty()).then(|| json!(choices)))
+        }
+    }
+}
+
+/// Build a list filter from a `custom_fields` query parameter holding a JSON
+/// object. Plain values match exactly (a string also matches inside a
+/// multi-select), arrays require all listed options, and `{"min", "max"}`
+/// objects give an inclusive range for number and date fields. Ranges on
+/// any other field, or with bounds of the wrong kind, are rejected.
+pub fn custom_field_filter<E: EntityTrait>(
+    entity: E,
+    column: E::Column,
+    definitions: &[custom_field_definition::Model],
+    raw: &str,
+) -> Result<Condition, StatusCode> {
+    let filters: Map<String, Value> =
+        serde_json::from_str(raw).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let qualified = format!("\"{}\".\"custom_fields\"", entity.table_name());
+    let mut condition = Condition::all();
+
+    for (key, value) in filters {
+        if !is_valid_key(&key) {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        let contains = |value: Value| {
+            Expr::col((entity, column)).contains(Expr::val(Value::Object(Map::from_iter([(
+                key.clone(),
+                value,
+            )]))))
+        };
+
+        condition = match value {
+            Value::Object(range) => {
+                let field_type = definitions
+                    .iter()
+                    .find(|definition| definition.key == key)
+                    .and_then(|definition| CustomFieldType::parse(&definition.field_type))
+                    .ok_or(StatusCode::BAD_REQUEST)?;
+                let mut range_condition = Condition::all();
+                for (bound, op) in [("min", ">="), ("max", "<=")] {
+                    let Some(limit) = range.get(bound) else {
+                        continue;
+                    };
+                    let expr = match (field_type, limit) {
+                        (CustomFieldType::Number, Value::Number(number)) => Expr::cust_with_values(
+                            format!("({} ->> $1)::numeric {} $2", qualified, op),
+                            [
+                                sea_orm::Value::from(key.clone()),
+                                sea_orm::Value::from(number.as_f64()),
+                            ],
+                        ),
+                        (CustomFieldType::Date, Value::String(text)) => Expr::cust_with_values(
+                            format!("({} ->> $1) {} $2", qualified, op),
+                            [key.clone(), text.clone()],
+                        ),
+                        _ => return Err(StatusCode::BAD_REQUEST),
+                    };
+                    range_condition = range_condition.add(expr);
+                }
+                condition.add(range_condition)
+            }
+            Value::String(text) => condition.add(
+                Condition::any()
+                    .add(contains(json!(text)))
+                    .add(contains(json!([text]))),
+            ),
+            Value::Null => return Err(StatusCode::BAD_REQUEST),
+            other => condition.add(contains(other)),
+        };
+    }
+
+    Ok(condition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(
+        key: &str,
+        field_type: &str,
+        options: Option<Value>,
+    ) -> custom_field_definition::Model {
+        let now = Utc::now().naive_utc();
+        custom_field_definition::Model {
+            id: Uuid::new_v4(),
+            entity_type: ENTITY_STARTUP.to_string(),
+            key: key.to_string(),
+            label: key.to_string(),
+            field_type: field_type.to_string(),
+            options,
+            is_required: false,
+            sort_order: 0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn definitions() -> Vec<custom_field_definition::Model> {
+        vec![
+            definition("country", "text", None),
+            definition("headcount", "number", None),
+            definition("founded_on", "date", None),
+            definition(
+                "funding_stage",
+                "single_select",
+                Some(json!(["Seed", "Series A"])),
+            ),
+            definition(
+                "channels",
+                "multi_select",
+                Some(json!(["Email", "LinkedIn"])),
+            ),
+        ]
+    }
+
+    fn apply(current: Value, incoming: Value) -> Result<Value, CustomFieldError> {
+        apply_custom_field_values(&definitions(), &current, incoming.as_object().unwrap())
+    }
+
+    #[test]
+    fn test_values_are_validated_and_merged() {
+        let result = apply(
+            json!({ "country": "Nigeria", "headcount": 12 }),
+            json!({
+                "headcount": 40,
+                "founded_on": "2019-02-01",
+                "funding_stage": "Seed",
+                "channels": ["Email", "Email", "LinkedIn"],
+                "country": null,
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result,
+            json!({
+                "headcount": 40,
+                "founded_on": "2019-02-01",
+                "funding_stage": "Seed",
+                "channels": ["Email", "LinkedIn"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        assert_eq!(
+            apply(json!({}), json!({ "unknown": "x" })),
+            Err(CustomFieldError::UnknownField("unknown".to_string()))
+        );
+        assert!(apply(json!({}), json!({ "headcount": "forty" })).is_err());
+        assert!(apply(json!({}), json!({ "founded_on": "01/02/2019" })).is_err());
+        assert!(apply(json!({}), json!({ "funding_stage": "Series Z" })).is_err());
+        assert!(apply(json!({}), json!({ "channels": ["Fax"] })).is_err());
+    }
+
+    #[test]
+    fn test_required_fields() {
+        let mut definitions = definitions();
+        definitions[0].is_required = true;
+        let empty = Map::new();
+
+        assert_eq!(
+            apply_custom_field_values(&definitions, &json!({}), &empty),
+            Err(CustomFieldError::Required("country".to_string()))
+        );
+        assert!(
+            apply_custom_field_values(&definitions, &json!({ "country": "Kenya" }), &empty).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_filter_rejects_bad_input() {
+        let definitions = definitions();
+        let filter = |raw: &str| {
+            custom_field_filter(
+                startup::Entity,
+                startup::Column::CustomFields,
+                &definitions,
+                raw,
+            )
+        };
+
+        assert!(filter("nope").is_err());
+        assert!(filter(r#"{"Bad Key": 1}"#).is_err());
+        assert!(filter(r#"{"headcount": {"min": 10}, "funding_stage": "Seed"}"#).is_ok());
+        assert!(filter(r#"{"founded_on": {"min": "2020-01-01"}}"#).is_ok());
+        // Ranges only apply to number and date fields, with matching bounds.
+        assert_eq!(
+            filter(r#"{"country": {"min": 10}}"#).err(),
+            Some(StatusCode::BAD_REQUEST)
+        );
+        assert!(filter(r#"{"headcount": {"min": "10"}}"#).is_err());
+        assert!(filter(r#"{"founded_on": {"max": 2020}}"#).is_err());
+        assert!(filter(r#"{"unknown": {"min": 1}}"#).is_err());
+    }
+}
@@ -15,7 +15,12 @@ pub struct Model {
     pub is_primary: bool,
     pub notes: Option<String>,
     pub is_trashed: bool,
+    /// When the contact was last moved to the trash; the retention purge
+    /// counts from here.
+    pub trashed_at: Option<DateTime>,
     pub owner_id: Option<Uuid>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub custom_fields: Json,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
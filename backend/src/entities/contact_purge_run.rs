@@ -0,0 +1,28 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Audit record of one run of the trashed-contact purge.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "contact_purge_run")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    /// `scheduled` or `manual`.
+    pub trigger: String,
+    pub triggered_by_id: Option<Uuid>,
+    pub retention_days: i32,
+    /// Contacts trashed before this were purged.
+    pub cutoff: DateTime,
+    pub purged_count: i32,
+    /// Id, name, startup and `trashed_at` of every purged contact.
+    #[sea_orm(column_type = "JsonBinary")]
+    pub contacts: Json,
+    pub error: Option<String>,
+    pub started_at: DateTime,
+    pub finished_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
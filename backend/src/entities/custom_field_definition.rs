@@ -0,0 +1,25 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "custom_field_definition")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub entity_type: String,
+    pub key: String,
+    pub label: String,
+    pub field_type: String,
+    // JSON array of allowed values for select fields
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub options: Option<Json>,
+    pub is_required: bool,
+    pub sort_order: i32,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
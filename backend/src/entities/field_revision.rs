@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// One changed field on a startup or contact. Contact revisions also carry
+/// the contact's `startup_id` so a startup's history includes its people.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "field_revision")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub startup_id: Option<Uuid>,
+    pub field: String,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub old_value: Option<Json>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub new_value: Option<Json>,
+    pub user_id: Option<Uuid>,
+    pub user_name: Option<String>,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::startup::Entity",
+        from = "Column::StartupId",
+        to = "super::startup::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Startup,
+}
+
+impl Related<super::startup::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Startup.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
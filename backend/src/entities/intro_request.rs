@@ -0,0 +1,45 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A request for a warm intro: a contact at a startup asked to introduce us
+/// to a specific person or to whoever holds a role.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "intro_request")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub startup_id: Uuid,
+    pub requester_contact_id: Option<Uuid>,
+    pub target_contact_id: Option<Uuid>,
+    pub target_role: Option<String>,
+    /// `requested`, `promised`, `made` or `declined`.
+    pub status: String,
+    pub notes: Option<String>,
+    /// Address that was CC'd when the intro was detected from synced mail.
+    pub introduced_email: Option<String>,
+    pub conversation_id: Option<Uuid>,
+    pub requested_by_id: Option<Uuid>,
+    pub requested_at: DateTime,
+    pub made_at: Option<DateTime>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::startup::Entity",
+        from = "Column::StartupId",
+        to = "super::startup::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Startup,
+}
+
+impl Related<super::startup::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Startup.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
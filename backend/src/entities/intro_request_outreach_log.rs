@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Links an intro request to the outreach log rows that chased it.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "intro_request_outreach_log")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub intro_request_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub outreach_log_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::intro_request::Entity",
+        from = "Column::IntroRequestId",
+        to = "super::intro_request::Column::Id",
+        on_delete = "Cascade"
+    )]
+    IntroRequest,
+    #[sea_orm(
+        belongs_to = "super::outreach_log::Entity",
+        from = "Column::OutreachLogId",
+        to = "super::outreach_log::Column::Id",
+        on_delete = "Cascade"
+    )]
+    OutreachLog,
+}
+
+impl Related<super::intro_request::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::IntroRequest.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
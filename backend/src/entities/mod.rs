@@ -1,16 +1,29 @@
 pub mod activity_event;
 pub mod contact;
+pub mod contact_identity;
+pub mod contact_purge_run;
 pub mod conversation;
+pub mod custom_field_definition;
 pub mod email_attachment;
 pub mod email_credential;
 pub mod email_provider_setting;
+pub mod field_revision;
 pub mod interview;
 pub mod interview_insight;
+pub mod intro_request;
+pub mod intro_request_outreach_log;
 pub mod message;
+pub mod newsroom_claim;
 pub mod outreach_log;
+pub mod owner_rotation;
 pub mod password_reset_token;
+pub mod saved_view;
 pub mod session;
 pub mod startup;
+pub mod tag;
+pub mod tag_assignment;
+pub mod task;
+pub mod url_check;
 pub mod user;
 pub mod weekly_activity_plan;
 pub mod weekly_metric_definition;
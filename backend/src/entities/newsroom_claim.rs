@@ -0,0 +1,58 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A claim link sent to a contact so they can claim their startup's newsroom.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "newsroom_claim")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub startup_id: Uuid,
+    /// Cleared when the contact is deleted; the claim is kept for the record.
+    pub contact_id: Option<Uuid>,
+    /// Address the link was sent to.
+    pub email: String,
+    pub created_by_id: Option<Uuid>,
+    pub message_id: Option<String>,
+    pub expires_at: DateTime,
+    pub claimed_at: Option<DateTime>,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::startup::Entity",
+        from = "Column::StartupId",
+        to = "super::startup::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Startup,
+    #[sea_orm(
+        belongs_to = "super::contact::Entity",
+        from = "Column::ContactId",
+        to = "super::contact::Column::Id",
+        on_delete = "SetNull"
+    )]
+    Contact,
+}
+
+impl Related<super::startup::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Startup.def()
+    }
+}
+
+impl Related<super::contact::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Contact.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at < chrono::Utc::now().naive_utc()
+    }
+}
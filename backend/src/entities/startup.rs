@@ -14,6 +14,18 @@ pub struct Model {
     pub last_contact_date: Option<DateTime>,
     pub next_step: Option<String>,
     pub admin_claimed: bool,
+    pub owner_id: Option<Uuid>,
+    pub is_trashed: bool,
+    pub trashed_at: Option<DateTime>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub custom_fields: Json,
+    pub signal_score: i32,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub signal_breakdown: Json,
+    pub website_broken: bool,
+    pub newsroom_url_broken: bool,
+    pub claimed_at: Option<DateTime>,
+    pub claimed_by_contact_id: Option<Uuid>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
@@ -0,0 +1,26 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "tag")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub name: String,
+    pub color: Option<String>,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::tag_assignment::Entity")]
+    TagAssignment,
+}
+
+impl Related<super::tag_assignment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagAssignment.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,82 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "task")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub title: String,
+    pub due_date: Date,
+    pub assignee_id: Option<Uuid>,
+    /// `open` or `done`.
+    pub status: String,
+    /// `manual`, or `stale_follow_up` for tasks created by the stale-lead sweep.
+    pub source: String,
+    pub startup_id: Option<Uuid>,
+    pub contact_id: Option<Uuid>,
+    pub interview_id: Option<Uuid>,
+    pub conversation_id: Option<Uuid>,
+    pub created_by_id: Option<Uuid>,
+    pub completed_at: Option<DateTime>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::startup::Entity",
+        from = "Column::StartupId",
+        to = "super::startup::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Startup,
+    #[sea_orm(
+        belongs_to = "super::contact::Entity",
+        from = "Column::ContactId",
+        to = "super::contact::Column::Id",
+        on_delete = "SetNull"
+    )]
+    Contact,
+    #[sea_orm(
+        belongs_to = "super::interview::Entity",
+        from = "Column::InterviewId",
+        to = "super::interview::Column::Id",
+        on_delete = "SetNull"
+    )]
+    Interview,
+    #[sea_orm(
+        belongs_to = "super::conversation::Entity",
+        from = "Column::ConversationId",
+        to = "super::conversation::Column::Id",
+        on_delete = "SetNull"
+    )]
+    Conversation,
+}
+
+impl Related<super::startup::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Startup.def()
+    }
+}
+
+impl Related<super::contact::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Contact.def()
+    }
+}
+
+impl Related<super::interview::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Interview.def()
+    }
+}
+
+impl Related<super::conversation::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Conversation.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,42 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Latest health check of a startup's website or newsroom URL.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "url_check")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub startup_id: Uuid,
+    /// `website` or `newsroom`.
+    pub kind: String,
+    pub url: String,
+    pub status_code: Option<i32>,
+    pub latency_ms: Option<i32>,
+    /// Where the URL ended up after redirects, when that differs from `url`.
+    pub redirect_url: Option<String>,
+    pub error: Option<String>,
+    pub is_broken: bool,
+    pub consecutive_failures: i32,
+    pub checked_at: DateTime,
+    pub last_ok_at: Option<DateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::startup::Entity",
+        from = "Column::StartupId",
+        to = "super::startup::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Startup,
+}
+
+impl Related<super::startup::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Startup.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,429 @@
+use crate::auth::middleware::AuthUser;
+use crate::entities::{contact, interview, interview_insight, outreach_log, startup};
+use crate::{apply_startup_sort, startup_list_filters, AppState, StartupListQuery};
+use axum::{
+    body::Body,
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::Response,
+};
+use chrono::{NaiveDateTime, Utc};
+use futures::stream;
+use rust_xlsxwriter::{Format, Workbook};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    Select,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::error;
+use uuid::Uuid;
+
+const EXPORT_BATCH_SIZE: u64 = 500;
+
+const EXPORT_HEADERS: [&str; 17] = [
+    "Startup ID",
+    "Name",
+    "Category",
+    "Website",
+    "Newsroom URL",
+    "Status",
+    "Last Contact Date",
+    "Next Step",
+    "Admin Claimed",
+    "Primary Contact",
+    "Primary Contact Role",
+    "Primary Contact Email",
+    "Latest Outreach Outcome",
+    "Latest Outreach Date",
+    "Interview Count",
+    "Latest Interest Level",
+    "Activation Candidate",
+];
+
+#[derive(Deserialize, Default)]
+pub struct ExportQuery {
+    pub format: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Csv,
+    Xlsx,
+}
+
+impl ExportFormat {
+    fn parse(value: Option<&str>) -> Option<Self> {
+        match value.map(|v| v.trim().to_lowercase()).as_deref() {
+            None | Some("") | Some("csv") => Some(ExportFormat::Csv),
+            Some("xlsx") => Some(ExportFormat::Xlsx),
+            _ => None,
+        }
+    }
+}
+
+/// One exported line: a startup plus the joined contact, outreach and
+/// insight columns.
+struct ExportRow {
+    startup: startup::Model,
+    primary_contact: Option<contact::Model>,
+    latest_outreach: Option<outreach_log::Model>,
+    interview_count: usize,
+    latest_insight: Option<interview_insight::Model>,
+}
+
+impl ExportRow {
+    fn cells(&self) -> [String; 17] {
+        let contact = self.primary_contact.as_ref();
+        let outreach = self.latest_outreach.as_ref();
+        let insight = self.latest_insight.as_ref();
+
+        [
+            self.startup.id.to_string(),
+            self.startup.name.clone(),
+            self.startup.category.clone().unwrap_or_default(),
+            self.startup.website.clone().unwrap_or_default(),
+            self.startup.newsroom_url.clone().unwrap_or_default(),
+            self.startup.status.clone(),
+            self.startup
+                .last_contact_date
+                .map(|d| d.to_string())
+                .unwrap_or_default(),
+            self.startup.next_step.clone().unwrap_or_default(),
+            yes_no(self.startup.admin_claimed),
+            contact.map(|c| c.name.clone()).unwrap_or_default(),
+            contact.map(|c| c.role.clone()).unwrap_or_default(),
+            contact.and_then(|c| c.email.clone()).unwrap_or_default(),
+            outreach.map(|o| o.outcome.clone()).unwrap_or_default(),
+            outreach
+                .map(|o| format_datetime(o.date))
+                .unwrap_or_default(),
+            self.interview_count.to_string(),
+            insight
+                .map(|i| i.interest_level.clone())
+                .unwrap_or_default(),
+            insight
+                .map(|i| yes_no(i.activation_candidate))
+                .unwrap_or_default(),
+        ]
+    }
+}
+
+/// Spreadsheets opening a CSV run cells starting with `=`, `+`, `-` or `@`
+/// (or a tab or carriage return in front of one) as formulas; a leading `'`
+/// keeps user text as text. XLSX cells are written as strings and need no
+/// escaping.
+fn escape_formula(value: String) -> String {
+    if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value
+    }
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "Yes" } else { "No" }.to_string()
+}
+
+fn format_datetime(value: NaiveDateTime) -> String {
+    value.format("%Y-%m-%d %H:%M").to_string()
+}
+
+/// GET /api/startups/export?format=csv|xlsx
+/// Accepts the same filter and sort parameters as GET /api/startups.
+pub async fn export_startups(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    list_query: Option<Query<StartupListQuery>>,
+    export_query: Option<Query<ExportQuery>>,
+) -> Result<Response, StatusCode> {
+    let params = list_query.map(|q| q.0).unwrap_or_default();
+    if params.trashed.unwrap_or(false) && !user.is_admin() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let export = export_query.map(|q| q.0).unwrap_or_default();
+    let format = ExportFormat::parse(export.format.as_deref()).ok_or(StatusCode::BAD_REQUEST)?;
+
+    let filters = startup_list_filters(&state.db, &params, &user).await?;
+    let select = apply_startup_sort(startup::Entity::find().filter(filters), &params)?;
+    let stamp = Utc::now().format("%Y%m%d");
+
+    match format {
+        ExportFormat::Csv => {
+            let body = Body::from_stream(csv_stream(state.db.clone(), select));
+            attachment_response(body, "text/csv", &format!("pipeline-{}.csv", stamp))
+        }
+        ExportFormat::Xlsx => {
+            let mut rows = Vec::new();
+            let mut page = 0;
+            loop {
+                let batch = load_batch(&state.db, &select, page).await.map_err(|err| {
+                    error!(error = ?err, "failed to load startups for export");
+                    StatusCode::INTERNAL_SERVER_ERROR
+                })?;
+                let done = (batch.len() as u64) < EXPORT_BATCH_SIZE;
+                rows.extend(batch);
+                if done {
+                    break;
+                }
+                page += 1;
+            }
+
+            let bytes = write_xlsx(&rows).map_err(|err| {
+                error!(error = ?err, "failed to build xlsx export");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            attachment_response(
+                Body::from(bytes),
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+                &format!("pipeline-{}.xlsx", stamp),
+            )
+        }
+    }
+}
+
+fn attachment_response(
+    body: Body,
+    content_type: &str,
+    file_name: &str,
+) -> Result<Response, StatusCode> {
+    let mut response = Response::new(body);
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        content_type
+            .parse()
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+    );
+    response.headers_mut().insert(
+        header::CONTENT_DISPOSITION,
+        format!("attachment; filename=\"{}\"", file_name)
+            .parse()
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+    );
+    Ok(response)
+}
+
+/// Stream CSV in batches so large pipelines are never held in memory at once.
+fn csv_stream(
+    db: DatabaseConnection,
+    select: Select<startup::Entity>,
+) -> impl futures::Stream<Item = Result<Vec<u8>, std::io::Error>> {
+    stream::unfold(Some(0u64), move |page| {
+        let db = db.clone();
+        let select = select.clone();
+        async move {
+            let page = page?;
+            let batch = match load_batch(&db, &select, page).await {
+                Ok(batch) => batch,
+                Err(err) => {
+                    error!(error = ?err, "failed to load startups for export");
+                    return Some((Err(std::io::Error::other(err.to_string())), None));
+                }
+            };
+
+            let next = if (batch.len() as u64) < EXPORT_BATCH_SIZE {
+                None
+            } else {
+                Some(page + 1)
+            };
+            Some((write_csv_chunk(&batch, page == 0), next))
+        }
+    })
+}
+
+fn write_csv_chunk(rows: &[ExportRow], include_headers: bool) -> Result<Vec<u8>, std::io::Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    if include_headers {
+        writer.write_record(EXPORT_HEADERS)?;
+    }
+    for row in rows {
+        writer.write_record(row.cells().map(escape_formula))?;
+    }
+    writer
+        .into_inner()
+        .map_err(|err| std::io::Error::other(err.to_string()))
+}
+
+fn write_xlsx(rows: &[ExportRow]) -> Result<Vec<u8>, rust_xlsxwriter::XlsxError> {
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("Pipeline")?;
+
+    let bold = Format::new().set_bold();
+    for (col, title) in EXPORT_HEADERS.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, *title, &bold)?;
+    }
+
+    for (idx, row) in rows.iter().enumerate() {
+        let line = idx as u32 + 1;
+        for (col, value) in row.cells().iter().enumerate() {
+            // Keep the interview count numeric so it can be summed in a sheet.
+            if EXPORT_HEADERS[col] == "Interview Count" {
+                sheet.write_number(line, col as u16, row.interview_count as f64)?;
+            } else {
+                sheet.write_string(line, col as u16, value)?;
+            }
+        }
+    }
+    sheet.set_freeze_panes(1, 0)?;
+    sheet.autofit();
+
+    workbook.save_to_buffer()
+}
+
+async fn load_batch(
+    db: &DatabaseConnection,
+    select: &Select<startup::Entity>,
+    page: u64,
+) -> Result<Vec<ExportRow>, DbErr> {
+    let startups = select
+        .clone()
+        .offset(page * EXPORT_BATCH_SIZE)
+        .limit(EXPORT_BATCH_SIZE)
+        .all(db)
+        .await?;
+    if startups.is_empty() {
+        return Ok(Vec::new());
+    }
+    let ids: Vec<Uuid> = startups.iter().map(|s| s.id).collect();
+
+    let mut primary_contacts: HashMap<Uuid, contact::Model> = HashMap::new();
+    let contacts = contact::Entity::find()
+        .filter(contact::Column::StartupId.is_in(ids.clone()))
+        .filter(contact::Column::IsTrashed.eq(false))
+        .order_by_desc(contact::Column::IsPrimary)
+        .order_by_asc(contact::Column::Name)
+        .all(db)
+        .await?;
+    for model in contacts {
+        primary_contacts.entry(model.startup_id).or_insert(model);
+    }
+
+    let mut latest_outreach: HashMap<Uuid, outreach_log::Model> = HashMap::new();
+    let outreach = outreach_log::Entity::find()
+        .filter(outreach_log::Column::StartupId.is_in(ids.clone()))
+        .order_by_desc(outreach_log::Column::Date)
+        .all(db)
+        .await?;
+    for model in outreach {
+        latest_outreach.entry(model.startup_id).or_insert(model);
+    }
+
+    let interviews = interview::Entity::find()
+        .filter(interview::Column::StartupId.is_in(ids))
+        .order_by_desc(interview::Column::Date)
+        .all(db)
+        .await?;
+    let mut interview_counts: HashMap<Uuid, usize> = HashMap::new();
+    for model in &interviews {
+        *interview_counts.entry(model.startup_id).or_default() += 1;
+    }
+
+    let mut insights: HashMap<Uuid, interview_insight::Model> = interview_insight::Entity::find()
+        .filter(interview_insight::Column::InterviewId.is_in(interviews.iter().map(|i| i.id)))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| (model.interview_id, model))
+        .collect();
+    // Interviews are newest first, so the first insight seen per startup wins.
+    let mut latest_insights: HashMap<Uuid, interview_insight::Model> = HashMap::new();
+    for model in &interviews {
+        if latest_insights.contains_key(&model.startup_id) {
+            continue;
+        }
+        if let Some(insight) = insights.remove(&model.id) {
+            latest_insights.insert(model.startup_id, insight);
+        }
+    }
+
+    Ok(startups
+        .into_iter()
+        .map(|startup| ExportRow {
+            primary_contact: primary_contacts.remove(&startup.id),
+            latest_outreach: latest_outreach.remove(&startup.id),
+            interview_count: interview_counts.get(&startup.id).copied().unwrap_or(0),
+            latest_insight: latest_insights.remove(&startup.id),
+            startup,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_format_parsing() {
+        assert!(ExportFormat::parse(None) == Some(ExportFormat::Csv));
+        assert!(ExportFormat::parse(Some("XLSX")) == Some(ExportFormat::Xlsx));
+        assert!(ExportFormat::parse(Some("pdf")).is_none());
+    }
+
+    #[test]
+    fn test_escape_formula() {
+        for value in [
+            "=HYPERLINK(\"x\")",
+            "+1",
+            "-2+3",
+            "@SUM(A1)",
+            "\t=1",
+            "\r=1",
+        ] {
+            assert_eq!(escape_formula(value.to_string()), format!("'{value}"));
+        }
+        assert_eq!(escape_formula("Paystack".to_string()), "Paystack");
+        assert_eq!(escape_formula("2025-05-01".to_string()), "2025-05-01");
+        assert_eq!(escape_formula(String::new()), "");
+
+        let now = chrono::NaiveDate::from_ymd_opt(2025, 5, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let row = ExportRow {
+            startup: startup::Model {
+                id: Uuid::new_v4(),
+                name: "=Paystack".to_string(),
+                category: None,
+                website: None,
+                newsroom_url: None,
+                status: "Lead".to_string(),
+                last_contact_date: None,
+                next_step: Some("-follow up".to_string()),
+                admin_claimed: false,
+                owner_id: None,
+                is_trashed: false,
+                trashed_at: None,
+                custom_fields: serde_json::json!({}),
+                signal_score: 0,
+                signal_breakdown: serde_json::json!([]),
+                website_broken: false,
+                newsroom_url_broken: false,
+                claimed_at: None,
+                claimed_by_contact_id: None,
+                created_at: now,
+                updated_at: now,
+            },
+            primary_contact: None,
+            latest_outreach: None,
+            interview_count: 0,
+            latest_insight: None,
+        };
+
+        let csv =
+            String::from_utf8(write_csv_chunk(std::slice::from_ref(&row), false).unwrap()).unwrap();
+        assert!(csv.contains("'=Paystack") && csv.contains("'-follow up"));
+
+        // XLSX strings are stored as text, so they keep their exact value.
+        let xlsx = write_xlsx(&[row]).unwrap();
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(xlsx)).unwrap();
+        let mut strings = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name("xl/sharedStrings.xml").unwrap(),
+            &mut strings,
+        )
+        .unwrap();
+        assert!(strings.contains(">=Paystack<") && strings.contains(">-follow up<"));
+        assert!(!strings.contains("'"));
+    }
+}
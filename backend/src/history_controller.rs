@@ -0,0 +1,459 @@
+use crate::auth::middleware::AuthUser;
+use crate::contact_identities_controller::sync_primary_identities;
+use crate::custom_fields_controller::{resolve_custom_fields, ENTITY_CONTACT, ENTITY_STARTUP};
+use crate::entities::{contact, field_revision, startup, user};
+use crate::owners_controller::find_assignable_owner;
+use crate::{can_edit_contact, find_active_startup, user_display_name, AppState};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
+use uuid::Uuid;
+
+const CUSTOM_FIELD_PREFIX: &str = "custom_fields.";
+
+/// Startup fields whose edits are recorded. Custom field values are tracked
+/// per key as `custom_fields.<key>`.
+const STARTUP_TRACKED_FIELDS: &[&str] = &[
+    "name",
+    "category",
+    "website",
+    "newsroom_url",
+    "status",
+    "last_contact_date",
+    "next_step",
+    "admin_claimed",
+    "claimed_by_contact_id",
+    "owner_id",
+];
+
+const CONTACT_TRACKED_FIELDS: &[&str] = &[
+    "name",
+    "role",
+    "email",
+    "phone",
+    "linkedin_url",
+    "is_primary",
+    "notes",
+    "owner_id",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+struct FieldChange {
+    field: String,
+    old_value: Value,
+    new_value: Value,
+}
+
+/// Compare the tracked fields of two serialized models.
+fn diff_fields(before: &Value, after: &Value, tracked: &[&str]) -> Vec<FieldChange> {
+    let mut changes: Vec<FieldChange> = tracked
+        .iter()
+        .filter_map(|field| {
+            let old_value = before.get(field).cloned().unwrap_or(Value::Null);
+            let new_value = after.get(field).cloned().unwrap_or(Value::Null);
+            (old_value != new_value).then(|| FieldChange {
+                field: field.to_string(),
+                old_value,
+                new_value,
+            })
+        })
+        .collect();
+
+    let empty = Map::new();
+    let before_custom = before
+        .get("custom_fields")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let after_custom = after
+        .get("custom_fields")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let mut keys: Vec<&String> = before_custom.keys().chain(after_custom.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    for key in keys {
+        let old_value = before_custom.get(key).cloned().unwrap_or(Value::Null);
+        let new_value = after_custom.get(key).cloned().unwrap_or(Value::Null);
+        if old_value != new_value {
+            changes.push(FieldChange {
+                field: format!("{CUSTOM_FIELD_PREFIX}{key}"),
+                old_value,
+                new_value,
+            });
+        }
+    }
+
+    changes
+}
+
+async fn record_revisions<C: ConnectionTrait>(
+    db: &C,
+    entity_type: &str,
+    entity_id: Uuid,
+    startup_id: Uuid,
+    changes: Vec<FieldChange>,
+    actor: Option<&user::Model>,
+) -> Result<(), DbErr> {
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let now = Utc::now().naive_utc();
+    let rows = changes
+        .into_iter()
+        .map(|change| field_revision::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            entity_type: Set(entity_type.to_string()),
+            entity_id: Set(entity_id),
+            startup_id: Set(Some(startup_id)),
+            field: Set(change.field),
+            old_value: Set(Some(change.old_value)),
+            new_value: Set(Some(change.new_value)),
+            user_id: Set(actor.map(|user| user.id)),
+            user_name: Set(actor.map(user_display_name)),
+            created_at: Set(now),
+        });
+    field_revision::Entity::insert_many(rows)
+        .exec_without_returning(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Record every tracked field that differs between `before` and `after`.
+pub async fn record_startup_revisions<C: ConnectionTrait>(
+    db: &C,
+    before: &startup::Model,
+    after: &startup::Model,
+    actor: Option<&user::Model>,
+) -> Result<(), DbErr> {
+    let changes = diff_fields(
+        &serde_json::to_value(before).unwrap_or_default(),
+        &serde_json::to_value(after).unwrap_or_default(),
+        STARTUP_TRACKED_FIELDS,
+    );
+    record_revisions(db, ENTITY_STARTUP, after.id, after.id, changes, actor).await
+}
+
+pub async fn record_contact_revisions<C: ConnectionTrait>(
+    db: &C,
+    before: &contact::Model,
+    after: &contact::Model,
+    actor: Option<&user::Model>,
+) -> Result<(), DbErr> {
+    let changes = diff_fields(
+        &serde_json::to_value(before).unwrap_or_default(),
+        &serde_json::to_value(after).unwrap_or_default(),
+        CONTACT_TRACKED_FIELDS,
+    );
+    record_revisions(
+        db,
+        ENTITY_CONTACT,
+        after.id,
+        after.startup_id,
+        changes,
+        actor,
+    )
+    .await
+}
+
+#[derive(Deserialize, Default)]
+pub struct HistoryQuery {
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+    /// `startup` or `contact`; both by default.
+    pub entity_type: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct FieldRevisionResponse {
+    pub id: Uuid,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    /// Startup or contact name as it is now.
+    pub entity_name: Option<String>,
+    pub field: String,
+    pub old_value: Option<Value>,
+    pub new_value: Option<Value>,
+    pub user_id: Option<Uuid>,
+    pub user_name: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Serialize)]
+pub struct HistoryResponse {
+    pub total: u64,
+    pub page: u32,
+    pub page_size: u32,
+    pub results: Vec<FieldRevisionResponse>,
+}
+
+/// GET /api/startups/:id/history
+/// Field changes on the startup and its contacts, newest first.
+pub async fn get_startup_history(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    Path(id): Path<Uuid>,
+    query: Option<Query<HistoryQuery>>,
+) -> Result<Json<HistoryResponse>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    let existing = find_active_startup(&state.db, id).await?;
+
+    let page = params.page.unwrap_or(1).max(1);
+    let page_size = params.page_size.unwrap_or(20).clamp(10, 100);
+
+    let mut select = field_revision::Entity::find()
+        .filter(field_revision::Column::StartupId.eq(id))
+        .order_by_desc(field_revision::Column::CreatedAt)
+        .order_by_asc(field_revision::Column::Field);
+    if let Some(entity_type) = params.entity_type.as_deref().map(str::trim) {
+        if entity_type != ENTITY_STARTUP && entity_type != ENTITY_CONTACT {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        select = select.filter(field_revision::Column::EntityType.eq(entity_type));
+    }
+
+    let paginator = select.paginate(&state.db, page_size as u64);
+    let total = paginator
+        .num_items()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let revisions = paginator
+        .fetch_page((page - 1) as u64)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let contact_ids: Vec<Uuid> = revisions
+        .iter()
+        .filter(|revision| revision.entity_type == ENTITY_CONTACT)
+        .map(|revision| revision.entity_id)
+        .collect();
+    let contact_names: HashMap<Uuid, String> = contact::Entity::find()
+        .filter(contact::Column::Id.is_in(contact_ids))
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .into_iter()
+        .map(|contact| (contact.id, contact.name))
+        .collect();
+
+    let results = revisions
+        .into_iter()
+        .map(|revision| FieldRevisionResponse {
+            entity_name: if revision.entity_type == ENTITY_STARTUP {
+                Some(existing.name.clone())
+            } else {
+                contact_names.get(&revision.entity_id).cloned()
+            },
+            id: revision.id,
+            entity_type: revision.entity_type,
+            entity_id: revision.entity_id,
+            field: revision.field,
+            old_value: revision.old_value,
+            new_value: revision.new_value,
+            user_id: revision.user_id,
+            user_name: revision.user_name,
+            created_at: revision.created_at,
+        })
+        .collect();
+
+    Ok(Json(HistoryResponse {
+        total,
+        page,
+        page_size,
+        results,
+    }))
+}
+
+/// POST /api/startups/:id/history/:revision_id/revert
+/// Put the field a revision changed back to its previous value. The revert
+/// is itself recorded as a new revision. Stage changes are not reverted here;
+/// they go through the transition endpoint so the pipeline rules apply.
+pub async fn revert_field_revision(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path((id, revision_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, StatusCode> {
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let current_startup = find_active_startup(&txn, id).await?;
+    let revision = field_revision::Entity::find_by_id(revision_id)
+        .one(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .filter(|revision| revision.startup_id == Some(id))
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let old_value = revision.old_value.clone().unwrap_or(Value::Null);
+
+    if revision.field.strip_prefix(CUSTOM_FIELD_PREFIX).is_none() {
+        let tracked = match revision.entity_type.as_str() {
+            ENTITY_STARTUP => STARTUP_TRACKED_FIELDS,
+            _ => CONTACT_TRACKED_FIELDS,
+        };
+        if revision.field == "status" || !tracked.contains(&revision.field.as_str()) {
+            return Err(StatusCode::UNPROCESSABLE_ENTITY);
+        }
+        if revision.field == "owner_id" {
+            if let Some(owner_id) = old_value.as_str() {
+                let owner_id = Uuid::parse_str(owner_id).map_err(|_| StatusCode::BAD_REQUEST)?;
+                find_assignable_owner(&txn, owner_id).await?;
+            }
+        }
+    }
+
+    match revision.entity_type.as_str() {
+        ENTITY_STARTUP if revision.entity_id == id => {
+            let (reverted, column) = revert_model(
+                &txn,
+                ENTITY_STARTUP,
+                &current_startup,
+                &current_startup.custom_fields,
+                &revision.field,
+                old_value,
+            )
+            .await?;
+            let mut active: startup::ActiveModel = reverted.into();
+            active.reset(startup::Column::from_str(&column).map_err(|_| StatusCode::BAD_REQUEST)?);
+            active.updated_at = Set(Utc::now().naive_utc());
+            let updated = active
+                .update(&txn)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            record_startup_revisions(&txn, &current_startup, &updated, Some(&user))
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        }
+        ENTITY_CONTACT => {
+            let current = contact::Entity::find_by_id(revision.entity_id)
+                .one(&txn)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .filter(|contact| contact.startup_id == id)
+                .ok_or(StatusCode::NOT_FOUND)?;
+            if !can_edit_contact(&user, &current) {
+                return Err(StatusCode::FORBIDDEN);
+            }
+            let (reverted, column) = revert_model(
+                &txn,
+                ENTITY_CONTACT,
+                &current,
+                &current.custom_fields,
+                &revision.field,
+                old_value,
+            )
+            .await?;
+            let mut active: contact::ActiveModel = reverted.into();
+            active.reset(contact::Column::from_str(&column).map_err(|_| StatusCode::BAD_REQUEST)?);
+            let updated = active
+                .update(&txn)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            sync_primary_identities(&txn, &updated)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            record_contact_revisions(&txn, &current, &updated, Some(&user))
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        }
+        _ => return Err(StatusCode::NOT_FOUND),
+    }
+
+    txn.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Rebuild `current` with one field set back to `value`, returning the new
+/// model and the column that has to be written.
+async fn revert_model<C, M>(
+    db: &C,
+    entity_type: &str,
+    current: &M,
+    current_custom_fields: &Value,
+    field: &str,
+    value: Value,
+) -> Result<(M, String), StatusCode>
+where
+    C: ConnectionTrait,
+    M: Serialize + serde::de::DeserializeOwned,
+{
+    let mut json = serde_json::to_value(current).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let object = json
+        .as_object_mut()
+        .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let column = match field.strip_prefix(CUSTOM_FIELD_PREFIX) {
+        Some(key) => {
+            // Re-validate against the current definition; a deleted field
+            // or a value that no longer fits is rejected with 422.
+            let mut patch = Map::new();
+            patch.insert(key.to_string(), value);
+            let values =
+                resolve_custom_fields(db, entity_type, current_custom_fields, Some(&patch)).await?;
+            object.insert("custom_fields".to_string(), values);
+            "custom_fields".to_string()
+        }
+        None => {
+            object.insert(field.to_string(), value);
+            field.to_string()
+        }
+    };
+
+    let reverted = serde_json::from_value(json).map_err(|_| StatusCode::UNPROCESSABLE_ENTITY)?;
+    Ok((reverted, column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_fields_tracks_columns_and_custom_keys() {
+        let before = json!({
+            "name": "Paystack",
+            "website": "paystack.com",
+            "updated_at": "2025-03-01T09:00:00",
+            "custom_fields": { "stage": "Seed", "headcount": 10 },
+        });
+        let after = json!({
+            "name": "Paystack",
+            "website": "paystack.co",
+            "updated_at": "2025-03-02T09:00:00",
+            "custom_fields": { "stage": "Series A", "region": "West Africa" },
+        });
+
+        let changes = diff_fields(&before, &after, &["name", "website"]);
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+
+        assert_eq!(
+            fields,
+            vec![
+                "website",
+                "custom_fields.headcount",
+                "custom_fields.region",
+                "custom_fields.stage"
+            ]
+        );
+        assert_eq!(changes[1].old_value, json!(10));
+        assert_eq!(changes[1].new_value, Value::Null);
+        assert_eq!(changes[3].new_value, json!("Series A"));
+    }
+}
@@ -0,0 +1,1179 @@
+use crate::auth::middleware::AuthUser;
+use crate::contact_identities_controller::sync_primary_identities;
+use crate::custom_fields_controller::{
+    apply_custom_field_values, load_definitions, ENTITY_CONTACT, ENTITY_STARTUP,
+};
+use crate::entities::{contact, startup, user};
+use crate::history_controller::{record_contact_revisions, record_startup_revisions};
+use crate::owners_controller::NewStartupOwners;
+use crate::pipeline::PipelineStage;
+use crate::{
+    record_activity_event, user_display_name, ActivityEventInput, AppState,
+    ACTIVITY_CONTACT_CREATED, ACTIVITY_STARTUP_CREATED,
+};
+use axum::{extract::State, http::StatusCode, Json};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map};
+use std::collections::HashMap;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+const MAX_IMPORT_ROWS: usize = 5000;
+pub const DEFAULT_CONTACT_ROLE: &str = "Unknown";
+
+/// Model field a CSV column can be mapped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum ImportField {
+    #[serde(rename = "startup.name")]
+    StartupName,
+    #[serde(rename = "startup.category")]
+    StartupCategory,
+    #[serde(rename = "startup.website")]
+    StartupWebsite,
+    #[serde(rename = "startup.newsroom_url")]
+    StartupNewsroomUrl,
+    #[serde(rename = "startup.status")]
+    StartupStatus,
+    #[serde(rename = "contact.name")]
+    ContactName,
+    #[serde(rename = "contact.role")]
+    ContactRole,
+    #[serde(rename = "contact.email")]
+    ContactEmail,
+    #[serde(rename = "contact.phone")]
+    ContactPhone,
+    #[serde(rename = "contact.linkedin_url")]
+    ContactLinkedinUrl,
+    #[serde(rename = "contact.notes")]
+    ContactNotes,
+    #[serde(rename = "contact.is_primary")]
+    ContactIsPrimary,
+}
+
+#[derive(Deserialize)]
+pub struct ImportRequest {
+    pub csv: String,
+    /// CSV header name -> model field.
+    pub mapping: HashMap<String, ImportField>,
+    #[serde(default = "default_dry_run")]
+    pub dry_run: bool,
+    pub default_status: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportAction {
+    Create,
+    Update,
+    Unchanged,
+    Conflict,
+    /// The row itself is unusable, e.g. it has no name or an unknown stage.
+    Invalid,
+}
+
+#[derive(Serialize)]
+pub struct ImportRowReport {
+    pub row: usize,
+    pub startup_action: ImportAction,
+    pub startup_id: Option<Uuid>,
+    pub startup_name: Option<String>,
+    pub contact_action: Option<ImportAction>,
+    pub contact_id: Option<Uuid>,
+    pub contact_name: Option<String>,
+    pub messages: Vec<String>,
+}
+
+#[derive(Serialize, Default)]
+pub struct ImportSummary {
+    pub total_rows: usize,
+    pub startups_created: usize,
+    pub startups_updated: usize,
+    pub contacts_created: usize,
+    pub contacts_updated: usize,
+    pub conflicts: usize,
+    pub invalid: usize,
+}
+
+#[derive(Serialize)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub committed: bool,
+    pub summary: ImportSummary,
+    pub rows: Vec<ImportRowReport>,
+}
+
+pub fn default_dry_run() -> bool {
+    true
+}
+
+/// POST /api/startups/import
+/// Import startups and contacts from CSV. Defaults to a dry run that only
+/// reports what would change; pass `dry_run: false` to commit.
+pub async fn import_startups(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Json(payload): Json<ImportRequest>,
+) -> Result<Json<ImportReport>, StatusCode> {
+    if !payload
+        .mapping
+        .values()
+        .any(|field| *field == ImportField::StartupName)
+    {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let default_status = match payload.default_status.as_deref() {
+        Some(value) => PipelineStage::parse(value).ok_or(StatusCode::BAD_REQUEST)?,
+        None => PipelineStage::Lead,
+    };
+
+    let rows = parse_csv(&payload.csv, &payload.mapping).map_err(|err| {
+        warn!(error = %err, "failed to parse import csv");
+        StatusCode::BAD_REQUEST
+    })?;
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    let existing_startups = startup::Entity::find()
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let existing_contacts = contact::Entity::find()
+        .filter(contact::Column::IsTrashed.eq(false))
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let blockers = CreateBlockers {
+        startup: required_custom_field_error(&state.db, ENTITY_STARTUP).await?,
+        contact: required_custom_field_error(&state.db, ENTITY_CONTACT).await?,
+    };
+
+    let plan = build_plan(
+        rows,
+        existing_startups,
+        existing_contacts,
+        default_status,
+        &blockers,
+    );
+
+    if payload.dry_run {
+        return Ok(Json(plan.into_report(true, false)));
+    }
+
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut owners = NewStartupOwners::load(&txn, state.owner_assignment, &user)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    apply_plan(&txn, &plan, &user, &mut owners)
+        .await
+        .map_err(|err| {
+            error!(error = ?err, "failed to apply startup import");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    owners
+        .save(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    txn.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(plan.into_report(false, true)))
+}
+
+/// Why the import cannot create new startups or contacts. Imported records
+/// start without custom field values, so a required custom field blocks them.
+#[derive(Default)]
+struct CreateBlockers {
+    startup: Option<String>,
+    contact: Option<String>,
+}
+
+async fn required_custom_field_error<C: ConnectionTrait>(
+    db: &C,
+    entity_type: &str,
+) -> Result<Option<String>, StatusCode> {
+    let definitions = load_definitions(db, entity_type)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(
+        apply_custom_field_values(&definitions, &json!({}), &Map::new())
+            .err()
+            .map(|err| err.to_string()),
+    )
+}
+
+#[derive(Debug, Default, Clone)]
+struct ImportRow {
+    row: usize,
+    values: HashMap<ImportField, String>,
+}
+
+impl ImportRow {
+    fn get(&self, field: ImportField) -> Option<&str> {
+        self.values
+            .get(&field)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+fn parse_csv(
+    raw: &str,
+    mapping: &HashMap<String, ImportField>,
+) -> Result<Vec<ImportRow>, csv::Error> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(raw.as_bytes());
+
+    let headers = reader.headers()?.clone();
+    let columns: Vec<(usize, ImportField)> = headers
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, header)| mapping.get(header).map(|field| (idx, *field)))
+        .collect();
+
+    let mut rows = Vec::new();
+    for (idx, record) in reader.records().enumerate() {
+        let record = record?;
+        let mut row = ImportRow {
+            // Header is line 1, so the first data row is line 2.
+            row: idx + 2,
+            values: HashMap::new(),
+        };
+        for (column, field) in &columns {
+            if let Some(value) = record.get(*column) {
+                row.values.insert(*field, value.to_string());
+            }
+        }
+        if row.values.values().all(|value| value.trim().is_empty()) {
+            continue;
+        }
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+/// Reduce a website to a bare lowercase domain so "https://www.paystack.com/about"
+/// and "paystack.com" match.
+pub fn normalize_domain(website: &str) -> Option<String> {
+    let lowered = website.trim().to_lowercase();
+    let without_scheme = lowered
+        .strip_prefix("https://")
+        .or_else(|| lowered.strip_prefix("http://"))
+        .unwrap_or(&lowered);
+    let host = without_scheme
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+    let host = host.strip_prefix("www.").unwrap_or(host);
+
+    if host.is_empty() || !host.contains('.') {
+        None
+    } else {
+        Some(host.to_string())
+    }
+}
+
+fn normalize_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum StartupRef {
+    Existing(usize),
+    New(usize),
+}
+
+struct NewStartup {
+    id: Uuid,
+    name: String,
+    category: Option<String>,
+    website: Option<String>,
+    newsroom_url: Option<String>,
+    status: PipelineStage,
+    /// Whether a row set the status, rather than the import default.
+    status_from_row: bool,
+}
+
+struct StartupUpdate {
+    index: usize,
+    category: Option<String>,
+    website: Option<String>,
+    newsroom_url: Option<String>,
+}
+
+#[derive(Clone)]
+struct ContactFields {
+    name: String,
+    role: Option<String>,
+    email: Option<String>,
+    phone: Option<String>,
+    linkedin_url: Option<String>,
+    notes: Option<String>,
+    is_primary: Option<bool>,
+}
+
+enum ContactChange {
+    Create {
+        id: Uuid,
+        startup: StartupRef,
+        fields: ContactFields,
+    },
+    Update {
+        existing: Box<contact::Model>,
+        fields: ContactFields,
+    },
+}
+
+struct PlannedRow {
+    row: usize,
+    startup: Option<StartupRef>,
+    startup_action: ImportAction,
+    contact_action: Option<ImportAction>,
+    contact_id: Option<Uuid>,
+    contact_name: Option<String>,
+    messages: Vec<String>,
+}
+
+struct ImportPlan {
+    existing: Vec<startup::Model>,
+    new_startups: Vec<NewStartup>,
+    updates: Vec<StartupUpdate>,
+    contacts: Vec<ContactChange>,
+    rows: Vec<PlannedRow>,
+}
+
+fn build_plan(
+    rows: Vec<ImportRow>,
+    existing: Vec<startup::Model>,
+    existing_contacts: Vec<contact::Model>,
+    default_status: PipelineStage,
+    blockers: &CreateBlockers,
+) -> ImportPlan {
+    let mut by_name: HashMap<String, StartupRef> = HashMap::new();
+    let mut by_domain: HashMap<String, StartupRef> = HashMap::new();
+    for (idx, model) in existing.iter().enumerate() {
+        by_name
+            .entry(normalize_name(&model.name))
+            .or_insert(StartupRef::Existing(idx));
+        if let Some(domain) = model.website.as_deref().and_then(normalize_domain) {
+            by_domain.entry(domain).or_insert(StartupRef::Existing(idx));
+        }
+    }
+
+    let startup_index: HashMap<Uuid, usize> = existing
+        .iter()
+        .enumerate()
+        .map(|(idx, model)| (model.id, idx))
+        .collect();
+    let mut contacts_by_email: HashMap<(usize, String), contact::Model> = HashMap::new();
+    let mut contacts_by_name: HashMap<(usize, String), contact::Model> = HashMap::new();
+    for model in existing_contacts {
+        let Some(idx) = startup_index.get(&model.startup_id) else {
+            continue;
+        };
+        contacts_by_name
+            .entry((*idx, normalize_name(&model.name)))
+            .or_insert_with(|| model.clone());
+        if let Some(email) = &model.email {
+            contacts_by_email
+                .entry((*idx, email.trim().to_lowercase()))
+                .or_insert(model);
+        }
+    }
+
+    let mut plan = ImportPlan {
+        existing,
+        new_startups: Vec::new(),
+        updates: Vec::new(),
+        contacts: Vec::new(),
+        rows: Vec::new(),
+    };
+    let mut updated: HashMap<usize, usize> = HashMap::new();
+    let mut planned_contact_emails: HashMap<(StartupRef, String), Uuid> = HashMap::new();
+    let mut planned_contact_names: HashMap<(StartupRef, String), Uuid> = HashMap::new();
+
+    for row in rows {
+        let mut planned = PlannedRow {
+            row: row.row,
+            startup: None,
+            startup_action: ImportAction::Conflict,
+            contact_action: None,
+            contact_id: None,
+            contact_name: None,
+            messages: Vec::new(),
+        };
+
+        let Some(name) = row.get(ImportField::StartupName) else {
+            planned.startup_action = ImportAction::Invalid;
+            planned.messages.push("missing startup name".to_string());
+            plan.rows.push(planned);
+            continue;
+        };
+
+        let status = match row.get(ImportField::StartupStatus) {
+            Some(value) => match PipelineStage::parse(value) {
+                Some(stage) => Some(stage),
+                None => {
+                    planned.startup_action = ImportAction::Invalid;
+                    planned
+                        .messages
+                        .push(format!("unknown pipeline stage \"{}\"", value));
+                    plan.rows.push(planned);
+                    continue;
+                }
+            },
+            None => None,
+        };
+
+        let website = row.get(ImportField::StartupWebsite).map(str::to_string);
+        let domain = website.as_deref().and_then(normalize_domain);
+        let name_match = by_name.get(&normalize_name(name)).copied();
+        let domain_match = domain.as_ref().and_then(|d| by_domain.get(d)).copied();
+
+        let target = match (name_match, domain_match) {
+            (Some(a), Some(b)) if a != b => {
+                planned.messages.push(format!(
+                    "name matches \"{}\" but website matches \"{}\"",
+                    plan.startup_name(a),
+                    plan.startup_name(b)
+                ));
+                plan.rows.push(planned);
+                continue;
+            }
+            (Some(found), _) | (None, Some(found)) => Some(found),
+            (None, None) => None,
+        };
+
+        if let Some(StartupRef::Existing(idx)) = target {
+            if plan.existing[idx].is_trashed {
+                planned.messages.push(format!(
+                    "matches trashed startup \"{}\"; restore it first",
+                    plan.existing[idx].name
+                ));
+                plan.rows.push(planned);
+                continue;
+            }
+        }
+        if let (None, Some(reason)) = (target, &blockers.startup) {
+            planned.startup_action = ImportAction::Invalid;
+            planned
+                .messages
+                .push(format!("cannot create startup: {reason}"));
+            plan.rows.push(planned);
+            continue;
+        }
+
+        let category = row.get(ImportField::StartupCategory).map(str::to_string);
+        let newsroom_url = row.get(ImportField::StartupNewsroomUrl).map(str::to_string);
+
+        let startup_ref = match target {
+            Some(StartupRef::Existing(idx)) => {
+                let model = &plan.existing[idx];
+                // Only a different domain counts as a website change, not a
+                // different spelling of the same one.
+                let existing_domain = model.website.as_deref().and_then(normalize_domain);
+                let update = StartupUpdate {
+                    index: idx,
+                    category: category.filter(|v| model.category.as_deref() != Some(v)),
+                    website: website.filter(|_| domain != existing_domain),
+                    newsroom_url: newsroom_url.filter(|v| model.newsroom_url.as_deref() != Some(v)),
+                };
+                if let Some(stage) = status {
+                    if stage.as_str() != model.status {
+                        planned.messages.push(format!(
+                            "status \"{}\" ignored for existing startup; use the transition endpoint",
+                            stage
+                        ));
+                    }
+                }
+                let has_changes = update.category.is_some()
+                    || update.website.is_some()
+                    || update.newsroom_url.is_some();
+                if has_changes {
+                    match updated.get(&idx) {
+                        Some(pos) => {
+                            let prior = &mut plan.updates[*pos];
+                            prior.category = update.category.or(prior.category.take());
+                            prior.website = update.website.or(prior.website.take());
+                            prior.newsroom_url = update.newsroom_url.or(prior.newsroom_url.take());
+                        }
+                        None => {
+                            updated.insert(idx, plan.updates.len());
+                            plan.updates.push(update);
+                        }
+                    }
+                    planned.startup_action = ImportAction::Update;
+                } else {
+                    planned.startup_action = ImportAction::Unchanged;
+                }
+                StartupRef::Existing(idx)
+            }
+            Some(new_ref @ StartupRef::New(idx)) => {
+                // A later row for a startup this import creates fills in the
+                // fields earlier rows left empty.
+                let new_startup = &mut plan.new_startups[idx];
+                let mut filled = false;
+                for (label, slot, incoming) in [
+                    ("category", &mut new_startup.category, category),
+                    ("website", &mut new_startup.website, website),
+                    ("newsroom URL", &mut new_startup.newsroom_url, newsroom_url),
+                ] {
+                    filled |= fill_new_field(label, slot, incoming, &mut planned.messages);
+                }
+                if let Some(stage) = status {
+                    if !new_startup.status_from_row {
+                        filled |= stage != new_startup.status;
+                        new_startup.status = stage;
+                        new_startup.status_from_row = true;
+                    } else if stage != new_startup.status {
+                        planned.messages.push(format!(
+                            "status \"{}\" ignored; an earlier row set \"{}\"",
+                            stage, new_startup.status
+                        ));
+                    }
+                }
+                if let Some(domain) = new_startup.website.as_deref().and_then(normalize_domain) {
+                    by_domain.entry(domain).or_insert(new_ref);
+                }
+                planned.startup_action = if filled {
+                    ImportAction::Update
+                } else {
+                    ImportAction::Unchanged
+                };
+                new_ref
+            }
+            None => {
+                let new_ref = StartupRef::New(plan.new_startups.len());
+                plan.new_startups.push(NewStartup {
+                    id: Uuid::new_v4(),
+                    name: name.to_string(),
+                    category,
+                    website,
+                    newsroom_url,
+                    status: status.unwrap_or(default_status),
+                    status_from_row: status.is_some(),
+                });
+                by_name.insert(normalize_name(name), new_ref);
+                if let Some(domain) = domain {
+                    by_domain.insert(domain, new_ref);
+                }
+                planned.startup_action = ImportAction::Create;
+                new_ref
+            }
+        };
+        planned.startup = Some(startup_ref);
+
+        if let Some(fields) = contact_fields(&row) {
+            let email_key = fields.email.as_ref().map(|e| e.to_lowercase());
+            let name_key = normalize_name(&fields.name);
+            planned.contact_name = Some(fields.name.clone());
+
+            // Without an email, the same name at the same startup is the
+            // same person.
+            let existing_contact = match (startup_ref, email_key.as_ref()) {
+                (StartupRef::Existing(idx), Some(email)) => {
+                    contacts_by_email.get(&(idx, email.clone())).cloned()
+                }
+                (StartupRef::Existing(idx), None) => {
+                    contacts_by_name.get(&(idx, name_key.clone())).cloned()
+                }
+                _ => None,
+            };
+            let already_planned = match email_key.as_ref() {
+                Some(email) => planned_contact_emails.get(&(startup_ref, email.clone())),
+                None => planned_contact_names.get(&(startup_ref, name_key.clone())),
+            };
+
+            if let Some(id) = already_planned {
+                planned.contact_action = Some(ImportAction::Unchanged);
+                planned.contact_id = Some(*id);
+                planned
+                    .messages
+                    .push("contact already appears earlier in this import".to_string());
+            } else if let Some(existing) = existing_contact {
+                // Later rows for the same person, by either their email or
+                // name, must not queue a second update.
+                let existing_email = existing.email.as_ref().map(|e| e.trim().to_lowercase());
+                for email in email_key.into_iter().chain(existing_email) {
+                    planned_contact_emails
+                        .entry((startup_ref, email))
+                        .or_insert(existing.id);
+                }
+                for name in [name_key, normalize_name(&existing.name)] {
+                    planned_contact_names
+                        .entry((startup_ref, name))
+                        .or_insert(existing.id);
+                }
+                planned.contact_id = Some(existing.id);
+                if contact_differs(&existing, &fields) {
+                    planned.contact_action = Some(ImportAction::Update);
+                    plan.contacts.push(ContactChange::Update {
+                        existing: Box::new(existing),
+                        fields,
+                    });
+                } else {
+                    planned.contact_action = Some(ImportAction::Unchanged);
+                }
+            } else if let Some(reason) = &blockers.contact {
+                planned.contact_action = Some(ImportAction::Invalid);
+                planned
+                    .messages
+                    .push(format!("cannot create contact: {reason}"));
+            } else {
+                let id = Uuid::new_v4();
+                if let Some(email) = email_key {
+                    planned_contact_emails.insert((startup_ref, email), id);
+                }
+                planned_contact_names
+                    .entry((startup_ref, name_key))
+                    .or_insert(id);
+                planned.contact_action = Some(ImportAction::Create);
+                planned.contact_id = Some(id);
+                plan.contacts.push(ContactChange::Create {
+                    id,
+                    startup: startup_ref,
+                    fields,
+                });
+            }
+        }
+
+        plan.rows.push(planned);
+    }
+
+    plan
+}
+
+/// Fills an empty field of a startup created earlier in the import. A
+/// different value for a field that is already set is reported and dropped.
+fn fill_new_field(
+    label: &str,
+    slot: &mut Option<String>,
+    incoming: Option<String>,
+    messages: &mut Vec<String>,
+) -> bool {
+    match (slot.as_deref(), incoming) {
+        (_, None) => false,
+        (None, Some(value)) => {
+            *slot = Some(value);
+            true
+        }
+        (Some(current), Some(value)) => {
+            if current != value {
+                messages.push(format!(
+                    "{label} \"{value}\" ignored; an earlier row set \"{current}\""
+                ));
+            }
+            false
+        }
+    }
+}
+
+fn contact_fields(row: &ImportRow) -> Option<ContactFields> {
+    let email = row.get(ImportField::ContactEmail).map(str::to_string);
+    let name = row
+        .get(ImportField::ContactName)
+        .map(str::to_string)
+        .or_else(|| email.clone())?;
+
+    Some(ContactFields {
+        name,
+        role: row.get(ImportField::ContactRole).map(str::to_string),
+        email,
+        phone: row.get(ImportField::ContactPhone).map(str::to_string),
+        linkedin_url: row.get(ImportField::ContactLinkedinUrl).map(str::to_string),
+        notes: row.get(ImportField::ContactNotes).map(str::to_string),
+        is_primary: row.get(ImportField::ContactIsPrimary).map(parse_bool),
+    })
+}
+
+fn parse_bool(value: &str) -> bool {
+    matches!(
+        value.trim().to_lowercase().as_str(),
+        "true" | "yes" | "y" | "1" | "x"
+    )
+}
+
+fn contact_differs(existing: &contact::Model, fields: &ContactFields) -> bool {
+    fn changed(current: Option<&str>, incoming: Option<&str>) -> bool {
+        incoming.is_some() && incoming != current
+    }
+
+    existing.name != fields.name
+        || changed(Some(&existing.role), fields.role.as_deref())
+        || changed(existing.phone.as_deref(), fields.phone.as_deref())
+        || changed(
+            existing.linkedin_url.as_deref(),
+            fields.linkedin_url.as_deref(),
+        )
+        || changed(existing.notes.as_deref(), fields.notes.as_deref())
+        || fields
+            .is_primary
+            .map(|flag| flag != existing.is_primary)
+            .unwrap_or(false)
+}
+
+impl ImportPlan {
+    fn startup_id(&self, startup: StartupRef) -> Uuid {
+        match startup {
+            StartupRef::Existing(idx) => self.existing[idx].id,
+            StartupRef::New(idx) => self.new_startups[idx].id,
+        }
+    }
+
+    fn startup_name(&self, startup: StartupRef) -> &str {
+        match startup {
+            StartupRef::Existing(idx) => &self.existing[idx].name,
+            StartupRef::New(idx) => &self.new_startups[idx].name,
+        }
+    }
+
+    fn into_report(self, dry_run: bool, committed: bool) -> ImportReport {
+        let mut summary = ImportSummary {
+            total_rows: self.rows.len(),
+            startups_created: self.new_startups.len(),
+            startups_updated: self.updates.len(),
+            ..Default::default()
+        };
+        for change in &self.contacts {
+            match change {
+                ContactChange::Create { .. } => summary.contacts_created += 1,
+                ContactChange::Update { .. } => summary.contacts_updated += 1,
+            }
+        }
+
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                match row.startup_action {
+                    ImportAction::Conflict => summary.conflicts += 1,
+                    ImportAction::Invalid => summary.invalid += 1,
+                    _ if row.contact_action == Some(ImportAction::Invalid) => summary.invalid += 1,
+                    _ => {}
+                }
+                // Ids of records that do not exist yet are only meaningful once committed.
+                let is_new_startup = matches!(row.startup, Some(StartupRef::New(_)));
+                let is_new_contact = row.contact_action == Some(ImportAction::Create);
+                ImportRowReport {
+                    row: row.row,
+                    startup_action: row.startup_action,
+                    startup_id: row
+                        .startup
+                        .filter(|_| committed || !is_new_startup)
+                        .map(|s| self.startup_id(s)),
+                    startup_name: row.startup.map(|s| self.startup_name(s).to_string()),
+                    contact_action: row.contact_action,
+                    contact_id: row.contact_id.filter(|_| committed || !is_new_contact),
+                    contact_name: row.contact_name.clone(),
+                    messages: row.messages.clone(),
+                }
+            })
+            .collect();
+
+        ImportReport {
+            dry_run,
+            committed,
+            summary,
+            rows,
+        }
+    }
+}
+
+async fn apply_plan<C: ConnectionTrait>(
+    db: &C,
+    plan: &ImportPlan,
+    user: &user::Model,
+    owners: &mut NewStartupOwners,
+) -> Result<(), sea_orm::DbErr> {
+    let now = Utc::now().naive_utc();
+    let actor_name = user_display_name(user);
+
+    for new_startup in &plan.new_startups {
+        startup::ActiveModel {
+            id: Set(new_startup.id),
+            name: Set(new_startup.name.clone()),
+            category: Set(new_startup.category.clone()),
+            website: Set(new_startup.website.clone()),
+            newsroom_url: Set(new_startup.newsroom_url.clone()),
+            status: Set(new_startup.status.as_str().to_string()),
+            last_contact_date: Set(None),
+            next_step: Set(None),
+            admin_claimed: Set(false),
+            owner_id: Set(Some(owners.next())),
+            is_trashed: Set(false),
+            trashed_at: Set(None),
+            custom_fields: Set(json!({})),
+            signal_score: Set(0),
+            signal_breakdown: Set(json!([])),
+            website_broken: Set(false),
+            newsroom_url_broken: Set(false),
+            claimed_at: Set(None),
+            claimed_by_contact_id: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(db)
+        .await?;
+
+        record_activity_event(
+            db,
+            ActivityEventInput {
+                activity_type: ACTIVITY_STARTUP_CREATED,
+                description: format!("Imported startup {}", new_startup.name),
+                user_id: Some(user.id),
+                user_name: Some(actor_name.clone()),
+                startup_id: Some(new_startup.id),
+                startup_name: Some(new_startup.name.clone()),
+                contact_id: None,
+                contact_name: None,
+                stage_from: None,
+                stage_to: Some(new_startup.status.as_str().to_string()),
+                metadata: Some(json!({
+                    "status": new_startup.status.as_str(),
+                    "source": "csv_import",
+                })),
+                occurred_at: None,
+            },
+        )
+        .await?;
+    }
+
+    for update in &plan.updates {
+        let mut active: startup::ActiveModel = plan.existing[update.index].clone().into();
+        if let Some(category) = &update.category {
+            active.category = Set(Some(category.clone()));
+        }
+        if let Some(website) = &update.website {
+            active.website = Set(Some(website.clone()));
+        }
+        if let Some(newsroom_url) = &update.newsroom_url {
+            active.newsroom_url = Set(Some(newsroom_url.clone()));
+        }
+        active.updated_at = Set(now);
+        let updated = active.update(db).await?;
+        record_startup_revisions(db, &plan.existing[update.index], &updated, Some(user)).await?;
+    }
+
+    for change in &plan.contacts {
+        match change {
+            ContactChange::Create {
+                id,
+                startup,
+                fields,
+            } => {
+                let role = fields
+                    .role
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_CONTACT_ROLE.to_string());
+                let startup_id = plan.startup_id(*startup);
+                let inserted = contact::ActiveModel {
+                    id: Set(*id),
+                    startup_id: Set(startup_id),
+                    name: Set(fields.name.clone()),
+                    role: Set(role.clone()),
+                    email: Set(fields.email.clone()),
+                    phone: Set(fields.phone.clone()),
+                    linkedin_url: Set(fields.linkedin_url.clone()),
+                    is_primary: Set(fields.is_primary.unwrap_or(false)),
+                    notes: Set(fields.notes.clone()),
+                    is_trashed: Set(false),
+                    trashed_at: Set(None),
+                    owner_id: Set(Some(user.id)),
+                    custom_fields: Set(json!({})),
+                }
+                .insert(db)
+                .await?;
+                sync_primary_identities(db, &inserted).await?;
+
+                record_activity_event(
+                    db,
+                    ActivityEventInput {
+                        activity_type: ACTIVITY_CONTACT_CREATED,
+                        description: format!("Imported contact {} ({})", fields.name, role),
+                        user_id: Some(user.id),
+                        user_name: Some(actor_name.clone()),
+                        startup_id: Some(startup_id),
+                        startup_name: Some(plan.startup_name(*startup).to_string()),
+                        contact_id: Some(*id),
+                        contact_name: Some(fields.name.clone()),
+                        stage_from: None,
+                        stage_to: None,
+                        metadata: Some(json!({ "contact_role": role, "source": "csv_import" })),
+                        occurred_at: None,
+                    },
+                )
+                .await?;
+            }
+            ContactChange::Update { existing, fields } => {
+                let mut active: contact::ActiveModel = existing.as_ref().clone().into();
+                active.name = Set(fields.name.clone());
+                if let Some(role) = &fields.role {
+                    active.role = Set(role.clone());
+                }
+                if let Some(phone) = &fields.phone {
+                    active.phone = Set(Some(phone.clone()));
+                }
+                if let Some(linkedin_url) = &fields.linkedin_url {
+                    active.linkedin_url = Set(Some(linkedin_url.clone()));
+                }
+                if let Some(notes) = &fields.notes {
+                    active.notes = Set(Some(notes.clone()));
+                }
+                if let Some(is_primary) = fields.is_primary {
+                    active.is_primary = Set(is_primary);
+                }
+                let updated = active.update(db).await?;
+                sync_primary_identities(db, &updated).await?;
+                record_contact_revisions(db, existing, &updated, Some(user)).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn existing_startup(name: &str, website: Option<&str>) -> startup::Model {
+        let now = Utc::now().naive_utc();
+        startup::Model {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            category: None,
+            website: website.map(str::to_string),
+            newsroom_url: None,
+            status: "Lead".to_string(),
+            last_contact_date: None,
+            next_step: None,
+            admin_claimed: false,
+            owner_id: None,
+            is_trashed: false,
+            trashed_at: None,
+            custom_fields: json!({}),
+            signal_score: 0,
+            signal_breakdown: json!([]),
+            website_broken: false,
+            newsroom_url_broken: false,
+            claimed_at: None,
+            claimed_by_contact_id: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn mapping() -> HashMap<String, ImportField> {
+        HashMap::from([
+            ("Company".to_string(), ImportField::StartupName),
+            ("Site".to_string(), ImportField::StartupWebsite),
+            ("Person".to_string(), ImportField::ContactName),
+            ("Email".to_string(), ImportField::ContactEmail),
+        ])
+    }
+
+    #[test]
+    fn test_normalize_domain() {
+        assert_eq!(
+            normalize_domain("https://www.Paystack.com/about?x=1"),
+            Some("paystack.com".to_string())
+        );
+        assert_eq!(
+            normalize_domain("paystack.com"),
+            Some("paystack.com".to_string())
+        );
+        assert_eq!(normalize_domain("localhost"), None);
+        assert_eq!(normalize_domain("  "), None);
+    }
+
+    #[test]
+    fn test_plan_matches_and_dedupes() {
+        let csv = "Company,Site,Person,Email\n\
+                   Paystack Inc,https://paystack.com,Ada,ada@paystack.com\n\
+                   Flutterwave,flutterwave.com,Bola,bola@flutterwave.com\n\
+                   flutterwave,,Chidi,chidi@flutterwave.com\n\
+                   ,,Nobody,\n";
+        let rows = parse_csv(csv, &mapping()).unwrap();
+        let existing = vec![existing_startup("Paystack", Some("www.paystack.com"))];
+        let plan = build_plan(
+            rows,
+            existing,
+            Vec::new(),
+            PipelineStage::Lead,
+            &CreateBlockers::default(),
+        );
+
+        assert_eq!(plan.new_startups.len(), 1);
+        assert_eq!(plan.new_startups[0].name, "Flutterwave");
+        assert_eq!(plan.contacts.len(), 3);
+        assert_eq!(plan.rows[0].startup_action, ImportAction::Unchanged);
+        assert_eq!(plan.rows[1].startup_action, ImportAction::Create);
+        assert_eq!(plan.rows[2].startup_action, ImportAction::Unchanged);
+        assert_eq!(plan.rows[3].startup_action, ImportAction::Invalid);
+    }
+
+    #[test]
+    fn test_plan_merges_rows_for_new_startup() {
+        let mut mapping = mapping();
+        mapping.insert("Stage".to_string(), ImportField::StartupStatus);
+        let csv = "Company,Site,Person,Email,Stage\n\
+                   Flutterwave,,Bola,,\n\
+                   Flutterwave,flutterwave.com,bola,,Contacted\n\
+                   Flutterwave,other.com,,,Lead\n\
+                   Chipper,,,,Nowhere\n";
+        let rows = parse_csv(csv, &mapping).unwrap();
+        let plan = build_plan(
+            rows,
+            Vec::new(),
+            Vec::new(),
+            PipelineStage::Lead,
+            &CreateBlockers::default(),
+        );
+
+        assert_eq!(plan.new_startups.len(), 1);
+        let new_startup = &plan.new_startups[0];
+        assert_eq!(new_startup.website.as_deref(), Some("flutterwave.com"));
+        assert_eq!(new_startup.status, PipelineStage::Contacted);
+        assert_eq!(plan.rows[1].startup_action, ImportAction::Update);
+        assert_eq!(plan.rows[2].startup_action, ImportAction::Unchanged);
+        assert_eq!(plan.rows[2].messages.len(), 2);
+        assert_eq!(plan.rows[3].startup_action, ImportAction::Invalid);
+        // The second Bola has no email but the same name, so is not re-created.
+        assert_eq!(plan.contacts.len(), 1);
+        assert_eq!(plan.rows[1].contact_action, Some(ImportAction::Unchanged));
+    }
+
+    fn existing_contact(
+        startup: &startup::Model,
+        name: &str,
+        email: Option<&str>,
+    ) -> contact::Model {
+        contact::Model {
+            id: Uuid::new_v4(),
+            startup_id: startup.id,
+            name: name.to_string(),
+            role: "CEO".to_string(),
+            email: email.map(str::to_string),
+            phone: None,
+            linkedin_url: None,
+            is_primary: false,
+            notes: None,
+            is_trashed: false,
+            trashed_at: None,
+            owner_id: None,
+            custom_fields: json!({}),
+        }
+    }
+
+    #[test]
+    fn test_plan_matches_contacts_without_email_by_name() {
+        let startup = existing_startup("Paystack", Some("paystack.com"));
+        let contact = existing_contact(&startup, "Ada Obi", None);
+        let csv = "Company,Site,Person,Email\nPaystack,,Ada Obi,\n";
+        let rows = parse_csv(csv, &mapping()).unwrap();
+        let plan = build_plan(
+            rows,
+            vec![startup],
+            vec![contact.clone()],
+            PipelineStage::Lead,
+            &CreateBlockers::default(),
+        );
+
+        assert!(plan.contacts.is_empty());
+        assert_eq!(plan.rows[0].contact_action, Some(ImportAction::Unchanged));
+        assert_eq!(plan.rows[0].contact_id, Some(contact.id));
+    }
+
+    #[test]
+    fn test_plan_updates_existing_contact_once() {
+        let startup = existing_startup("Paystack", Some("paystack.com"));
+        let contact = existing_contact(&startup, "Ada Obi", Some("ada@paystack.com"));
+        let csv = "Company,Site,Person,Email\n\
+                   Paystack,,Ada Obi-Okafor,ada@paystack.com\n\
+                   Paystack,,Ada O.,ADA@paystack.com\n\
+                   Paystack,,ada obi-okafor,\n";
+        let rows = parse_csv(csv, &mapping()).unwrap();
+        let plan = build_plan(
+            rows,
+            vec![startup],
+            vec![contact.clone()],
+            PipelineStage::Lead,
+            &CreateBlockers::default(),
+        );
+
+        assert_eq!(plan.contacts.len(), 1);
+        assert_eq!(plan.rows[0].contact_action, Some(ImportAction::Update));
+        for row in &plan.rows[1..] {
+            assert_eq!(row.contact_action, Some(ImportAction::Unchanged));
+            assert_eq!(row.contact_id, Some(contact.id));
+        }
+    }
+
+    #[test]
+    fn test_plan_reports_rows_blocked_by_required_custom_fields() {
+        let startup = existing_startup("Paystack", Some("paystack.com"));
+        let blockers = CreateBlockers {
+            startup: Some("custom field country is required".to_string()),
+            contact: Some("custom field seniority is required".to_string()),
+        };
+        let csv = "Company,Site,Person,Email\n\
+                   Flutterwave,,Bola,\n\
+                   Paystack,,Chidi,chidi@paystack.com\n";
+        let rows = parse_csv(csv, &mapping()).unwrap();
+        let plan = build_plan(
+            rows,
+            vec![startup],
+            Vec::new(),
+            PipelineStage::Lead,
+            &blockers,
+        );
+
+        assert!(plan.new_startups.is_empty());
+        assert!(plan.contacts.is_empty());
+        assert_eq!(plan.rows[0].startup_action, ImportAction::Invalid);
+        assert_eq!(plan.rows[1].startup_action, ImportAction::Unchanged);
+        assert_eq!(plan.rows[1].contact_action, Some(ImportAction::Invalid));
+        assert_eq!(plan.into_report(true, false).summary.invalid, 2);
+    }
+
+    #[test]
+    fn test_plan_reports_conflicting_matches() {
+        let csv = "Company,Site\nPaystack,flutterwave.com\n";
+        let rows = parse_csv(csv, &mapping()).unwrap();
+        let existing = vec![
+            existing_startup("Paystack", None),
+            existing_startup("Flutterwave", Some("flutterwave.com")),
+        ];
+        let plan = build_plan(
+            rows,
+            existing,
+            Vec::new(),
+            PipelineStage::Lead,
+            &CreateBlockers::default(),
+        );
+
+        assert_eq!(plan.rows[0].startup_action, ImportAction::Conflict);
+        assert!(plan.new_startups.is_empty());
+        assert!(plan.updates.is_empty());
+    }
+}
@@ -0,0 +1,526 @@
+use crate::auth::middleware::AuthUser;
+use crate::entities::{
+    contact, intro_request, intro_request_outreach_log, outreach_log, startup, user,
+};
+use crate::{
+    find_active_startup, non_empty, record_activity_event, user_display_name, ActivityEventInput,
+    AppState, ACTIVITY_INTRO_MADE,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub const INTRO_STATUS_REQUESTED: &str = "requested";
+pub const INTRO_STATUS_PROMISED: &str = "promised";
+pub const INTRO_STATUS_MADE: &str = "made";
+pub const INTRO_STATUS_DECLINED: &str = "declined";
+
+/// Statuses an intro can still move on from; only these are picked up by
+/// detection from synced mail.
+pub const OPEN_INTRO_STATUSES: [&str; 2] = [INTRO_STATUS_REQUESTED, INTRO_STATUS_PROMISED];
+
+fn is_intro_status(status: &str) -> bool {
+    matches!(
+        status,
+        INTRO_STATUS_REQUESTED | INTRO_STATUS_PROMISED | INTRO_STATUS_MADE | INTRO_STATUS_DECLINED
+    )
+}
+
+#[derive(Deserialize, Default)]
+pub struct IntroListQuery {
+    pub status: Option<String>,
+    pub startup_id: Option<Uuid>,
+    pub requester_contact_id: Option<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateIntroRequest {
+    pub requester_contact_id: Uuid,
+    pub target_contact_id: Option<Uuid>,
+    /// Who we want to meet when there is no contact for them yet, e.g.
+    /// "Head of Sales".
+    pub target_role: Option<String>,
+    pub status: Option<String>,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub outreach_log_ids: Vec<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateIntroRequest {
+    pub status: Option<String>,
+    pub target_contact_id: Option<Uuid>,
+    pub target_role: Option<String>,
+    pub notes: Option<String>,
+    /// Replaces the linked outreach logs.
+    pub outreach_log_ids: Option<Vec<Uuid>>,
+}
+
+#[derive(Serialize)]
+pub struct IntroResponse {
+    pub id: Uuid,
+    pub startup_id: Uuid,
+    pub startup_name: Option<String>,
+    pub requester_contact_id: Option<Uuid>,
+    pub requester_name: Option<String>,
+    pub target_contact_id: Option<Uuid>,
+    pub target_name: Option<String>,
+    pub target_role: Option<String>,
+    pub status: String,
+    pub notes: Option<String>,
+    pub introduced_email: Option<String>,
+    pub conversation_id: Option<Uuid>,
+    pub requested_by_id: Option<Uuid>,
+    pub outreach_log_ids: Vec<Uuid>,
+    pub requested_at: NaiveDateTime,
+    pub made_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Whoever asked for the intro and admins can change it; intros without a
+/// requesting user are open to everyone.
+fn can_edit_intro(user: &user::Model, intro: &intro_request::Model) -> bool {
+    user.is_admin() || intro.requested_by_id.is_none() || intro.requested_by_id == Some(user.id)
+}
+
+/// Attach startup and contact names and linked outreach logs, loading each
+/// kind in one query.
+pub async fn intro_responses<C: ConnectionTrait>(
+    db: &C,
+    intros: Vec<intro_request::Model>,
+) -> Result<Vec<IntroResponse>, DbErr> {
+    let intro_ids: Vec<Uuid> = intros.iter().map(|intro| intro.id).collect();
+    let startup_ids: Vec<Uuid> = intros.iter().map(|intro| intro.startup_id).collect();
+    let contact_ids: Vec<Uuid> = intros
+        .iter()
+        .flat_map(|intro| [intro.requester_contact_id, intro.target_contact_id])
+        .flatten()
+        .collect();
+
+    let startups: HashMap<Uuid, String> = startup::Entity::find()
+        .filter(startup::Column::Id.is_in(startup_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|startup| (startup.id, startup.name))
+        .collect();
+    let contacts: HashMap<Uuid, String> = contact::Entity::find()
+        .filter(contact::Column::Id.is_in(contact_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|contact| (contact.id, contact.name))
+        .collect();
+    let mut outreach: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for link in intro_request_outreach_log::Entity::find()
+        .filter(intro_request_outreach_log::Column::IntroRequestId.is_in(intro_ids))
+        .all(db)
+        .await?
+    {
+        outreach
+            .entry(link.intro_request_id)
+            .or_default()
+            .push(link.outreach_log_id);
+    }
+
+    Ok(intros
+        .into_iter()
+        .map(|intro| IntroResponse {
+            startup_name: startups.get(&intro.startup_id).cloned(),
+            requester_name: intro
+                .requester_contact_id
+                .and_then(|id| contacts.get(&id).cloned()),
+            target_name: intro
+                .target_contact_id
+                .and_then(|id| contacts.get(&id).cloned()),
+            outreach_log_ids: outreach.remove(&intro.id).unwrap_or_default(),
+            id: intro.id,
+            startup_id: intro.startup_id,
+            requester_contact_id: intro.requester_contact_id,
+            target_contact_id: intro.target_contact_id,
+            target_role: intro.target_role,
+            status: intro.status,
+            notes: intro.notes,
+            introduced_email: intro.introduced_email,
+            conversation_id: intro.conversation_id,
+            requested_by_id: intro.requested_by_id,
+            requested_at: intro.requested_at,
+            made_at: intro.made_at,
+            created_at: intro.created_at,
+            updated_at: intro.updated_at,
+        })
+        .collect())
+}
+
+/// GET /api/intros
+pub async fn list_intros(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    query: Option<Query<IntroListQuery>>,
+) -> Result<Json<Vec<IntroResponse>>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    list_matching(&state, params).await
+}
+
+/// GET /api/startups/:startup_id/intros
+pub async fn list_intros_for_startup(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    Path(startup_id): Path<Uuid>,
+    query: Option<Query<IntroListQuery>>,
+) -> Result<Json<Vec<IntroResponse>>, StatusCode> {
+    find_active_startup(&state.db, startup_id).await?;
+    let params = IntroListQuery {
+        startup_id: Some(startup_id),
+        ..query.map(|q| q.0).unwrap_or_default()
+    };
+    list_matching(&state, params).await
+}
+
+async fn list_matching(
+    state: &AppState,
+    params: IntroListQuery,
+) -> Result<Json<Vec<IntroResponse>>, StatusCode> {
+    let mut filters = Condition::all();
+    if let Some(status) = non_empty(params.status.as_deref()) {
+        if !is_intro_status(status) {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        filters = filters.add(intro_request::Column::Status.eq(status));
+    }
+    if let Some(startup_id) = params.startup_id {
+        filters = filters.add(intro_request::Column::StartupId.eq(startup_id));
+    }
+    if let Some(contact_id) = params.requester_contact_id {
+        filters = filters.add(intro_request::Column::RequesterContactId.eq(contact_id));
+    }
+
+    let intros = intro_request::Entity::find()
+        .filter(filters)
+        .order_by_desc(intro_request::Column::RequestedAt)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let responses = intro_responses(&state.db, intros)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(responses))
+}
+
+/// POST /api/startups/:startup_id/intros
+/// The requester must be a contact of the startup; the target is either
+/// another of its contacts or a role.
+pub async fn create_intro(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(startup_id): Path<Uuid>,
+    Json(payload): Json<CreateIntroRequest>,
+) -> Result<(StatusCode, Json<IntroResponse>), StatusCode> {
+    let startup = find_active_startup(&state.db, startup_id).await?;
+    find_startup_contact(&state, startup.id, payload.requester_contact_id).await?;
+    if let Some(target_id) = payload.target_contact_id {
+        if target_id == payload.requester_contact_id {
+            return Err(StatusCode::UNPROCESSABLE_ENTITY);
+        }
+        find_startup_contact(&state, startup.id, target_id).await?;
+    }
+    let target_role = non_empty(payload.target_role.as_deref()).map(str::to_string);
+    if payload.target_contact_id.is_none() && target_role.is_none() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let status = match non_empty(payload.status.as_deref()) {
+        Some(status) if is_intro_status(status) => status.to_string(),
+        Some(_) => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+        None => INTRO_STATUS_REQUESTED.to_string(),
+    };
+    check_outreach_logs(&state, startup.id, &payload.outreach_log_ids).await?;
+
+    let now = Utc::now().naive_utc();
+    let intro = intro_request::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        startup_id: Set(startup.id),
+        requester_contact_id: Set(Some(payload.requester_contact_id)),
+        target_contact_id: Set(payload.target_contact_id),
+        target_role: Set(target_role),
+        status: Set(status.clone()),
+        notes: Set(non_empty(payload.notes.as_deref()).map(str::to_string)),
+        introduced_email: Set(None),
+        conversation_id: Set(None),
+        requested_by_id: Set(Some(user.id)),
+        requested_at: Set(now),
+        made_at: Set((status == INTRO_STATUS_MADE).then_some(now)),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(&state.db)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    replace_outreach_logs(&state, intro.id, &payload.outreach_log_ids).await?;
+
+    if status == INTRO_STATUS_MADE {
+        if let Err(err) = record_intro_made(&state.db, &intro, Some(&user), None).await {
+            tracing::warn!(error = ?err, "failed to record intro activity");
+        }
+    }
+
+    let response = single_response(&state.db, intro).await?;
+    Ok((StatusCode::CREATED, response))
+}
+
+/// PUT /api/intros/:id
+/// Moving to `made` stamps `made_at` and records an `intro_made` activity
+/// event; moving back to an open status clears it.
+pub async fn update_intro(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateIntroRequest>,
+) -> Result<Json<IntroResponse>, StatusCode> {
+    let existing = find_intro(&state.db, id).await?;
+    if !can_edit_intro(&user, &existing) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let was_made = existing.status == INTRO_STATUS_MADE;
+    let mut active: intro_request::ActiveModel = existing.clone().into();
+    if let Some(target_id) = payload.target_contact_id {
+        if Some(target_id) == existing.requester_contact_id {
+            return Err(StatusCode::UNPROCESSABLE_ENTITY);
+        }
+        find_startup_contact(&state, existing.startup_id, target_id).await?;
+        active.target_contact_id = Set(Some(target_id));
+    }
+    if let Some(role) = payload.target_role {
+        let role = non_empty(Some(&role)).map(str::to_string);
+        if role.is_none()
+            && payload
+                .target_contact_id
+                .or(existing.target_contact_id)
+                .is_none()
+        {
+            return Err(StatusCode::UNPROCESSABLE_ENTITY);
+        }
+        active.target_role = Set(role);
+    }
+    if let Some(notes) = payload.notes {
+        active.notes = Set(non_empty(Some(&notes)).map(str::to_string));
+    }
+    let now = Utc::now().naive_utc();
+    let making = match payload.status.as_deref() {
+        None => false,
+        Some(status) if !is_intro_status(status) => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+        Some(status) => {
+            active.status = Set(status.to_string());
+            if status == INTRO_STATUS_MADE {
+                if !was_made {
+                    active.made_at = Set(Some(now));
+                }
+            } else {
+                active.made_at = Set(None);
+            }
+            status == INTRO_STATUS_MADE && !was_made
+        }
+    };
+    active.updated_at = Set(now);
+
+    if let Some(ids) = payload.outreach_log_ids.as_deref() {
+        check_outreach_logs(&state, existing.startup_id, ids).await?;
+    }
+
+    let updated = active
+        .update(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if let Some(ids) = payload.outreach_log_ids.as_deref() {
+        replace_outreach_logs(&state, updated.id, ids).await?;
+    }
+
+    if making {
+        if let Err(err) = record_intro_made(&state.db, &updated, Some(&user), None).await {
+            tracing::warn!(error = ?err, "failed to record intro activity");
+        }
+    }
+
+    single_response(&state.db, updated).await
+}
+
+/// DELETE /api/intros/:id
+pub async fn delete_intro(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let existing = find_intro(&state.db, id).await?;
+    if !can_edit_intro(&user, &existing) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    intro_request::Entity::delete_by_id(existing.id)
+        .exec(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn find_intro<C: ConnectionTrait>(
+    db: &C,
+    id: Uuid,
+) -> Result<intro_request::Model, StatusCode> {
+    intro_request::Entity::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn find_startup_contact(
+    state: &AppState,
+    startup_id: Uuid,
+    contact_id: Uuid,
+) -> Result<contact::Model, StatusCode> {
+    contact::Entity::find_by_id(contact_id)
+        .filter(contact::Column::StartupId.eq(startup_id))
+        .filter(contact::Column::IsTrashed.eq(false))
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::UNPROCESSABLE_ENTITY)
+}
+
+/// Every linked outreach log has to exist and belong to the intro's startup.
+async fn check_outreach_logs(
+    state: &AppState,
+    startup_id: Uuid,
+    ids: &[Uuid],
+) -> Result<(), StatusCode> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+    let found = outreach_log::Entity::find()
+        .filter(outreach_log::Column::Id.is_in(ids.to_vec()))
+        .filter(outreach_log::Column::StartupId.eq(startup_id))
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if ids.iter().all(|id| found.iter().any(|log| log.id == *id)) {
+        Ok(())
+    } else {
+        Err(StatusCode::UNPROCESSABLE_ENTITY)
+    }
+}
+
+async fn replace_outreach_logs(
+    state: &AppState,
+    intro_id: Uuid,
+    ids: &[Uuid],
+) -> Result<(), StatusCode> {
+    intro_request_outreach_log::Entity::delete_many()
+        .filter(intro_request_outreach_log::Column::IntroRequestId.eq(intro_id))
+        .exec(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut seen = Vec::new();
+    for id in ids {
+        if seen.contains(id) {
+            continue;
+        }
+        seen.push(*id);
+        intro_request_outreach_log::ActiveModel {
+            intro_request_id: Set(intro_id),
+            outreach_log_id: Set(*id),
+        }
+        .insert(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
+    Ok(())
+}
+
+async fn single_response<C: ConnectionTrait>(
+    db: &C,
+    intro: intro_request::Model,
+) -> Result<Json<IntroResponse>, StatusCode> {
+    intro_responses(db, vec![intro])
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .pop()
+        .map(Json)
+        .ok_or(StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// Records an `intro_made` activity event. `message_id` is set when the
+/// intro was detected from synced mail rather than marked by hand.
+pub async fn record_intro_made<C: ConnectionTrait>(
+    db: &C,
+    intro: &intro_request::Model,
+    actor: Option<&user::Model>,
+    message_id: Option<Uuid>,
+) -> Result<(), DbErr> {
+    let startup_name = startup::Entity::find_by_id(intro.startup_id)
+        .one(db)
+        .await?
+        .map(|startup| startup.name);
+    let requester = match intro.requester_contact_id {
+        Some(id) => contact::Entity::find_by_id(id).one(db).await?,
+        None => None,
+    };
+    let target = match intro.target_contact_id {
+        Some(id) => contact::Entity::find_by_id(id).one(db).await?,
+        None => None,
+    };
+
+    let introduced = target
+        .as_ref()
+        .map(|contact| contact.name.clone())
+        .or_else(|| intro.introduced_email.clone())
+        .or_else(|| intro.target_role.clone())
+        .unwrap_or_else(|| "a new contact".to_string());
+    let description = match &requester {
+        Some(requester) => format!("{} introduced us to {}", requester.name, introduced),
+        None => format!("Intro made to {introduced}"),
+    };
+
+    record_activity_event(
+        db,
+        ActivityEventInput {
+            activity_type: ACTIVITY_INTRO_MADE,
+            description,
+            user_id: actor.map(|user| user.id),
+            user_name: actor.map(user_display_name),
+            startup_id: Some(intro.startup_id),
+            startup_name,
+            contact_id: requester.as_ref().map(|contact| contact.id),
+            contact_name: requester.map(|contact| contact.name),
+            stage_from: None,
+            stage_to: None,
+            metadata: Some(json!({
+                "intro_id": intro.id,
+                "target_contact_id": intro.target_contact_id,
+                "introduced_email": intro.introduced_email,
+                "auto": message_id.is_some(),
+                "message_id": message_id,
+            })),
+            occurred_at: intro.made_at,
+        },
+    )
+    .await
+}
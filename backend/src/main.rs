@@ -1,9 +1,26 @@
+mod analytics_controller;
 mod auth;
+mod bulk_actions_controller;
+mod claims_controller;
+mod contact_identities_controller;
 mod conversations_controller;
+mod custom_fields_controller;
 mod email_service;
 mod entities;
+mod export_controller;
+mod history_controller;
+mod import_controller;
+mod intro_requests_controller;
+mod merge_controller;
+mod owners_controller;
+mod pipeline;
+mod saved_views_controller;
+mod search_controller;
 mod services;
+mod tags_controller;
+mod tasks_controller;
 mod user_management;
+mod vcard_controller;
 
 use axum::{
     extract::{Path, Query, State},
@@ -11,16 +28,18 @@ use axum::{
     routing::{delete, get, post, put},
     Json, Router,
 };
-use chrono::{Datelike, NaiveDate, Utc};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Utc};
 use entities::{
-    activity_event, contact, interview, interview_insight, outreach_log, startup, user,
-    weekly_activity_plan, weekly_metric_definition, weekly_synthesis,
+    activity_event, contact, contact_identity, contact_purge_run, conversation, field_revision,
+    interview, interview_insight, intro_request, newsroom_claim, outreach_log, startup, task,
+    url_check, user, weekly_activity_plan, weekly_metric_definition, weekly_synthesis,
 };
 use sea_orm::sea_query::extension::postgres::PgExpr;
-use sea_orm::sea_query::Expr;
+use sea_orm::sea_query::{Expr, NullOrdering};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, Database, DatabaseConnection, EntityTrait,
-    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, Database, DatabaseConnection,
+    EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+    TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -31,14 +50,42 @@ use tower_http::cors::CorsLayer;
 use uuid::Uuid;
 
 use crate::auth::middleware::{AdminUser, AuthUser};
+use crate::claims_controller::ClaimSigningKey;
+use crate::contact_identities_controller::{sync_primary_identities, IdentityKind};
+use crate::custom_fields_controller::{
+    custom_field_filter, load_definitions, resolve_custom_fields, ENTITY_CONTACT, ENTITY_STARTUP,
+};
 use crate::email_service::{EmailService, EmailServiceError, EmailTemplateKind};
+use crate::history_controller::{record_contact_revisions, record_startup_revisions};
+use crate::owners_controller::{find_assignable_owner, NewStartupOwners, OwnerAssignment};
+use crate::pipeline::{
+    PipelineStage, TransitionDirection, TransitionError, TransitionGraph, ALL_STAGES,
+};
+use crate::services::contact_retention::{
+    list_purge_runs, preview_contact_purge, purge_trashed_contacts, ContactRetention, PurgePreview,
+    PURGE_TRIGGER_MANUAL, PURGE_TRIGGER_SCHEDULED,
+};
 use crate::services::encryption_service::EncryptionService;
 use crate::services::imap_service::ImapService;
+use crate::services::signal_score::{rescore_all_startups, rescore_startup};
+use crate::services::stale_leads::{clear_stale_follow_ups, sweep_stale_leads, StaleThresholds};
+use crate::services::startup_activity::{
+    backfill_startup_contact_fields, refresh_passed_interviews, refresh_startup_contact_fields,
+    BackfillReport,
+};
+use crate::services::url_health::{check_all_urls, UrlHealthReport};
+use crate::tags_controller::{
+    count_tag_assignments, delete_tag_assignments, tag_filter, TaggableEntity,
+};
 
 #[derive(Clone)]
 struct AppState {
     db: DatabaseConnection,
     email_service: EmailService,
+    pipeline: TransitionGraph,
+    owner_assignment: OwnerAssignment,
+    contact_retention: ContactRetention,
+    claim_signing_key: ClaimSigningKey,
 }
 
 impl axum::extract::FromRef<AppState> for DatabaseConnection {
@@ -61,6 +108,55 @@ struct CreateStartupRequest {
     website: Option<String>,
     newsroom_url: Option<String>,
     status: String,
+    custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Only used on create; reassign through `PUT /api/startups/:id/owner`.
+    owner_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, Default)]
+struct StartupListQuery {
+    page: Option<u32>,
+    page_size: Option<u32>,
+    search: Option<String>,
+    status: Option<String>,
+    category: Option<String>,
+    next_step: Option<String>,
+    admin_claimed: Option<bool>,
+    /// Only startups whose website or newsroom URL is currently broken.
+    broken_links: Option<bool>,
+    owner_id: Option<Uuid>,
+    /// Only startups owned by the caller.
+    mine: Option<bool>,
+    last_contact_from: Option<String>,
+    last_contact_to: Option<String>,
+    sort_by: Option<String>,
+    sort_order: Option<String>,
+    trashed: Option<bool>,
+    /// JSON object of custom field filters, see `custom_field_filter`.
+    custom_fields: Option<String>,
+    /// Comma-separated tag ids or names, see `tag_filter`.
+    tags: Option<String>,
+}
+
+#[derive(Serialize)]
+struct StartupListResponse {
+    total: u64,
+    page: u32,
+    page_size: u32,
+    results: Vec<startup::Model>,
+}
+
+#[derive(Deserialize)]
+struct StartupTransitionRequest {
+    to_stage: String,
+    reason: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PipelineStageResponse {
+    stage: PipelineStage,
+    position: usize,
+    allowed_transitions: Vec<PipelineStage>,
 }
 
 // Contact DTOs
@@ -74,6 +170,7 @@ struct CreateContactRequest {
     linkedin_url: Option<String>,
     is_primary: Option<bool>,
     notes: Option<String>,
+    custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 #[derive(Deserialize)]
@@ -85,11 +182,14 @@ struct UpdateContactRequest {
     linkedin_url: Option<String>,
     is_primary: Option<bool>,
     notes: Option<String>,
+    custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 #[derive(Deserialize, Default)]
 struct ContactListQuery {
     trashed: Option<bool>,
+    custom_fields: Option<String>,
+    tags: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -104,9 +204,11 @@ struct ContactResponse {
     is_primary: bool,
     notes: Option<String>,
     is_trashed: bool,
+    trashed_at: Option<NaiveDateTime>,
     owner_id: Option<Uuid>,
     owner_name: Option<String>,
     owner_email: Option<String>,
+    custom_fields: serde_json::Value,
 }
 
 #[derive(Deserialize)]
@@ -131,9 +233,11 @@ impl From<(contact::Model, Option<user::Model>)> for ContactResponse {
             is_primary: contact.is_primary,
             notes: contact.notes,
             is_trashed: contact.is_trashed,
+            trashed_at: contact.trashed_at,
             owner_id: contact.owner_id,
             owner_name,
             owner_email,
+            custom_fields: contact.custom_fields,
         }
     }
 }
@@ -159,6 +263,9 @@ struct SendContactEmailRequest {
     body_text: Option<String>,
     #[serde(default)]
     template: EmailTemplateKind,
+    /// One of the contact's email identities; defaults to their primary
+    /// address.
+    identity_id: Option<Uuid>,
 }
 
 #[derive(Serialize)]
@@ -228,11 +335,24 @@ const ACTIVITY_STARTUP_CREATED: &str = "startup_created";
 const ACTIVITY_OUTREACH_LOGGED: &str = "outreach_logged";
 const ACTIVITY_MEETING_LOGGED: &str = "meeting_logged";
 const ACTIVITY_STAGE_MOVED: &str = "stage_moved";
+const ACTIVITY_STARTUP_MERGED: &str = "startup_merged";
+const ACTIVITY_CONTACT_MERGED: &str = "contact_merged";
+const ACTIVITY_OWNER_CHANGED: &str = "owner_changed";
+const ACTIVITY_TASK_COMPLETED: &str = "task_completed";
+const ACTIVITY_NEWSROOM_DOWN: &str = "newsroom_down";
+const ACTIVITY_NEWSROOM_RECOVERED: &str = "newsroom_recovered";
+const ACTIVITY_NEWSROOM_CLAIMED: &str = "newsroom_claimed";
+const ACTIVITY_STARTUP_UPDATED: &str = "startup_updated";
+const ACTIVITY_INTRO_MADE: &str = "intro_made";
+/// Not a pipeline stage: the `stage_to` of newsroom claims, so output metrics
+/// can count them.
+const NEWSROOM_CLAIMED_STAGE: &str = "Newsroom Claimed";
 const INPUT_ACTIVITY_TYPES: &[&str] = &[
     ACTIVITY_CONTACT_CREATED,
     ACTIVITY_STARTUP_CREATED,
     ACTIVITY_OUTREACH_LOGGED,
     ACTIVITY_MEETING_LOGGED,
+    ACTIVITY_TASK_COMPLETED,
 ];
 
 #[derive(Deserialize)]
@@ -385,20 +505,158 @@ async fn health_check() -> Json<HealthResponse> {
 // Startup handlers
 async fn list_startups(
     State(state): State<AppState>,
-    _auth_user: AuthUser,
-) -> Result<Json<Vec<startup::Model>>, StatusCode> {
-    let startups = startup::Entity::find()
-        .order_by_desc(startup::Column::CreatedAt)
-        .all(&state.db)
+    AuthUser(user): AuthUser,
+    query: Option<Query<StartupListQuery>>,
+) -> Result<Json<StartupListResponse>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    if params.trashed.unwrap_or(false) && !user.is_admin() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let filters = startup_list_filters(&state.db, &params, &user).await?;
+
+    let page = params.page.unwrap_or(1).max(1);
+    let page_size = params.page_size.unwrap_or(20).clamp(10, 100);
+
+    let base_query = apply_startup_sort(startup::Entity::find().filter(filters), &params)?;
+    let paginator = base_query.paginate(&state.db, page_size as u64);
+
+    let total = paginator
+        .num_items()
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    Ok(Json(startups))
+    let startups = paginator
+        .fetch_page((page - 1) as u64)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(StartupListResponse {
+        total,
+        page,
+        page_size,
+        results: startups,
+    }))
+}
+
+/// Build the filter condition shared by the startup list and anything that
+/// needs to select the same set of startups.
+async fn startup_list_filters<C: ConnectionTrait>(
+    db: &C,
+    params: &StartupListQuery,
+    current_user: &user::Model,
+) -> Result<Condition, StatusCode> {
+    let mut filters =
+        Condition::all().add(startup::Column::IsTrashed.eq(params.trashed.unwrap_or(false)));
+
+    if let Some(status) = non_empty(params.status.as_deref()) {
+        let stages = status
+            .split(',')
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| {
+                PipelineStage::parse(value)
+                    .map(|stage| stage.as_str().to_string())
+                    .ok_or(StatusCode::BAD_REQUEST)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        filters = filters.add(startup::Column::Status.is_in(stages));
+    }
+    if let Some(category) = non_empty(params.category.as_deref()) {
+        filters = filters.add(Expr::col(startup::Column::Category).ilike(category));
+    }
+    if let Some(next_step) = non_empty(params.next_step.as_deref()) {
+        filters =
+            filters.add(Expr::col(startup::Column::NextStep).ilike(format!("%{}%", next_step)));
+    }
+    if let Some(admin_claimed) = params.admin_claimed {
+        filters = filters.add(startup::Column::AdminClaimed.eq(admin_claimed));
+    }
+    if params.broken_links == Some(true) {
+        filters = filters.add(
+            Condition::any()
+                .add(startup::Column::WebsiteBroken.eq(true))
+                .add(startup::Column::NewsroomUrlBroken.eq(true)),
+        );
+    }
+    if let Some(owner_id) = params.owner_id {
+        filters = filters.add(startup::Column::OwnerId.eq(owner_id));
+    }
+    if params.mine.unwrap_or(false) {
+        filters = filters.add(startup::Column::OwnerId.eq(current_user.id));
+    }
+
+    if let Some(start) = non_empty(params.last_contact_from.as_deref()) {
+        let start_dt = parse_date_str(start)?.and_hms_opt(0, 0, 0).unwrap();
+        filters = filters.add(startup::Column::LastContactDate.gte(start_dt));
+    }
+    if let Some(end) = non_empty(params.last_contact_to.as_deref()) {
+        let end_dt = parse_date_str(end)?.and_hms_opt(23, 59, 59).unwrap();
+        filters = filters.add(startup::Column::LastContactDate.lte(end_dt));
+    }
+
+    if let Some(custom_fields) = non_empty(params.custom_fields.as_deref()) {
+        let definitions = load_definitions(db, ENTITY_STARTUP)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        filters = filters.add(custom_field_filter(
+            startup::Entity,
+            startup::Column::CustomFields,
+            &definitions,
+            custom_fields,
+        )?);
+    }
+
+    if let Some(tags) = non_empty(params.tags.as_deref()) {
+        filters = filters.add(tag_filter(
+            startup::Column::Id,
+            TaggableEntity::Startup,
+            tags,
+        ));
+    }
+
+    if let Some(search) = non_empty(params.search.as_deref()) {
+        let pattern = format!("%{}%", search);
+        filters = filters.add(
+            Condition::any()
+                .add(Expr::col(startup::Column::Name).ilike(pattern.clone()))
+                .add(Expr::col(startup::Column::Website).ilike(pattern)),
+        );
+    }
+
+    Ok(filters)
+}
+
+fn apply_startup_sort(
+    query: sea_orm::Select<startup::Entity>,
+    params: &StartupListQuery,
+) -> Result<sea_orm::Select<startup::Entity>, StatusCode> {
+    let column = match non_empty(params.sort_by.as_deref()).unwrap_or("created_at") {
+        "name" => startup::Column::Name,
+        "status" => startup::Column::Status,
+        "category" => startup::Column::Category,
+        "last_contact_date" => startup::Column::LastContactDate,
+        "signal_score" => startup::Column::SignalScore,
+        "created_at" => startup::Column::CreatedAt,
+        "updated_at" => startup::Column::UpdatedAt,
+        _ => return Err(StatusCode::BAD_REQUEST),
+    };
+    let order = match non_empty(params.sort_order.as_deref()).unwrap_or("desc") {
+        "asc" => Order::Asc,
+        "desc" => Order::Desc,
+        _ => return Err(StatusCode::BAD_REQUEST),
+    };
+
+    Ok(query
+        .order_by_with_nulls(column, order, NullOrdering::Last)
+        .order_by_asc(startup::Column::Id))
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
 }
 
 async fn get_startup(
     State(state): State<AppState>,
-    _auth_user: AuthUser,
+    AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
 ) -> Result<Json<startup::Model>, StatusCode> {
     let startup = startup::Entity::find_by_id(id)
@@ -407,6 +665,11 @@ async fn get_startup(
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .ok_or(StatusCode::NOT_FOUND)?;
 
+    // Only admins can look at trashed startups, e.g. before restoring them.
+    if startup.is_trashed && !user.is_admin() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
     Ok(Json(startup))
 }
 
@@ -415,6 +678,34 @@ async fn create_startup(
     AuthUser(user): AuthUser,
     Json(payload): Json<CreateStartupRequest>,
 ) -> Result<Json<startup::Model>, StatusCode> {
+    let stage = PipelineStage::parse(&payload.status).ok_or(StatusCode::BAD_REQUEST)?;
+    let custom_fields = resolve_custom_fields(
+        &state.db,
+        ENTITY_STARTUP,
+        &json!({}),
+        Some(&payload.custom_fields.unwrap_or_default()),
+    )
+    .await?;
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    // A hand-picked owner leaves the round-robin cursor where it is.
+    let owner_id = match payload.owner_id {
+        Some(owner_id) => find_assignable_owner(&txn, owner_id).await?.id,
+        None => {
+            let mut owners = NewStartupOwners::load(&txn, state.owner_assignment, &user)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            let owner_id = owners.next();
+            owners
+                .save(&txn)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            owner_id
+        }
+    };
     let now = Utc::now().naive_utc();
 
     let startup = startup::ActiveModel {
@@ -423,16 +714,29 @@ async fn create_startup(
         category: Set(payload.category),
         website: Set(payload.website),
         newsroom_url: Set(payload.newsroom_url),
-        status: Set(payload.status),
+        status: Set(stage.as_str().to_string()),
         last_contact_date: Set(None),
         next_step: Set(None),
         admin_claimed: Set(false),
+        owner_id: Set(Some(owner_id)),
+        is_trashed: Set(false),
+        trashed_at: Set(None),
+        custom_fields: Set(custom_fields),
+        signal_score: Set(0),
+        signal_breakdown: Set(json!([])),
+        website_broken: Set(false),
+        newsroom_url_broken: Set(false),
+        claimed_at: Set(None),
+        claimed_by_contact_id: Set(None),
         created_at: Set(now),
         updated_at: Set(now),
     };
 
     let result = startup
-        .insert(&state.db)
+        .insert(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    txn.commit()
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
@@ -452,7 +756,7 @@ async fn create_startup(
             contact_name: None,
             stage_from: None,
             stage_to: Some(startup_status.clone()),
-            metadata: Some(json!({ "status": startup_status })),
+            metadata: Some(json!({ "status": startup_status, "owner_id": owner_id })),
             occurred_at: None,
         },
     )
@@ -470,19 +774,40 @@ async fn update_startup(
     Path(id): Path<Uuid>,
     Json(payload): Json<CreateStartupRequest>,
 ) -> Result<Json<startup::Model>, StatusCode> {
-    let existing = startup::Entity::find_by_id(id)
-        .one(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .ok_or(StatusCode::NOT_FOUND)?;
+    let existing = find_active_startup(&state.db, id).await?;
 
+    let stage = PipelineStage::parse(&payload.status).ok_or(StatusCode::BAD_REQUEST)?;
     let previous_status = existing.status.clone();
+    let direction = if stage.as_str() != previous_status {
+        // Moves that need a reason must go through the transition endpoint.
+        check_stage_transition(&state.pipeline, &previous_status, stage, None)?
+    } else {
+        None
+    };
+
+    let custom_fields = resolve_custom_fields(
+        &state.db,
+        ENTITY_STARTUP,
+        &existing.custom_fields,
+        payload.custom_fields.as_ref(),
+    )
+    .await?;
+
+    let before = existing.clone();
     let mut active: startup::ActiveModel = existing.into();
+    // A new URL has not been checked yet.
+    if payload.website != before.website {
+        active.website_broken = Set(false);
+    }
+    if payload.newsroom_url != before.newsroom_url {
+        active.newsroom_url_broken = Set(false);
+    }
     active.name = Set(payload.name);
     active.category = Set(payload.category);
     active.website = Set(payload.website);
     active.newsroom_url = Set(payload.newsroom_url);
-    active.status = Set(payload.status.clone());
+    active.status = Set(stage.as_str().to_string());
+    active.custom_fields = Set(custom_fields);
     active.updated_at = Set(Utc::now().naive_utc());
 
     let result = active
@@ -490,54 +815,440 @@ async fn update_startup(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    if let Err(err) = record_startup_revisions(&state.db, &before, &result, Some(&user)).await {
+        tracing::warn!(error = ?err, "failed to record startup revisions");
+    }
     if previous_status != result.status {
-        if let Err(err) = record_activity_event(
-            &state.db,
-            ActivityEventInput {
-                activity_type: ACTIVITY_STAGE_MOVED,
-                description: format!(
-                    "Moved {} from {} to {}",
-                    result.name, previous_status, result.status
-                ),
-                user_id: Some(user.id),
-                user_name: Some(user_display_name(&user)),
-                startup_id: Some(result.id),
-                startup_name: Some(result.name.clone()),
-                contact_id: None,
-                contact_name: None,
-                stage_from: Some(previous_status),
-                stage_to: Some(result.status.clone()),
-                metadata: None,
-                occurred_at: None,
-            },
-        )
+        record_stage_move(&state.db, &user, &result, previous_status, direction, None).await;
+    }
+
+    Ok(Json(result))
+}
+
+async fn transition_startup(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<StartupTransitionRequest>,
+) -> Result<Json<startup::Model>, StatusCode> {
+    let existing = find_active_startup(&state.db, id).await?;
+
+    let stage = PipelineStage::parse(&payload.to_stage).ok_or(StatusCode::BAD_REQUEST)?;
+    let reason = payload
+        .reason
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    let previous_status = existing.status.clone();
+    let direction =
+        check_stage_transition(&state.pipeline, &previous_status, stage, reason.as_deref())?;
+
+    let before = existing.clone();
+    let mut active: startup::ActiveModel = existing.into();
+    active.status = Set(stage.as_str().to_string());
+    active.updated_at = Set(Utc::now().naive_utc());
+
+    let result = active
+        .update(&state.db)
         .await
-        {
-            tracing::warn!(error = ?err, "failed to record stage move activity");
-        }
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if let Err(err) = record_startup_revisions(&state.db, &before, &result, Some(&user)).await {
+        tracing::warn!(error = ?err, "failed to record startup revisions");
     }
+    record_stage_move(
+        &state.db,
+        &user,
+        &result,
+        previous_status,
+        direction,
+        reason,
+    )
+    .await;
 
     Ok(Json(result))
 }
 
+async fn list_pipeline_stages(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+) -> Json<Vec<PipelineStageResponse>> {
+    let stages = ALL_STAGES
+        .iter()
+        .map(|stage| PipelineStageResponse {
+            stage: *stage,
+            position: stage.position(),
+            allowed_transitions: state.pipeline.allowed_targets(*stage).to_vec(),
+        })
+        .collect();
+
+    Json(stages)
+}
+
+/// Validate a move from the stored status to `to`. Rows whose stored status is
+/// not a known stage may move anywhere so they can be repaired; the returned
+/// direction is `None` in that case.
+fn check_stage_transition(
+    graph: &TransitionGraph,
+    current_status: &str,
+    to: PipelineStage,
+    reason: Option<&str>,
+) -> Result<Option<TransitionDirection>, StatusCode> {
+    let Some(from) = PipelineStage::parse(current_status) else {
+        return Ok(None);
+    };
+
+    graph.validate(from, to, reason).map(Some).map_err(|err| {
+        tracing::debug!(error = %err, "rejected stage transition");
+        match err {
+            TransitionError::Unchanged => StatusCode::BAD_REQUEST,
+            TransitionError::NotAllowed { .. } | TransitionError::ReasonRequired => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+        }
+    })
+}
+
+fn stage_move_event(
+    user: &user::Model,
+    startup: &startup::Model,
+    previous_status: String,
+    direction: Option<TransitionDirection>,
+    reason: Option<String>,
+) -> ActivityEventInput {
+    let description = match reason.as_deref() {
+        Some(reason) => format!(
+            "Moved {} from {} to {}: {}",
+            startup.name, previous_status, startup.status, reason
+        ),
+        None => format!(
+            "Moved {} from {} to {}",
+            startup.name, previous_status, startup.status
+        ),
+    };
+
+    ActivityEventInput {
+        activity_type: ACTIVITY_STAGE_MOVED,
+        description,
+        user_id: Some(user.id),
+        user_name: Some(user_display_name(user)),
+        startup_id: Some(startup.id),
+        startup_name: Some(startup.name.clone()),
+        contact_id: None,
+        contact_name: None,
+        stage_from: Some(previous_status),
+        stage_to: Some(startup.status.clone()),
+        metadata: Some(json!({ "reason": reason, "direction": direction })),
+        occurred_at: None,
+    }
+}
+
+async fn record_stage_move(
+    db: &DatabaseConnection,
+    user: &user::Model,
+    startup: &startup::Model,
+    previous_status: String,
+    direction: Option<TransitionDirection>,
+    reason: Option<String>,
+) {
+    let event = stage_move_event(user, startup, previous_status, direction, reason);
+    if let Err(err) = record_activity_event(db, event).await {
+        tracing::warn!(error = ?err, "failed to record stage move activity");
+    }
+}
+
+async fn find_active_startup<C: ConnectionTrait>(
+    db: &C,
+    id: Uuid,
+) -> Result<startup::Model, StatusCode> {
+    startup::Entity::find_by_id(id)
+        .filter(startup::Column::IsTrashed.eq(false))
+        .one(db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Moves a startup to the trash. Its contacts are hidden with it but keep
+/// their own trash state, so restoring brings back exactly what was visible.
 async fn delete_startup(
     State(state): State<AppState>,
     _auth_user: AuthUser,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, StatusCode> {
+    let existing = find_active_startup(&state.db, id).await?;
+
+    let mut active: startup::ActiveModel = existing.into();
+    active.is_trashed = Set(true);
+    active.trashed_at = Set(Some(Utc::now().naive_utc()));
+    active
+        .update(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn restore_startup(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<startup::Model>, StatusCode> {
     let existing = startup::Entity::find_by_id(id)
         .one(&state.db)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .ok_or(StatusCode::NOT_FOUND)?;
 
-    let active: startup::ActiveModel = existing.into();
-    active
-        .delete(&state.db)
+    if !existing.is_trashed {
+        return Ok(Json(existing));
+    }
+
+    let mut active: startup::ActiveModel = existing.into();
+    active.is_trashed = Set(false);
+    active.trashed_at = Set(None);
+    let restored = active
+        .update(&state.db)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok(Json(restored))
+}
+
+/// Everything a permanent startup delete touches. Deleted counts are rows
+/// removed with the startup; detached counts are rows that survive with
+/// their startup link cleared.
+#[derive(Serialize)]
+struct StartupPurgeReport {
+    startup_id: Uuid,
+    startup_name: String,
+    contacts_deleted: u64,
+    contact_identities_deleted: u64,
+    outreach_logs_deleted: u64,
+    interviews_deleted: u64,
+    interview_insights_deleted: u64,
+    tasks_deleted: u64,
+    newsroom_claims_deleted: u64,
+    intro_requests_deleted: u64,
+    field_revisions_deleted: u64,
+    url_checks_deleted: u64,
+    tag_assignments_deleted: u64,
+    conversations_detached: u64,
+    activity_events_detached: u64,
+    purged: bool,
+}
+
+async fn find_trashed_startup(
+    db: &DatabaseConnection,
+    id: Uuid,
+) -> Result<startup::Model, StatusCode> {
+    let existing = startup::Entity::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Startups must go through the trash before they can be purged.
+    if !existing.is_trashed {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    Ok(existing)
+}
+
+/// Ids of the startup's contacts and interviews, whose tags have to be
+/// removed by hand.
+async fn startup_tagged_children<C: ConnectionTrait>(
+    db: &C,
+    startup_id: Uuid,
+) -> Result<(Vec<Uuid>, Vec<Uuid>), sea_orm::DbErr> {
+    let contact_ids: Vec<Uuid> = contact::Entity::find()
+        .select_only()
+        .column(contact::Column::Id)
+        .filter(contact::Column::StartupId.eq(startup_id))
+        .into_tuple()
+        .all(db)
+        .await?;
+    let interview_ids: Vec<Uuid> = interview::Entity::find()
+        .select_only()
+        .column(interview::Column::Id)
+        .filter(interview::Column::StartupId.eq(startup_id))
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    Ok((contact_ids, interview_ids))
+}
+
+async fn build_startup_purge_report<C: ConnectionTrait>(
+    db: &C,
+    startup: &startup::Model,
+) -> Result<StartupPurgeReport, sea_orm::DbErr> {
+    let (contact_ids, interview_ids) = startup_tagged_children(db, startup.id).await?;
+    let mut tag_assignments_deleted = 0;
+    for (entity, ids) in [
+        (TaggableEntity::Startup, vec![startup.id]),
+        (TaggableEntity::Contact, contact_ids.clone()),
+        (TaggableEntity::Interview, interview_ids.clone()),
+    ] {
+        tag_assignments_deleted += count_tag_assignments(db, entity, ids).await?;
+    }
+
+    Ok(StartupPurgeReport {
+        startup_id: startup.id,
+        startup_name: startup.name.clone(),
+        contacts_deleted: contact_ids.len() as u64,
+        contact_identities_deleted: contact_identity::Entity::find()
+            .filter(contact_identity::Column::ContactId.is_in(contact_ids))
+            .count(db)
+            .await?,
+        outreach_logs_deleted: outreach_log::Entity::find()
+            .filter(outreach_log::Column::StartupId.eq(startup.id))
+            .count(db)
+            .await?,
+        interviews_deleted: interview_ids.len() as u64,
+        interview_insights_deleted: interview_insight::Entity::find()
+            .filter(interview_insight::Column::InterviewId.is_in(interview_ids))
+            .count(db)
+            .await?,
+        tasks_deleted: task::Entity::find()
+            .filter(task::Column::StartupId.eq(startup.id))
+            .count(db)
+            .await?,
+        newsroom_claims_deleted: newsroom_claim::Entity::find()
+            .filter(newsroom_claim::Column::StartupId.eq(startup.id))
+            .count(db)
+            .await?,
+        intro_requests_deleted: intro_request::Entity::find()
+            .filter(intro_request::Column::StartupId.eq(startup.id))
+            .count(db)
+            .await?,
+        field_revisions_deleted: field_revision::Entity::find()
+            .filter(field_revision::Column::StartupId.eq(startup.id))
+            .count(db)
+            .await?,
+        url_checks_deleted: url_check::Entity::find()
+            .filter(url_check::Column::StartupId.eq(startup.id))
+            .count(db)
+            .await?,
+        tag_assignments_deleted,
+        conversations_detached: conversation::Entity::find()
+            .filter(conversation::Column::StartupId.eq(startup.id))
+            .count(db)
+            .await?,
+        activity_events_detached: activity_event::Entity::find()
+            .filter(activity_event::Column::StartupId.eq(startup.id))
+            .count(db)
+            .await?,
+        purged: false,
+    })
+}
+
+/// POST /api/admin/startups/backfill-contact-fields
+/// Recompute `last_contact_date`, `next_step` and the signal score for every
+/// startup from its outreach, interviews and emails.
+async fn backfill_contact_fields(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+) -> Result<Json<BackfillReport>, StatusCode> {
+    let report = backfill_startup_contact_fields(&state.db)
+        .await
+        .map_err(|err| {
+            tracing::error!(error = ?err, "failed to backfill startup contact fields");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(report))
+}
+
+/// GET /api/startups/:id/url-health
+/// Latest website and newsroom check for a startup.
+async fn get_startup_url_health(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<url_check::Model>>, StatusCode> {
+    let startup = find_active_startup(&state.db, id).await?;
+    let checks = url_check::Entity::find()
+        .filter(url_check::Column::StartupId.eq(startup.id))
+        .order_by_asc(url_check::Column::Kind)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(checks))
+}
+
+/// POST /api/admin/startups/check-urls
+/// Run the URL health check now instead of waiting for the scheduler.
+async fn run_url_health_check(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+) -> Result<Json<UrlHealthReport>, StatusCode> {
+    let report = check_all_urls(&state.db).await.map_err(|err| {
+        tracing::error!(error = ?err, "failed to check startup URLs");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(report))
+}
+
+async fn preview_startup_purge(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<StartupPurgeReport>, StatusCode> {
+    let existing = find_trashed_startup(&state.db, id).await?;
+    let report = build_startup_purge_report(&state.db, &existing)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(report))
+}
+
+async fn permanently_delete_startup(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<StartupPurgeReport>, StatusCode> {
+    let existing = find_trashed_startup(&state.db, id).await?;
+
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut report = build_startup_purge_report(&txn, &existing)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    // Tag assignments are polymorphic and have no foreign key to cascade on.
+    // Everything else in the report cascades in the database, while
+    // conversations and activity events are kept with their startup cleared.
+    let (contact_ids, interview_ids) = startup_tagged_children(&txn, existing.id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    for (entity, ids) in [
+        (TaggableEntity::Startup, vec![existing.id]),
+        (TaggableEntity::Contact, contact_ids),
+        (TaggableEntity::Interview, interview_ids),
+    ] {
+        delete_tag_assignments(&txn, entity, ids)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
+    startup::Entity::delete_by_id(existing.id)
+        .exec(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    txn.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    report.purged = true;
+    Ok(Json(report))
 }
 
 // Contact handlers
@@ -552,7 +1263,7 @@ async fn list_contacts(
         return Err(StatusCode::FORBIDDEN);
     }
 
-    let contacts = fetch_contacts(&state.db, None, trashed).await?;
+    let contacts = fetch_contacts(&state.db, None, trashed, &params).await?;
     Ok(Json(contacts))
 }
 
@@ -568,7 +1279,7 @@ async fn list_contacts_for_startup(
         return Err(StatusCode::FORBIDDEN);
     }
 
-    let contacts = fetch_contacts(&state.db, Some(startup_id), trashed).await?;
+    let contacts = fetch_contacts(&state.db, Some(startup_id), trashed, &params).await?;
     Ok(Json(contacts))
 }
 
@@ -577,6 +1288,14 @@ async fn create_contact(
     AuthUser(user): AuthUser,
     Json(payload): Json<CreateContactRequest>,
 ) -> Result<Json<ContactResponse>, StatusCode> {
+    let custom_fields = resolve_custom_fields(
+        &state.db,
+        ENTITY_CONTACT,
+        &json!({}),
+        Some(&payload.custom_fields.unwrap_or_default()),
+    )
+    .await?;
+
     let contact = contact::ActiveModel {
         id: Set(Uuid::new_v4()),
         startup_id: Set(payload.startup_id),
@@ -588,13 +1307,18 @@ async fn create_contact(
         is_primary: Set(payload.is_primary.unwrap_or(false)),
         notes: Set(payload.notes),
         is_trashed: Set(false),
+        trashed_at: Set(None),
         owner_id: Set(Some(user.id)),
+        custom_fields: Set(custom_fields),
     };
 
     let inserted = contact
         .insert(&state.db)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if let Err(err) = sync_primary_identities(&state.db, &inserted).await {
+        tracing::warn!(error = ?err, "failed to sync contact identities");
+    }
 
     let startup_name = lookup_startup_name(&state.db, inserted.startup_id).await;
 
@@ -640,7 +1364,17 @@ async fn update_contact(
         return Err(StatusCode::FORBIDDEN);
     }
 
+    let custom_fields = resolve_custom_fields(
+        &state.db,
+        ENTITY_CONTACT,
+        &existing.custom_fields,
+        payload.custom_fields.as_ref(),
+    )
+    .await?;
+
+    let before = existing.clone();
     let mut active: contact::ActiveModel = existing.into();
+    active.custom_fields = Set(custom_fields);
     if let Some(name) = payload.name {
         active.name = Set(name);
     }
@@ -668,6 +1402,15 @@ async fn update_contact(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    if let Err(err) = record_contact_revisions(&state.db, &before, &updated, Some(&user)).await {
+        tracing::warn!(error = ?err, "failed to record contact revisions");
+    }
+    if before.email != updated.email || before.phone != updated.phone {
+        if let Err(err) = sync_primary_identities(&state.db, &updated).await {
+            tracing::warn!(error = ?err, "failed to sync contact identities");
+        }
+    }
+
     let response = load_contact_with_owner(&state.db, updated.id).await?;
     Ok(Json(response))
 }
@@ -721,6 +1464,9 @@ async fn permanently_delete_contact(
         return Err(StatusCode::BAD_REQUEST);
     }
 
+    delete_tag_assignments(&state.db, TaggableEntity::Contact, vec![existing.id])
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     let active: contact::ActiveModel = existing.into();
     active
         .delete(&state.db)
@@ -730,6 +1476,59 @@ async fn permanently_delete_contact(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// GET /api/admin/contacts/purge
+/// Trashed contacts the next purge run will delete.
+async fn preview_trashed_contact_purge(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+) -> Result<Json<PurgePreview>, StatusCode> {
+    let preview = preview_contact_purge(&state.db, state.contact_retention)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(preview))
+}
+
+/// POST /api/admin/contacts/purge
+/// Run the purge now instead of waiting for the scheduler. Returns 409 when
+/// the purge is turned off.
+async fn run_trashed_contact_purge(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+) -> Result<Json<contact_purge_run::Model>, StatusCode> {
+    let run = purge_trashed_contacts(
+        &state.db,
+        state.contact_retention,
+        PURGE_TRIGGER_MANUAL,
+        Some(admin.id),
+    )
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .ok_or(StatusCode::CONFLICT)?;
+
+    Ok(Json(run))
+}
+
+#[derive(Deserialize, Default)]
+struct PurgeRunListQuery {
+    limit: Option<u64>,
+}
+
+/// GET /api/admin/contacts/purge/runs
+async fn list_trashed_contact_purge_runs(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    query: Option<Query<PurgeRunListQuery>>,
+) -> Result<Json<Vec<contact_purge_run::Model>>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    let limit = params.limit.unwrap_or(50).clamp(1, 200);
+    let runs = list_purge_runs(&state.db, limit)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(runs))
+}
+
 async fn bulk_restore_contacts(
     State(state): State<AppState>,
     AdminUser(_admin): AdminUser,
@@ -768,6 +1567,9 @@ async fn bulk_delete_contacts(
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         {
             if existing.is_trashed {
+                delete_tag_assignments(&state.db, TaggableEntity::Contact, vec![existing.id])
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
                 let active: contact::ActiveModel = existing.into();
                 active
                     .delete(&state.db)
@@ -796,9 +1598,30 @@ async fn fetch_contacts(
     db: &DatabaseConnection,
     startup_id: Option<Uuid>,
     trashed: bool,
+    params: &ContactListQuery,
 ) -> Result<Vec<ContactResponse>, StatusCode> {
     let mut query = contact::Entity::find().order_by_asc(contact::Column::Name);
 
+    if let Some(custom_fields) = non_empty(params.custom_fields.as_deref()) {
+        let definitions = load_definitions(db, ENTITY_CONTACT)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        query = query.filter(custom_field_filter(
+            contact::Entity,
+            contact::Column::CustomFields,
+            &definitions,
+            custom_fields,
+        )?);
+    }
+
+    if let Some(tags) = non_empty(params.tags.as_deref()) {
+        query = query.filter(tag_filter(
+            contact::Column::Id,
+            TaggableEntity::Contact,
+            tags,
+        ));
+    }
+
     if let Some(id) = startup_id {
         query = query.filter(contact::Column::StartupId.eq(id));
     }
@@ -806,7 +1629,16 @@ async fn fetch_contacts(
     if trashed {
         query = query.filter(contact::Column::IsTrashed.eq(true));
     } else {
-        query = query.filter(contact::Column::IsTrashed.eq(false));
+        // Contacts of a trashed startup are hidden along with it.
+        query = query.filter(contact::Column::IsTrashed.eq(false)).filter(
+            contact::Column::StartupId.not_in_subquery(
+                sea_orm::sea_query::Query::select()
+                    .column(startup::Column::Id)
+                    .from(startup::Entity)
+                    .and_where(startup::Column::IsTrashed.eq(true))
+                    .to_owned(),
+            ),
+        );
     }
 
     let contacts = query
@@ -843,6 +1675,7 @@ async fn set_contact_trash_state(
 
     let mut active: contact::ActiveModel = contact_model.into();
     active.is_trashed = Set(trashed);
+    active.trashed_at = Set(trashed.then(|| Utc::now().naive_utc()));
 
     let updated = active
         .update(db)
@@ -920,6 +1753,12 @@ async fn create_outreach_log(
     {
         tracing::warn!(error = ?err, "failed to record outreach activity");
     }
+    if let Err(err) = refresh_startup_contact_fields(&state.db, result.startup_id).await {
+        tracing::warn!(error = ?err, "failed to refresh startup contact fields");
+    }
+    if let Err(err) = clear_stale_follow_ups(&state.db, result.startup_id).await {
+        tracing::warn!(error = ?err, "failed to clear stale-lead follow-ups");
+    }
 
     Ok(Json(result))
 }
@@ -939,11 +1778,7 @@ async fn send_contact_email_handler(
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .ok_or(StatusCode::NOT_FOUND)?;
 
-    let startup = startup::Entity::find_by_id(startup_id)
-        .one(&state.db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .ok_or(StatusCode::NOT_FOUND)?;
+    let startup = find_active_startup(&state.db, startup_id).await?;
 
     let template_kind = payload.template;
     let defaults = template_kind.defaults(&contact.name, &startup.name);
@@ -964,13 +1799,27 @@ async fn send_contact_email_handler(
         text_body = fallback_plain_text(&html_body);
     }
 
+    let recipient = match payload.identity_id {
+        Some(identity_id) => Some(
+            contact_identity::Entity::find_by_id(identity_id)
+                .filter(contact_identity::Column::ContactId.eq(contact.id))
+                .filter(contact_identity::Column::Kind.eq(IdentityKind::Email.as_str()))
+                .one(&state.db)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?
+                .value,
+        ),
+        None => contact.email.clone(),
+    };
+
     let sender_email = sender.email.clone();
     let sender_name = sender.name.clone().unwrap_or_else(|| sender_email.clone());
 
     let send_result = state
         .email_service
         .send_contact_email(
-            contact.email.as_ref(),
+            recipient.as_ref(),
             &sender_name,
             &sender_email,
             &subject,
@@ -1006,6 +1855,12 @@ async fn send_contact_email_handler(
         .insert(&state.db)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if let Err(err) = refresh_startup_contact_fields(&state.db, startup_id).await {
+        tracing::warn!(error = ?err, "failed to refresh startup contact fields");
+    }
+    if let Err(err) = clear_stale_follow_ups(&state.db, startup_id).await {
+        tracing::warn!(error = ?err, "failed to clear stale-lead follow-ups");
+    }
 
     Ok(Json(SendContactEmailResponse {
         message_id: send_result.message_id,
@@ -1065,11 +1920,28 @@ async fn list_interviews_for_startup(
     Ok(Json(interviews))
 }
 
+#[derive(Deserialize, Default)]
+struct InterviewListQuery {
+    tags: Option<String>,
+}
+
 async fn list_all_interviews(
     State(state): State<AppState>,
     _auth_user: AuthUser,
+    query: Option<Query<InterviewListQuery>>,
 ) -> Result<Json<Vec<interview::Model>>, StatusCode> {
-    let interviews = interview::Entity::find()
+    let params = query.map(|q| q.0).unwrap_or_default();
+    let mut query = interview::Entity::find();
+
+    if let Some(tags) = non_empty(params.tags.as_deref()) {
+        query = query.filter(tag_filter(
+            interview::Column::Id,
+            TaggableEntity::Interview,
+            tags,
+        ));
+    }
+
+    let interviews = query
         .order_by_desc(interview::Column::Date)
         .all(&state.db)
         .await
@@ -1135,6 +2007,9 @@ async fn create_interview(
     {
         tracing::warn!(error = ?err, "failed to record interview activity");
     }
+    if let Err(err) = refresh_startup_contact_fields(&state.db, result.startup_id).await {
+        tracing::warn!(error = ?err, "failed to refresh startup contact fields");
+    }
 
     Ok(Json(result))
 }
@@ -1191,6 +2066,17 @@ async fn create_interview_insight(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let startup_id = interview::Entity::find_by_id(result.interview_id)
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(|interview| interview.startup_id);
+    if let Some(startup_id) = startup_id {
+        if let Err(err) = rescore_startup(&state.db, startup_id).await {
+            tracing::warn!(error = ?err, "failed to rescore startup after interview insight");
+        }
+    }
+
     Ok(Json(result))
 }
 
@@ -1359,11 +2245,15 @@ async fn update_weekly_activity_plan(
             .as_ref()
             .map(|value| value.trim().to_lowercase())
             .filter(|value| !value.is_empty());
-        let stage_name = metric
+        let stage_name = match metric
             .stage_name
-            .as_ref()
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty());
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            Some(value) => Some(parse_metric_stage(value).ok_or(StatusCode::BAD_REQUEST)?),
+            None => None,
+        };
 
         if name.is_empty() || unit_label.is_empty() {
             return Err(StatusCode::BAD_REQUEST);
@@ -1570,8 +2460,8 @@ async fn list_activity_feed(
     }))
 }
 
-async fn record_activity_event(
-    db: &DatabaseConnection,
+async fn record_activity_event<C: ConnectionTrait>(
+    db: &C,
     input: ActivityEventInput,
 ) -> Result<(), sea_orm::DbErr> {
     let occurred_at = input.occurred_at.unwrap_or_else(|| Utc::now().naive_utc());
@@ -1609,8 +2499,8 @@ async fn record_activity_event(
     Ok(())
 }
 
-async fn increment_metrics_for_event(
-    db: &DatabaseConnection,
+async fn increment_metrics_for_event<C: ConnectionTrait>(
+    db: &C,
     activity_type: &'static str,
     stage_to: Option<&str>,
     activity_date: NaiveDate,
@@ -1626,21 +2516,7 @@ async fn increment_metrics_for_event(
         .await?;
 
     for metric in metrics {
-        let should_increment = if metric.metric_type == METRIC_TYPE_INPUT {
-            metric
-                .activity_type
-                .as_deref()
-                .map(|value| value == activity_type)
-                .unwrap_or(false)
-        } else if metric.metric_type == METRIC_TYPE_OUTPUT {
-            stage_to
-                .and_then(|stage| metric.stage_name.as_deref().map(|value| value == stage))
-                .unwrap_or(false)
-        } else {
-            false
-        };
-
-        if should_increment {
+        if metric_counts_event(&metric, activity_type, stage_to) {
             let mut active: weekly_metric_definition::ActiveModel = metric.clone().into();
             active.actual_value = Set(metric.actual_value + 1);
             active.updated_at = Set(Utc::now().naive_utc());
@@ -1651,8 +2527,33 @@ async fn increment_metrics_for_event(
     Ok(())
 }
 
-async fn find_plan_covering_date(
-    db: &DatabaseConnection,
+/// Input metrics count events of their activity type, output metrics events
+/// that reach their stage.
+fn metric_counts_event(
+    metric: &weekly_metric_definition::Model,
+    activity_type: &str,
+    stage_to: Option<&str>,
+) -> bool {
+    if metric.metric_type == METRIC_TYPE_INPUT {
+        metric.activity_type.as_deref() == Some(activity_type)
+    } else if metric.metric_type == METRIC_TYPE_OUTPUT {
+        stage_to.is_some() && metric.stage_name.as_deref() == stage_to
+    } else {
+        false
+    }
+}
+
+/// The stage an output metric tracks: a pipeline stage, or the claimed
+/// newsroom milestone recorded when a contact accepts a claim link.
+fn parse_metric_stage(value: &str) -> Option<String> {
+    if value.eq_ignore_ascii_case(NEWSROOM_CLAIMED_STAGE) {
+        return Some(NEWSROOM_CLAIMED_STAGE.to_string());
+    }
+    PipelineStage::parse(value).map(|stage| stage.as_str().to_string())
+}
+
+async fn find_plan_covering_date<C: ConnectionTrait>(
+    db: &C,
     date: NaiveDate,
 ) -> Result<Option<weekly_activity_plan::Model>, sea_orm::DbErr> {
     weekly_activity_plan::Entity::find()
@@ -1666,8 +2567,8 @@ async fn find_plan_covering_date(
         .await
 }
 
-async fn ensure_plan_active_for_date(
-    db: &DatabaseConnection,
+async fn ensure_plan_active_for_date<C: ConnectionTrait>(
+    db: &C,
     plan: weekly_activity_plan::Model,
     date: NaiveDate,
 ) -> Result<weekly_activity_plan::Model, sea_orm::DbErr> {
@@ -1825,6 +2726,68 @@ fn spawn_weekly_plan_scheduler(db: DatabaseConnection) {
     });
 }
 
+fn spawn_stale_lead_scheduler(db: DatabaseConnection) {
+    tokio::spawn(async move {
+        let thresholds = StaleThresholds::from_env();
+        let mut ticker = interval(TokioDuration::from_secs(60 * 60));
+        loop {
+            ticker.tick().await;
+            // Staleness reads last_contact_date, which must include interviews
+            // that took place since the last sweep.
+            if let Err(err) = refresh_passed_interviews(&db).await {
+                tracing::warn!(error = ?err, "failed to refresh startups after interviews");
+            }
+            if let Err(err) = sweep_stale_leads(&db, &thresholds).await {
+                tracing::warn!(error = ?err, "failed to sweep stale leads");
+            }
+        }
+    });
+}
+
+fn spawn_url_health_scheduler(db: DatabaseConnection) {
+    tokio::spawn(async move {
+        let mut ticker = interval(TokioDuration::from_secs(60 * 60 * 6));
+        loop {
+            ticker.tick().await;
+            if let Err(err) = check_all_urls(&db).await {
+                tracing::warn!(error = ?err, "failed to check startup URLs");
+            }
+        }
+    });
+}
+
+fn spawn_signal_score_scheduler(db: DatabaseConnection) {
+    tokio::spawn(async move {
+        let mut ticker = interval(TokioDuration::from_secs(60 * 60 * 24));
+        loop {
+            ticker.tick().await;
+            if let Err(err) = rescore_all_startups(&db).await {
+                tracing::warn!(error = ?err, "failed to rescore startups");
+            }
+        }
+    });
+}
+
+fn spawn_contact_purge_scheduler(db: DatabaseConnection, retention: ContactRetention) {
+    if retention.days().is_none() {
+        tracing::info!("trashed contact purge is turned off");
+        return;
+    }
+    tokio::spawn(async move {
+        let mut ticker = interval(TokioDuration::from_secs(60 * 60 * 24));
+        loop {
+            ticker.tick().await;
+            match purge_trashed_contacts(&db, retention, PURGE_TRIGGER_SCHEDULED, None).await {
+                Ok(Some(run)) if run.purged_count > 0 => {
+                    tracing::info!(purged = run.purged_count, "purged trashed contacts");
+                }
+                Ok(_) => {}
+                Err(err) => tracing::warn!(error = ?err, "failed to purge trashed contacts"),
+            }
+        }
+    });
+}
+
 fn spawn_email_sync_scheduler(db: DatabaseConnection) {
     tokio::spawn(async move {
         let encryption_service = EncryptionService::new();
@@ -1893,10 +2856,18 @@ async fn main() {
     let state = AppState {
         db: db.clone(),
         email_service,
+        pipeline: TransitionGraph::from_env(),
+        owner_assignment: OwnerAssignment::from_env(),
+        contact_retention: ContactRetention::from_env(),
+        claim_signing_key: ClaimSigningKey::from_env(),
     };
 
     spawn_weekly_plan_scheduler(state.db.clone());
+    spawn_stale_lead_scheduler(state.db.clone());
     spawn_email_sync_scheduler(state.db.clone());
+    spawn_signal_score_scheduler(state.db.clone());
+    spawn_url_health_scheduler(state.db.clone());
+    spawn_contact_purge_scheduler(state.db.clone(), state.contact_retention);
 
     // Build CORS layer
     // Note: Cannot use Any wildcards with allow_credentials(true)
@@ -1954,26 +2925,134 @@ async fn main() {
         )
         // Startup routes
         .route("/api/startups", get(list_startups).post(create_startup))
+        .route(
+            "/api/startups/import",
+            post(import_controller::import_startups),
+        )
+        .route(
+            "/api/startups/export",
+            get(export_controller::export_startups),
+        )
         .route(
             "/api/startups/:id",
             get(get_startup).put(update_startup).delete(delete_startup),
         )
+        .route(
+            "/api/startups/bulk",
+            post(bulk_actions_controller::bulk_update_startups),
+        )
+        .route("/api/startups/:id/transition", post(transition_startup))
+        .route(
+            "/api/startups/:id/history",
+            get(history_controller::get_startup_history),
+        )
+        .route(
+            "/api/startups/:id/history/:revision_id/revert",
+            post(history_controller::revert_field_revision),
+        )
+        .route(
+            "/api/startups/:id/owner",
+            put(owners_controller::reassign_startup_owner),
+        )
+        .route("/api/startups/:id/url-health", get(get_startup_url_health))
+        .route("/api/admin/startups/check-urls", post(run_url_health_check))
+        .route(
+            "/api/admin/startups/backfill-contact-fields",
+            post(backfill_contact_fields),
+        )
+        .route(
+            "/api/admin/startups/owner",
+            post(owners_controller::bulk_reassign_startup_owner),
+        )
+        .route("/api/admin/startups/:id/restore", post(restore_startup))
+        .route(
+            "/api/admin/startups/:id/merge",
+            post(merge_controller::merge_startups),
+        )
+        .route(
+            "/api/admin/startups/:id/permanent",
+            get(preview_startup_purge).delete(permanently_delete_startup),
+        )
+        .route("/api/pipeline/stages", get(list_pipeline_stages))
+        // Custom field routes
+        .route(
+            "/api/custom-fields",
+            get(custom_fields_controller::list_custom_fields),
+        )
+        .route(
+            "/api/admin/custom-fields",
+            post(custom_fields_controller::create_custom_field),
+        )
+        .route(
+            "/api/admin/custom-fields/:id",
+            put(custom_fields_controller::update_custom_field)
+                .delete(custom_fields_controller::delete_custom_field),
+        )
+        // Tag routes
+        .route(
+            "/api/tags",
+            get(tags_controller::list_tags).post(tags_controller::create_tag),
+        )
+        .route("/api/tags/assign", post(tags_controller::bulk_assign_tags))
+        .route(
+            "/api/tags/unassign",
+            post(tags_controller::bulk_remove_tags),
+        )
+        .route(
+            "/api/tags/assignments",
+            get(tags_controller::list_tag_assignments),
+        )
+        .route(
+            "/api/admin/tags/:id",
+            put(tags_controller::update_tag).delete(tags_controller::delete_tag),
+        )
         // Contact routes
         .route("/api/contacts", get(list_contacts))
+        .route("/api/contacts/vcard", get(vcard_controller::export_vcards))
         .route(
             "/api/startups/:startup_id/contacts",
             get(list_contacts_for_startup).post(create_contact),
         )
+        .route(
+            "/api/startups/:startup_id/contacts/vcard",
+            get(vcard_controller::export_startup_vcards).post(vcard_controller::import_vcards),
+        )
         .route(
             "/api/contacts/:id",
             put(update_contact).delete(trash_contact),
         )
+        .route(
+            "/api/contacts/:id/identities",
+            get(contact_identities_controller::list_identities)
+                .post(contact_identities_controller::create_identity),
+        )
+        .route(
+            "/api/contacts/:id/identities/:identity_id",
+            put(contact_identities_controller::update_identity)
+                .delete(contact_identities_controller::delete_identity),
+        )
         .route("/api/admin/contacts/:id/restore", post(restore_contact))
+        .route(
+            "/api/admin/contacts/duplicates",
+            get(merge_controller::list_contact_duplicates),
+        )
+        .route(
+            "/api/admin/contacts/:id/merge",
+            post(merge_controller::merge_contacts),
+        )
         .route(
             "/api/admin/contacts/:id/permanent",
             delete(permanently_delete_contact),
         )
         .route("/api/admin/contacts/restore", post(bulk_restore_contacts))
+        .route(
+            "/api/admin/contacts/purge",
+            get(preview_trashed_contact_purge).post(run_trashed_contact_purge),
+        )
+        .route(
+            "/api/admin/contacts/purge/runs",
+            get(list_trashed_contact_purge_runs),
+        )
         .route(
             "/api/admin/contacts/delete-forever",
             post(bulk_delete_contacts),
@@ -1982,6 +3061,14 @@ async fn main() {
             "/api/startups/:startup_id/contacts/:contact_id/send-email",
             post(send_contact_email_handler),
         )
+        .route(
+            "/api/startups/:startup_id/contacts/:contact_id/claim-link",
+            post(claims_controller::send_claim_link),
+        )
+        .route(
+            "/api/claims/:token",
+            get(claims_controller::get_claim).post(claims_controller::accept_claim),
+        )
         // OutreachLog routes
         .route(
             "/api/startups/:startup_id/outreach",
@@ -1991,6 +3078,18 @@ async fn main() {
             "/api/email-status/:message_id",
             get(get_email_status_handler),
         )
+        // Intro routes
+        .route("/api/intros", get(intro_requests_controller::list_intros))
+        .route(
+            "/api/intros/:id",
+            put(intro_requests_controller::update_intro)
+                .delete(intro_requests_controller::delete_intro),
+        )
+        .route(
+            "/api/startups/:startup_id/intros",
+            get(intro_requests_controller::list_intros_for_startup)
+                .post(intro_requests_controller::create_intro),
+        )
         // Interview routes
         .route(
             "/api/startups/:startup_id/interviews",
@@ -2003,7 +3102,10 @@ async fn main() {
             get(get_insight_for_interview).post(create_interview_insight),
         )
         // WeeklySynthesis routes
-        .route("/api/weekly-synthesis", post(create_weekly_synthesis))
+        .route(
+            "/api/weekly-synthesis",
+            get(list_weekly_syntheses).post(create_weekly_synthesis),
+        )
         // Email & Conversations
         .route(
             "/api/admin/email-config",
@@ -2073,7 +3175,43 @@ async fn main() {
             post(close_weekly_activity_plan),
         )
         .route("/api/activity/summary", get(get_weekly_activity_summary))
+        .route(
+            "/api/views",
+            get(saved_views_controller::list_saved_views)
+                .post(saved_views_controller::create_saved_view),
+        )
+        .route(
+            "/api/views/:id",
+            get(saved_views_controller::get_saved_view)
+                .put(saved_views_controller::update_saved_view)
+                .delete(saved_views_controller::delete_saved_view),
+        )
+        .route(
+            "/api/views/:id/apply",
+            get(saved_views_controller::apply_saved_view),
+        )
         .route("/api/activity/feed", get(list_activity_feed))
+        .route("/api/search", get(search_controller::search))
+        // Task routes
+        .route(
+            "/api/tasks",
+            get(tasks_controller::list_tasks).post(tasks_controller::create_task),
+        )
+        .route(
+            "/api/tasks/overdue",
+            get(tasks_controller::list_overdue_tasks),
+        )
+        .route(
+            "/api/tasks/:id",
+            get(tasks_controller::get_task)
+                .put(tasks_controller::update_task)
+                .delete(tasks_controller::delete_task),
+        )
+        // Analytics routes
+        .route(
+            "/api/analytics/funnel",
+            get(analytics_controller::get_pipeline_funnel),
+        )
         .layer(cors)
         .with_state(state);
 
@@ -0,0 +1,1023 @@
+use crate::auth::middleware::AdminUser;
+use crate::contact_identities_controller::{
+    normalize_email, normalize_phone, sync_primary_identities,
+};
+use crate::custom_fields_controller::ENTITY_CONTACT;
+use crate::entities::{
+    activity_event, contact, contact_identity, conversation, field_revision, interview,
+    intro_request, newsroom_claim, outreach_log, startup, task,
+};
+use crate::history_controller::{record_contact_revisions, record_startup_revisions};
+use crate::pipeline::PipelineStage;
+use crate::services::signal_score::rescore_startup;
+use crate::tags_controller::{move_tag_assignments, TaggableEntity};
+use crate::{
+    check_stage_transition, find_active_startup, record_activity_event, stage_move_event,
+    user_display_name, ActivityEventInput, AppState, ACTIVITY_CONTACT_MERGED,
+    ACTIVITY_STARTUP_MERGED,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tracing::error;
+use uuid::Uuid;
+
+/// Which side of a merge a field value is taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeWinner {
+    Target,
+    Source,
+}
+
+/// Per-field winners. Fields left out keep the target's value, falling back
+/// to the source's when the target has none; `last_contact_date` defaults to
+/// the most recent of the two and `admin_claimed` to either being claimed.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct StartupMergeFields {
+    pub name: Option<MergeWinner>,
+    pub category: Option<MergeWinner>,
+    pub website: Option<MergeWinner>,
+    pub newsroom_url: Option<MergeWinner>,
+    pub status: Option<MergeWinner>,
+    pub last_contact_date: Option<MergeWinner>,
+    pub next_step: Option<MergeWinner>,
+    pub admin_claimed: Option<MergeWinner>,
+    pub owner_id: Option<MergeWinner>,
+}
+
+#[derive(Deserialize)]
+pub struct StartupMergeRequest {
+    pub source_id: Uuid,
+    #[serde(default)]
+    pub fields: StartupMergeFields,
+    /// Required when taking the source's status is a backward or Not a Fit
+    /// move, as for a stage transition.
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Serialize, Default)]
+pub struct ReparentedCounts {
+    pub contacts: u64,
+    pub outreach_logs: u64,
+    pub interviews: u64,
+    pub conversations: u64,
+    pub activity_events: u64,
+    pub tags: u64,
+    pub tasks: u64,
+    pub newsroom_claims: u64,
+    pub intro_requests: u64,
+}
+
+#[derive(Serialize)]
+pub struct StartupMergeResponse {
+    pub dry_run: bool,
+    pub startup: startup::Model,
+    pub merged_startup_id: Uuid,
+    pub reparented: ReparentedCounts,
+}
+
+fn pick<T: Clone>(winner: Option<MergeWinner>, target: &T, source: &T) -> T {
+    match winner {
+        Some(MergeWinner::Source) => source.clone(),
+        _ => target.clone(),
+    }
+}
+
+fn pick_optional<T: Clone>(
+    winner: Option<MergeWinner>,
+    target: &Option<T>,
+    source: &Option<T>,
+) -> Option<T> {
+    match winner {
+        Some(MergeWinner::Target) => target.clone(),
+        Some(MergeWinner::Source) => source.clone(),
+        None => target.clone().or_else(|| source.clone()),
+    }
+}
+
+/// Custom field values from both sides, the target's winning on overlap.
+fn merge_custom_fields(target: &Value, source: &Value) -> Value {
+    let mut merged = source.as_object().cloned().unwrap_or_default();
+    if let Some(values) = target.as_object() {
+        merged.extend(values.clone());
+    }
+    Value::Object(merged)
+}
+
+/// Apply the chosen winners to the target, returning the merged model.
+fn merge_startup_fields(
+    target: &startup::Model,
+    source: &startup::Model,
+    fields: &StartupMergeFields,
+) -> startup::Model {
+    let last_contact_date = match fields.last_contact_date {
+        Some(winner) => pick(
+            Some(winner),
+            &target.last_contact_date,
+            &source.last_contact_date,
+        ),
+        None => target.last_contact_date.max(source.last_contact_date),
+    };
+    let admin_claimed = match fields.admin_claimed {
+        Some(winner) => pick(Some(winner), &target.admin_claimed, &source.admin_claimed),
+        None => target.admin_claimed || source.admin_claimed,
+    };
+    // Who claimed it and when travel with the claimed side.
+    let claimed_from = if !admin_claimed {
+        None
+    } else if source.admin_claimed
+        && (fields.admin_claimed == Some(MergeWinner::Source) || !target.admin_claimed)
+    {
+        Some(source)
+    } else {
+        Some(target)
+    };
+
+    startup::Model {
+        name: pick(fields.name, &target.name, &source.name),
+        category: pick_optional(fields.category, &target.category, &source.category),
+        website: pick_optional(fields.website, &target.website, &source.website),
+        newsroom_url: pick_optional(
+            fields.newsroom_url,
+            &target.newsroom_url,
+            &source.newsroom_url,
+        ),
+        status: pick(fields.status, &target.status, &source.status),
+        last_contact_date,
+        next_step: pick_optional(fields.next_step, &target.next_step, &source.next_step),
+        admin_claimed,
+        claimed_at: claimed_from.and_then(|side| side.claimed_at),
+        claimed_by_contact_id: claimed_from.and_then(|side| side.claimed_by_contact_id),
+        owner_id: pick_optional(fields.owner_id, &target.owner_id, &source.owner_id),
+        custom_fields: merge_custom_fields(&target.custom_fields, &source.custom_fields),
+        created_at: target.created_at.min(source.created_at),
+        ..target.clone()
+    }
+}
+
+/// POST /api/admin/startups/:id/merge
+/// Merge `source_id` into the startup at `:id`. Related records are moved to
+/// the surviving startup and the source row is deleted, all in one
+/// transaction. With `dry_run` the transaction is rolled back and the
+/// response shows what would happen.
+pub async fn merge_startups(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(target_id): Path<Uuid>,
+    Json(payload): Json<StartupMergeRequest>,
+) -> Result<Json<StartupMergeResponse>, StatusCode> {
+    if payload.source_id == target_id {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let target = find_active_startup(&txn, target_id).await?;
+    // The duplicate may already be in the trash; it can still be merged.
+    let source = startup::Entity::find_by_id(payload.source_id)
+        .one(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let merged = merge_startup_fields(&target, &source, &payload.fields);
+    let reason = payload
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    // Taking the source's status is a stage move like any other.
+    let direction = if merged.status != target.status {
+        let stage = PipelineStage::parse(&merged.status).ok_or(StatusCode::BAD_REQUEST)?;
+        Some(check_stage_transition(
+            &state.pipeline,
+            &target.status,
+            stage,
+            reason.as_deref(),
+        )?)
+    } else {
+        None
+    };
+    let db_err = |err: sea_orm::DbErr| {
+        error!(error = ?err, "failed to merge startups");
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+
+    let reparented = ReparentedCounts {
+        contacts: contact::Entity::update_many()
+            .col_expr(contact::Column::StartupId, Expr::value(target.id))
+            .filter(contact::Column::StartupId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        outreach_logs: outreach_log::Entity::update_many()
+            .col_expr(outreach_log::Column::StartupId, Expr::value(target.id))
+            .filter(outreach_log::Column::StartupId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        interviews: interview::Entity::update_many()
+            .col_expr(interview::Column::StartupId, Expr::value(target.id))
+            .filter(interview::Column::StartupId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        conversations: conversation::Entity::update_many()
+            .col_expr(conversation::Column::StartupId, Expr::value(target.id))
+            .filter(conversation::Column::StartupId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        // Events keep the startup name they were recorded with.
+        activity_events: activity_event::Entity::update_many()
+            .col_expr(activity_event::Column::StartupId, Expr::value(target.id))
+            .filter(activity_event::Column::StartupId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        tags: move_tag_assignments(&txn, TaggableEntity::Startup, source.id, target.id)
+            .await
+            .map_err(db_err)?,
+        tasks: task::Entity::update_many()
+            .col_expr(task::Column::StartupId, Expr::value(target.id))
+            .filter(task::Column::StartupId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        newsroom_claims: newsroom_claim::Entity::update_many()
+            .col_expr(newsroom_claim::Column::StartupId, Expr::value(target.id))
+            .filter(newsroom_claim::Column::StartupId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        intro_requests: intro_request::Entity::update_many()
+            .col_expr(intro_request::Column::StartupId, Expr::value(target.id))
+            .filter(intro_request::Column::StartupId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+    };
+
+    // Contact history follows the contacts; the duplicate's own history is
+    // removed with it.
+    field_revision::Entity::update_many()
+        .col_expr(field_revision::Column::StartupId, Expr::value(target.id))
+        .filter(field_revision::Column::StartupId.eq(source.id))
+        .filter(field_revision::Column::EntityType.eq(ENTITY_CONTACT))
+        .exec(&txn)
+        .await
+        .map_err(db_err)?;
+
+    startup::Entity::delete_by_id(source.id)
+        .exec(&txn)
+        .await
+        .map_err(db_err)?;
+
+    let mut active: startup::ActiveModel = target.clone().into();
+    active.name = Set(merged.name.clone());
+    active.category = Set(merged.category.clone());
+    active.website = Set(merged.website.clone());
+    active.newsroom_url = Set(merged.newsroom_url.clone());
+    active.status = Set(merged.status.clone());
+    active.last_contact_date = Set(merged.last_contact_date);
+    active.next_step = Set(merged.next_step.clone());
+    active.admin_claimed = Set(merged.admin_claimed);
+    active.claimed_at = Set(merged.claimed_at);
+    active.claimed_by_contact_id = Set(merged.claimed_by_contact_id);
+    active.owner_id = Set(merged.owner_id);
+    active.custom_fields = Set(merged.custom_fields.clone());
+    active.created_at = Set(merged.created_at);
+    active.updated_at = Set(Utc::now().naive_utc());
+    let mut result = active.update(&txn).await.map_err(db_err)?;
+    record_startup_revisions(&txn, &target, &result, Some(&admin))
+        .await
+        .map_err(db_err)?;
+    if let Some(rescored) = rescore_startup(&txn, result.id).await.map_err(db_err)? {
+        result = rescored;
+    }
+    if let Some(direction) = direction {
+        record_activity_event(
+            &txn,
+            stage_move_event(&admin, &result, target.status.clone(), direction, reason),
+        )
+        .await
+        .map_err(db_err)?;
+    }
+
+    record_activity_event(
+        &txn,
+        ActivityEventInput {
+            activity_type: ACTIVITY_STARTUP_MERGED,
+            description: format!("Merged {} into {}", source.name, result.name),
+            user_id: Some(admin.id),
+            user_name: Some(user_display_name(&admin)),
+            startup_id: Some(result.id),
+            startup_name: Some(result.name.clone()),
+            contact_id: None,
+            contact_name: None,
+            stage_from: None,
+            stage_to: None,
+            metadata: Some(json!({
+                "merged_startup": source,
+                "previous_target": target,
+                "fields": payload.fields,
+                "reparented": reparented,
+            })),
+            occurred_at: None,
+        },
+    )
+    .await
+    .map_err(db_err)?;
+
+    if payload.dry_run {
+        txn.rollback()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    } else {
+        txn.commit()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
+    Ok(Json(StartupMergeResponse {
+        dry_run: payload.dry_run,
+        startup: result,
+        merged_startup_id: source.id,
+        reparented,
+    }))
+}
+
+/// Why two or more contacts were flagged as the same person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateReason {
+    Email,
+    Phone,
+    Name,
+}
+
+#[derive(Deserialize, Default)]
+pub struct ContactDuplicatesQuery {
+    pub startup_id: Option<Uuid>,
+    pub reason: Option<DuplicateReason>,
+}
+
+#[derive(Serialize)]
+pub struct DuplicateContact {
+    #[serde(flatten)]
+    pub contact: contact::Model,
+    pub startup_name: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct DuplicateGroup {
+    pub reason: DuplicateReason,
+    /// The normalized email or phone, or the first contact's name.
+    pub value: String,
+    pub contacts: Vec<DuplicateContact>,
+}
+
+/// Lowercase name words with punctuation removed, sorted so "Smith, Jane"
+/// and "jane smith" compare equal.
+fn name_tokens(name: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = name
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect();
+    tokens.sort();
+    tokens
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(current).min(row[j])
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+/// Same words in any order, or a typo or two apart in longer names.
+pub fn names_match(a: &str, b: &str) -> bool {
+    let (a, b) = (name_tokens(a), name_tokens(b));
+    if a.is_empty() || b.is_empty() {
+        return false;
+    }
+    if a == b {
+        return true;
+    }
+    let (a, b) = (a.join(" "), b.join(" "));
+    // One typo from 8 characters, two from 16; short names must match exactly.
+    let allowed = (a.len().min(b.len()) / 8).min(2);
+    allowed > 0 && edit_distance(&a, &b) <= allowed
+}
+
+/// Indexes of contacts sharing an email or phone (across startups) or with
+/// matching names within one startup. Name matches are reported per pair
+/// since they are not transitive.
+pub fn find_duplicate_groups(
+    contacts: &[contact::Model],
+) -> Vec<(DuplicateReason, String, Vec<usize>)> {
+    let mut groups = Vec::new();
+
+    let mut by_email: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    let mut by_phone: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (index, contact) in contacts.iter().enumerate() {
+        if let Some(email) = contact.email.as_deref().and_then(normalize_email) {
+            by_email.entry(email).or_default().push(index);
+        }
+        if let Some(phone) = contact.phone.as_deref().and_then(normalize_phone) {
+            by_phone.entry(phone).or_default().push(index);
+        }
+    }
+    for (reason, by_key) in [
+        (DuplicateReason::Email, by_email),
+        (DuplicateReason::Phone, by_phone),
+    ] {
+        groups.extend(
+            by_key
+                .into_iter()
+                .filter(|(_, indexes)| indexes.len() > 1)
+                .map(|(value, indexes)| (reason, value, indexes)),
+        );
+    }
+
+    let mut by_startup: BTreeMap<Uuid, Vec<usize>> = BTreeMap::new();
+    for (index, contact) in contacts.iter().enumerate() {
+        by_startup
+            .entry(contact.startup_id)
+            .or_default()
+            .push(index);
+    }
+    for indexes in by_startup.values() {
+        for (position, &first) in indexes.iter().enumerate() {
+            for &second in &indexes[position + 1..] {
+                if names_match(&contacts[first].name, &contacts[second].name) {
+                    groups.push((
+                        DuplicateReason::Name,
+                        contacts[first].name.clone(),
+                        vec![first, second],
+                    ));
+                }
+            }
+        }
+    }
+
+    groups
+}
+
+/// GET /api/admin/contacts/duplicates
+/// Groups of active contacts that look like the same person, for review
+/// before merging. `startup_id` limits the check to contacts that have at
+/// least one duplicate involving that startup.
+pub async fn list_contact_duplicates(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    params: Option<Query<ContactDuplicatesQuery>>,
+) -> Result<Json<Vec<DuplicateGroup>>, StatusCode> {
+    let Query(params) = params.unwrap_or_default();
+
+    let contacts = contact::Entity::find()
+        .filter(contact::Column::IsTrashed.eq(false))
+        .order_by_asc(contact::Column::Name)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let startup_names: HashMap<Uuid, String> = startup::Entity::find()
+        .filter(startup::Column::IsTrashed.eq(false))
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .into_iter()
+        .map(|model| (model.id, model.name))
+        .collect();
+    // Contacts of trashed startups are hidden with them.
+    let contacts: Vec<contact::Model> = contacts
+        .into_iter()
+        .filter(|model| startup_names.contains_key(&model.startup_id))
+        .collect();
+
+    let groups = find_duplicate_groups(&contacts)
+        .into_iter()
+        .filter(|(reason, _, _)| params.reason.is_none_or(|wanted| wanted == *reason))
+        .filter(|(_, _, indexes)| {
+            params.startup_id.is_none_or(|startup_id| {
+                indexes
+                    .iter()
+                    .any(|&index| contacts[index].startup_id == startup_id)
+            })
+        })
+        .map(|(reason, value, indexes)| DuplicateGroup {
+            reason,
+            value,
+            contacts: indexes
+                .into_iter()
+                .map(|index| DuplicateContact {
+                    contact: contacts[index].clone(),
+                    startup_name: startup_names.get(&contacts[index].startup_id).cloned(),
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(Json(groups))
+}
+
+/// Per-field winners for a contact merge, with the same defaults as
+/// startups: the target's value unless it has none. Notes from both sides
+/// are kept unless a side is chosen.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContactMergeFields {
+    pub name: Option<MergeWinner>,
+    pub role: Option<MergeWinner>,
+    pub email: Option<MergeWinner>,
+    pub phone: Option<MergeWinner>,
+    pub linkedin_url: Option<MergeWinner>,
+    pub notes: Option<MergeWinner>,
+    pub owner_id: Option<MergeWinner>,
+}
+
+#[derive(Deserialize)]
+pub struct ContactMergeRequest {
+    pub source_id: Uuid,
+    #[serde(default)]
+    pub fields: ContactMergeFields,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Serialize, Default)]
+pub struct ContactReparentedCounts {
+    pub outreach_logs: u64,
+    pub interviews: u64,
+    pub activity_events: u64,
+    pub tasks: u64,
+    pub newsroom_claims: u64,
+    pub intro_requests: u64,
+    pub tags: u64,
+    pub identities: u64,
+}
+
+#[derive(Serialize)]
+pub struct ContactMergeResponse {
+    pub dry_run: bool,
+    pub contact: contact::Model,
+    pub merged_contact: contact::Model,
+    pub reparented: ContactReparentedCounts,
+}
+
+fn merge_contact_fields(
+    target: &contact::Model,
+    source: &contact::Model,
+    fields: &ContactMergeFields,
+) -> contact::Model {
+    let notes = match (fields.notes, &target.notes, &source.notes) {
+        (None, Some(target_notes), Some(source_notes))
+            if target_notes.trim() != source_notes.trim() =>
+        {
+            Some(format!("{target_notes}\n\n{source_notes}"))
+        }
+        (winner, target_notes, source_notes) => pick_optional(winner, target_notes, source_notes),
+    };
+
+    contact::Model {
+        name: pick(fields.name, &target.name, &source.name),
+        role: match fields.role {
+            Some(winner) => pick(Some(winner), &target.role, &source.role),
+            None if target.role.trim().is_empty() => source.role.clone(),
+            None => target.role.clone(),
+        },
+        email: pick_optional(fields.email, &target.email, &source.email),
+        phone: pick_optional(fields.phone, &target.phone, &source.phone),
+        linkedin_url: pick_optional(
+            fields.linkedin_url,
+            &target.linkedin_url,
+            &source.linkedin_url,
+        ),
+        is_primary: target.is_primary
+            || (source.is_primary && source.startup_id == target.startup_id),
+        notes,
+        owner_id: pick_optional(fields.owner_id, &target.owner_id, &source.owner_id),
+        custom_fields: merge_custom_fields(&target.custom_fields, &source.custom_fields),
+        ..target.clone()
+    }
+}
+
+/// Moves the duplicate's addresses to the surviving contact as secondary
+/// identities, dropping any the target already has.
+async fn move_identities<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    from: Uuid,
+    to: Uuid,
+) -> Result<u64, sea_orm::DbErr> {
+    let existing: Vec<(String, String)> = contact_identity::Entity::find()
+        .filter(contact_identity::Column::ContactId.eq(to))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|identity| (identity.kind, identity.normalized_value))
+        .collect();
+
+    let mut moved = 0;
+    for identity in contact_identity::Entity::find()
+        .filter(contact_identity::Column::ContactId.eq(from))
+        .all(db)
+        .await?
+    {
+        let key = (identity.kind.clone(), identity.normalized_value.clone());
+        if existing.contains(&key) {
+            continue;
+        }
+        let mut active: contact_identity::ActiveModel = identity.into();
+        active.contact_id = Set(to);
+        active.is_primary = Set(false);
+        active.update(db).await?;
+        moved += 1;
+    }
+
+    Ok(moved)
+}
+
+/// Points intros the duplicate requested or was the target of at the
+/// surviving contact. Returns how many intros changed.
+async fn move_intro_requests<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    from: Uuid,
+    to: Uuid,
+) -> Result<u64, sea_orm::DbErr> {
+    let affected: HashSet<Uuid> = intro_request::Entity::find()
+        .filter(
+            Condition::any()
+                .add(intro_request::Column::RequesterContactId.eq(from))
+                .add(intro_request::Column::TargetContactId.eq(from)),
+        )
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|intro| intro.id)
+        .collect();
+
+    intro_request::Entity::update_many()
+        .col_expr(intro_request::Column::RequesterContactId, Expr::value(to))
+        .filter(intro_request::Column::RequesterContactId.eq(from))
+        .exec(db)
+        .await?;
+    intro_request::Entity::update_many()
+        .col_expr(intro_request::Column::TargetContactId, Expr::value(to))
+        .filter(intro_request::Column::TargetContactId.eq(from))
+        .exec(db)
+        .await?;
+
+    Ok(affected.len() as u64)
+}
+
+/// POST /api/admin/contacts/:id/merge
+/// Merge the contact `source_id` into the contact at `:id`. Outreach logs,
+/// interviews, activity events, tasks, claim links, intros, tags and
+/// identities move to the surviving contact and the duplicate is trashed, all in one
+/// transaction.
+/// The two may belong to different startups; the target keeps its own.
+pub async fn merge_contacts(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Path(target_id): Path<Uuid>,
+    Json(payload): Json<ContactMergeRequest>,
+) -> Result<Json<ContactMergeResponse>, StatusCode> {
+    if payload.source_id == target_id {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let target = contact::Entity::find_by_id(target_id)
+        .filter(contact::Column::IsTrashed.eq(false))
+        .one(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let source = contact::Entity::find_by_id(payload.source_id)
+        .one(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let startup = find_active_startup(&txn, target.startup_id).await?;
+
+    let merged = merge_contact_fields(&target, &source, &payload.fields);
+    let db_err = |err: sea_orm::DbErr| {
+        error!(error = ?err, "failed to merge contacts");
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+
+    let reparented = ContactReparentedCounts {
+        outreach_logs: outreach_log::Entity::update_many()
+            .col_expr(outreach_log::Column::ContactId, Expr::value(target.id))
+            .filter(outreach_log::Column::ContactId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        interviews: interview::Entity::update_many()
+            .col_expr(interview::Column::ContactId, Expr::value(target.id))
+            .filter(interview::Column::ContactId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        // Events keep the contact name they were recorded with.
+        activity_events: activity_event::Entity::update_many()
+            .col_expr(activity_event::Column::ContactId, Expr::value(target.id))
+            .filter(activity_event::Column::ContactId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        tasks: task::Entity::update_many()
+            .col_expr(task::Column::ContactId, Expr::value(target.id))
+            .filter(task::Column::ContactId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        newsroom_claims: newsroom_claim::Entity::update_many()
+            .col_expr(newsroom_claim::Column::ContactId, Expr::value(target.id))
+            .filter(newsroom_claim::Column::ContactId.eq(source.id))
+            .exec(&txn)
+            .await
+            .map_err(db_err)?
+            .rows_affected,
+        intro_requests: move_intro_requests(&txn, source.id, target.id)
+            .await
+            .map_err(db_err)?,
+        tags: move_tag_assignments(&txn, TaggableEntity::Contact, source.id, target.id)
+            .await
+            .map_err(db_err)?,
+        identities: move_identities(&txn, source.id, target.id)
+            .await
+            .map_err(db_err)?,
+    };
+    startup::Entity::update_many()
+        .col_expr(startup::Column::ClaimedByContactId, Expr::value(target.id))
+        .filter(startup::Column::ClaimedByContactId.eq(source.id))
+        .exec(&txn)
+        .await
+        .map_err(db_err)?;
+
+    let mut trashed: contact::ActiveModel = source.clone().into();
+    trashed.is_trashed = Set(true);
+    trashed.trashed_at = Set(Some(Utc::now().naive_utc()));
+    let trashed = trashed.update(&txn).await.map_err(db_err)?;
+
+    let mut active: contact::ActiveModel = target.clone().into();
+    active.name = Set(merged.name.clone());
+    active.role = Set(merged.role.clone());
+    active.email = Set(merged.email.clone());
+    active.phone = Set(merged.phone.clone());
+    active.linkedin_url = Set(merged.linkedin_url.clone());
+    active.is_primary = Set(merged.is_primary);
+    active.notes = Set(merged.notes.clone());
+    active.owner_id = Set(merged.owner_id);
+    active.custom_fields = Set(merged.custom_fields.clone());
+    let result = active.update(&txn).await.map_err(db_err)?;
+    sync_primary_identities(&txn, &result)
+        .await
+        .map_err(db_err)?;
+    record_contact_revisions(&txn, &target, &result, Some(&admin))
+        .await
+        .map_err(db_err)?;
+
+    record_activity_event(
+        &txn,
+        ActivityEventInput {
+            activity_type: ACTIVITY_CONTACT_MERGED,
+            description: format!("Merged contact {} into {}", source.name, result.name),
+            user_id: Some(admin.id),
+            user_name: Some(user_display_name(&admin)),
+            startup_id: Some(startup.id),
+            startup_name: Some(startup.name.clone()),
+            contact_id: Some(result.id),
+            contact_name: Some(result.name.clone()),
+            stage_from: None,
+            stage_to: None,
+            metadata: Some(json!({
+                "merged_contact": source,
+                "previous_target": target,
+                "fields": payload.fields,
+                "reparented": reparented,
+            })),
+            occurred_at: None,
+        },
+    )
+    .await
+    .map_err(db_err)?;
+
+    if payload.dry_run {
+        txn.rollback()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    } else {
+        txn.commit()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
+    Ok(Json(ContactMergeResponse {
+        dry_run: payload.dry_run,
+        contact: result,
+        merged_contact: trashed,
+        reparented,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn startup_model(name: &str) -> startup::Model {
+        let now = NaiveDate::from_ymd_opt(2025, 3, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        startup::Model {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            category: None,
+            website: None,
+            newsroom_url: None,
+            status: "Lead".to_string(),
+            last_contact_date: None,
+            next_step: None,
+            admin_claimed: false,
+            owner_id: None,
+            is_trashed: false,
+            trashed_at: None,
+            custom_fields: json!({}),
+            signal_score: 0,
+            signal_breakdown: json!([]),
+            website_broken: false,
+            newsroom_url_broken: false,
+            claimed_at: None,
+            claimed_by_contact_id: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_merge_defaults_fill_gaps() {
+        let mut target = startup_model("Paystack");
+        target.category = Some("Fintech".to_string());
+        let mut source = startup_model("Paystack Inc");
+        source.category = Some("Payments".to_string());
+        source.website = Some("paystack.com".to_string());
+        source.admin_claimed = true;
+        source.last_contact_date = Some(target.created_at);
+
+        let merged = merge_startup_fields(&target, &source, &StartupMergeFields::default());
+
+        assert_eq!(merged.id, target.id);
+        assert_eq!(merged.name, "Paystack");
+        assert_eq!(merged.category.as_deref(), Some("Fintech"));
+        assert_eq!(merged.website.as_deref(), Some("paystack.com"));
+        assert!(merged.admin_claimed);
+        assert_eq!(merged.last_contact_date, Some(target.created_at));
+    }
+
+    fn contact_model(startup_id: Uuid, name: &str) -> contact::Model {
+        contact::Model {
+            id: Uuid::new_v4(),
+            startup_id,
+            name: name.to_string(),
+            role: "CEO".to_string(),
+            email: None,
+            phone: None,
+            linkedin_url: None,
+            is_primary: false,
+            notes: None,
+            is_trashed: false,
+            trashed_at: None,
+            owner_id: None,
+            custom_fields: json!({}),
+        }
+    }
+
+    #[test]
+    fn test_find_duplicate_groups() {
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut jane = contact_model(first, "Jane Smith");
+        jane.email = Some("Jane@Acme.com ".to_string());
+        jane.phone = Some("+234 801 234 5678".to_string());
+        let mut jane_again = contact_model(second, "J. Smith");
+        jane_again.email = Some("jane@acme.com".to_string());
+        let mut smith = contact_model(first, "Smith, Jane");
+        smith.phone = Some("00234-801-234-5678".to_string());
+        let other = contact_model(second, "Jane Smyth");
+
+        let groups = find_duplicate_groups(&[jane, jane_again, smith, other]);
+
+        assert_eq!(
+            groups,
+            vec![
+                (
+                    DuplicateReason::Email,
+                    "jane@acme.com".to_string(),
+                    vec![0, 1]
+                ),
+                (
+                    DuplicateReason::Phone,
+                    "2348012345678".to_string(),
+                    vec![0, 2]
+                ),
+                (DuplicateReason::Name, "Jane Smith".to_string(), vec![0, 2]),
+            ]
+        );
+        assert!(names_match("Jonathan Smith", "Jonathon Smith"));
+        assert!(!names_match("Ann Lee", "Ann Lew"));
+    }
+
+    #[test]
+    fn test_merge_contact_fields_keeps_both_notes() {
+        let startup_id = Uuid::new_v4();
+        let mut target = contact_model(startup_id, "Jane Smith");
+        target.notes = Some("Met at demo day".to_string());
+        let mut source = contact_model(startup_id, "Jane S.");
+        source.notes = Some("Prefers email".to_string());
+        source.email = Some("jane@acme.com".to_string());
+        source.is_primary = true;
+
+        let merged = merge_contact_fields(&target, &source, &ContactMergeFields::default());
+
+        assert_eq!(merged.id, target.id);
+        assert_eq!(merged.name, "Jane Smith");
+        assert_eq!(merged.email.as_deref(), Some("jane@acme.com"));
+        assert_eq!(
+            merged.notes.as_deref(),
+            Some("Met at demo day\n\nPrefers email")
+        );
+        assert!(merged.is_primary);
+    }
+
+    #[test]
+    fn test_merge_explicit_winners() {
+        let mut target = startup_model("Paystack");
+        target.website = Some("paystack.com".to_string());
+        let mut source = startup_model("Paystack Inc");
+        source.status = "Contacted".to_string();
+
+        let fields = StartupMergeFields {
+            name: Some(MergeWinner::Source),
+            website: Some(MergeWinner::Source),
+            status: Some(MergeWinner::Source),
+            ..Default::default()
+        };
+        let merged = merge_startup_fields(&target, &source, &fields);
+
+        assert_eq!(merged.name, "Paystack Inc");
+        assert_eq!(merged.website, None);
+        assert_eq!(merged.status, "Contacted");
+    }
+}
@@ -0,0 +1,325 @@
+use crate::auth::middleware::{AdminUser, AuthUser};
+use crate::entities::{owner_rotation, startup, user};
+use crate::history_controller::record_startup_revisions;
+use crate::{
+    find_active_startup, record_activity_event, user_display_name, ActivityEventInput, AppState,
+    ACTIVITY_OWNER_CHANGED,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::env;
+use tracing::error;
+use uuid::Uuid;
+
+/// How new startups get an owner when the request does not name one.
+/// Configured with `STARTUP_OWNER_ASSIGNMENT` (`creator` or `round_robin`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OwnerAssignment {
+    Creator,
+    RoundRobin,
+}
+
+impl OwnerAssignment {
+    pub fn from_env() -> Self {
+        match env::var("STARTUP_OWNER_ASSIGNMENT")
+            .as_deref()
+            .map(str::trim)
+        {
+            Ok("round_robin") => OwnerAssignment::RoundRobin,
+            Ok("") | Ok("creator") | Err(_) => OwnerAssignment::Creator,
+            Ok(other) => {
+                tracing::warn!(
+                    value = other,
+                    "unknown STARTUP_OWNER_ASSIGNMENT, using creator"
+                );
+                OwnerAssignment::Creator
+            }
+        }
+    }
+}
+
+/// The only row of `owner_rotation`.
+const ROTATION_ID: i16 = 1;
+
+/// Hands out owners for a batch of new startups. In round-robin mode active
+/// users take turns, continuing after whoever the stored cursor says got the
+/// last automatically assigned startup. Load and [`save`](Self::save) in one
+/// transaction: loading locks the cursor so concurrent batches queue up.
+pub struct NewStartupOwners {
+    creator: Uuid,
+    rotation: Vec<Uuid>,
+    last: Option<Uuid>,
+    advanced: bool,
+}
+
+impl NewStartupOwners {
+    pub async fn load<C: ConnectionTrait>(
+        db: &C,
+        mode: OwnerAssignment,
+        creator: &user::Model,
+    ) -> Result<Self, DbErr> {
+        let mut owners = NewStartupOwners {
+            creator: creator.id,
+            rotation: Vec::new(),
+            last: None,
+            advanced: false,
+        };
+        if mode == OwnerAssignment::Creator {
+            return Ok(owners);
+        }
+
+        owners.rotation = user::Entity::find()
+            .select_only()
+            .column(user::Column::Id)
+            .filter(user::Column::IsActive.eq(true))
+            .order_by_asc(user::Column::CreatedAt)
+            .order_by_asc(user::Column::Id)
+            .into_tuple()
+            .all(db)
+            .await?;
+        owners.last = owner_rotation::Entity::find_by_id(ROTATION_ID)
+            .lock_exclusive()
+            .one(db)
+            .await?
+            .and_then(|cursor| cursor.last_owner_id);
+
+        Ok(owners)
+    }
+
+    pub fn next(&mut self) -> Uuid {
+        match next_in_rotation(&self.rotation, self.last) {
+            Some(owner) => {
+                self.last = Some(owner);
+                self.advanced = true;
+                owner
+            }
+            None => self.creator,
+        }
+    }
+
+    /// Store the cursor after the owners handed out by [`next`](Self::next).
+    pub async fn save<C: ConnectionTrait>(&self, db: &C) -> Result<(), DbErr> {
+        if !self.advanced {
+            return Ok(());
+        }
+
+        owner_rotation::Entity::insert(owner_rotation::ActiveModel {
+            id: Set(ROTATION_ID),
+            last_owner_id: Set(self.last),
+            updated_at: Set(Utc::now().naive_utc()),
+        })
+        .on_conflict(
+            OnConflict::column(owner_rotation::Column::Id)
+                .update_columns([
+                    owner_rotation::Column::LastOwnerId,
+                    owner_rotation::Column::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec(db)
+        .await?;
+        Ok(())
+    }
+}
+
+fn next_in_rotation(users: &[Uuid], last: Option<Uuid>) -> Option<Uuid> {
+    let first = *users.first()?;
+    let position = last.and_then(|last| users.iter().position(|id| *id == last));
+
+    Some(match position {
+        Some(index) => users[(index + 1) % users.len()],
+        None => first,
+    })
+}
+
+#[derive(Deserialize)]
+pub struct ReassignOwnerRequest {
+    /// `null` leaves the startup unassigned.
+    pub owner_id: Option<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct BulkReassignOwnerRequest {
+    pub startup_ids: Vec<Uuid>,
+    pub owner_id: Option<Uuid>,
+}
+
+#[derive(Serialize)]
+pub struct BulkReassignOwnerResponse {
+    pub updated: Vec<startup::Model>,
+    /// Startups that already had the requested owner.
+    pub unchanged: Vec<Uuid>,
+}
+
+/// Owners must be active users; anything else is a validation error.
+pub async fn find_assignable_owner<C: ConnectionTrait>(
+    db: &C,
+    owner_id: Uuid,
+) -> Result<user::Model, StatusCode> {
+    user::Entity::find_by_id(owner_id)
+        .one(db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .filter(|owner| owner.is_active)
+        .ok_or(StatusCode::UNPROCESSABLE_ENTITY)
+}
+
+/// PUT /api/startups/:id/owner
+/// The current owner, or anyone when the startup is unassigned, can hand it
+/// over; admins can always reassign.
+pub async fn reassign_startup_owner(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ReassignOwnerRequest>,
+) -> Result<Json<startup::Model>, StatusCode> {
+    let existing = find_active_startup(&state.db, id).await?;
+    if !user.is_admin() && existing.owner_id.is_some_and(|owner| owner != user.id) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let owner = match payload.owner_id {
+        Some(owner_id) => Some(find_assignable_owner(&state.db, owner_id).await?),
+        None => None,
+    };
+
+    let updated = set_startup_owner(&state.db, existing, owner.as_ref(), &user)
+        .await
+        .map_err(|err| {
+            error!(error = ?err, "failed to reassign startup owner");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(updated))
+}
+
+/// POST /api/admin/startups/owner
+pub async fn bulk_reassign_startup_owner(
+    State(state): State<AppState>,
+    AdminUser(admin): AdminUser,
+    Json(payload): Json<BulkReassignOwnerRequest>,
+) -> Result<Json<BulkReassignOwnerResponse>, StatusCode> {
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let owner = match payload.owner_id {
+        Some(owner_id) => Some(find_assignable_owner(&txn, owner_id).await?),
+        None => None,
+    };
+
+    let mut response = BulkReassignOwnerResponse {
+        updated: Vec::new(),
+        unchanged: Vec::new(),
+    };
+    for startup_id in payload.startup_ids {
+        let existing = find_active_startup(&txn, startup_id).await?;
+        if existing.owner_id == payload.owner_id {
+            response.unchanged.push(existing.id);
+            continue;
+        }
+        let updated = set_startup_owner(&txn, existing, owner.as_ref(), &admin)
+            .await
+            .map_err(|err| {
+                error!(error = ?err, "failed to reassign startup owner");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        response.updated.push(updated);
+    }
+
+    txn.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(response))
+}
+
+/// Store the new owner and log an `owner_changed` event when it differs.
+pub async fn set_startup_owner<C: ConnectionTrait>(
+    db: &C,
+    existing: startup::Model,
+    owner: Option<&user::Model>,
+    actor: &user::Model,
+) -> Result<startup::Model, DbErr> {
+    let new_owner_id = owner.map(|owner| owner.id);
+    if existing.owner_id == new_owner_id {
+        return Ok(existing);
+    }
+
+    let previous_owner = match existing.owner_id {
+        Some(previous_id) => user::Entity::find_by_id(previous_id).one(db).await?,
+        None => None,
+    };
+    let previous_name = previous_owner.as_ref().map(user_display_name);
+    let new_name = owner.map(user_display_name);
+
+    let before = existing.clone();
+    let mut active: startup::ActiveModel = existing.into();
+    active.owner_id = Set(new_owner_id);
+    active.updated_at = Set(Utc::now().naive_utc());
+    let updated = active.update(db).await?;
+    record_startup_revisions(db, &before, &updated, Some(actor)).await?;
+
+    let description = match &new_name {
+        Some(name) => format!("Assigned {} to {}", updated.name, name),
+        None => format!("Unassigned {}", updated.name),
+    };
+    record_activity_event(
+        db,
+        ActivityEventInput {
+            activity_type: ACTIVITY_OWNER_CHANGED,
+            description,
+            user_id: Some(actor.id),
+            user_name: Some(user_display_name(actor)),
+            startup_id: Some(updated.id),
+            startup_name: Some(updated.name.clone()),
+            contact_id: None,
+            contact_name: None,
+            stage_from: None,
+            stage_to: None,
+            metadata: Some(json!({
+                "from_owner_id": previous_owner.as_ref().map(|owner| owner.id),
+                "from_owner_name": previous_name,
+                "to_owner_id": new_owner_id,
+                "to_owner_name": new_name,
+            })),
+            occurred_at: None,
+        },
+    )
+    .await?;
+
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation_continues_after_last_owner() {
+        let users = vec![Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+
+        assert_eq!(next_in_rotation(&users, None), Some(users[0]));
+        assert_eq!(next_in_rotation(&users, Some(users[0])), Some(users[1]));
+        assert_eq!(next_in_rotation(&users, Some(users[2])), Some(users[0]));
+        // A deactivated previous owner restarts the rotation.
+        assert_eq!(
+            next_in_rotation(&users, Some(Uuid::new_v4())),
+            Some(users[0])
+        );
+        assert_eq!(next_in_rotation(&[], Some(users[0])), None);
+    }
+}
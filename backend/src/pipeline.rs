@@ -0,0 +1,358 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, env, fmt, str::FromStr, sync::Arc};
+
+/// Stages a startup moves through during validation, in pipeline order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PipelineStage {
+    #[serde(rename = "Lead")]
+    Lead,
+    #[serde(rename = "Contacted")]
+    Contacted,
+    #[serde(rename = "Intro Secured")]
+    IntroSecured,
+    #[serde(rename = "Call Booked")]
+    CallBooked,
+    #[serde(rename = "Meeting Scheduled")]
+    MeetingScheduled,
+    #[serde(rename = "Interview Done")]
+    InterviewDone,
+    #[serde(rename = "In Discussion")]
+    InDiscussion,
+    #[serde(rename = "Activation Candidate")]
+    ActivationCandidate,
+    #[serde(rename = "Closed Won")]
+    ClosedWon,
+    #[serde(rename = "Closed Lost")]
+    ClosedLost,
+    #[serde(rename = "Not a Fit")]
+    NotAFit,
+}
+
+pub const ALL_STAGES: [PipelineStage; 11] = [
+    PipelineStage::Lead,
+    PipelineStage::Contacted,
+    PipelineStage::IntroSecured,
+    PipelineStage::CallBooked,
+    PipelineStage::MeetingScheduled,
+    PipelineStage::InterviewDone,
+    PipelineStage::InDiscussion,
+    PipelineStage::ActivationCandidate,
+    PipelineStage::ClosedWon,
+    PipelineStage::ClosedLost,
+    PipelineStage::NotAFit,
+];
+
+impl PipelineStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PipelineStage::Lead => "Lead",
+            PipelineStage::Contacted => "Contacted",
+            PipelineStage::IntroSecured => "Intro Secured",
+            PipelineStage::CallBooked => "Call Booked",
+            PipelineStage::MeetingScheduled => "Meeting Scheduled",
+            PipelineStage::InterviewDone => "Interview Done",
+            PipelineStage::InDiscussion => "In Discussion",
+            PipelineStage::ActivationCandidate => "Activation Candidate",
+            PipelineStage::ClosedWon => "Closed Won",
+            PipelineStage::ClosedLost => "Closed Lost",
+            PipelineStage::NotAFit => "Not a Fit",
+        }
+    }
+
+    /// Zero-based position of the stage in the pipeline.
+    pub fn position(&self) -> usize {
+        ALL_STAGES
+            .iter()
+            .position(|stage| stage == self)
+            .unwrap_or_default()
+    }
+
+    /// Parse a stage name, ignoring case and surrounding whitespace.
+    pub fn parse(value: &str) -> Option<Self> {
+        let trimmed = value.trim();
+        ALL_STAGES
+            .iter()
+            .copied()
+            .find(|stage| stage.as_str().eq_ignore_ascii_case(trimmed))
+    }
+}
+
+impl fmt::Display for PipelineStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownStage(pub String);
+
+impl fmt::Display for UnknownStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown pipeline stage: {}", self.0)
+    }
+}
+
+impl FromStr for PipelineStage {
+    type Err = UnknownStage;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        PipelineStage::parse(value).ok_or_else(|| UnknownStage(value.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionDirection {
+    Forward,
+    Backward,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransitionError {
+    Unchanged,
+    NotAllowed {
+        from: PipelineStage,
+        to: PipelineStage,
+    },
+    ReasonRequired,
+}
+
+impl fmt::Display for TransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransitionError::Unchanged => f.write_str("startup is already in that stage"),
+            TransitionError::NotAllowed { from, to } => {
+                write!(f, "cannot move from {} to {}", from, to)
+            }
+            TransitionError::ReasonRequired => {
+                f.write_str("a reason is required for backward or Not a Fit moves")
+            }
+        }
+    }
+}
+
+/// Allowed stage-to-stage moves. Defaults can be overridden per stage with
+/// the `PIPELINE_TRANSITIONS` environment variable, a JSON object mapping a
+/// stage name to the list of stages it may move to.
+#[derive(Clone, Debug)]
+pub struct TransitionGraph {
+    edges: Arc<HashMap<PipelineStage, Vec<PipelineStage>>>,
+}
+
+impl Default for TransitionGraph {
+    fn default() -> Self {
+        use PipelineStage::*;
+
+        let edges = HashMap::from([
+            (Lead, vec![Contacted, NotAFit]),
+            (
+                Contacted,
+                vec![Lead, IntroSecured, CallBooked, ClosedLost, NotAFit],
+            ),
+            (
+                IntroSecured,
+                vec![Contacted, CallBooked, MeetingScheduled, ClosedLost, NotAFit],
+            ),
+            (
+                CallBooked,
+                vec![
+                    Contacted,
+                    IntroSecured,
+                    MeetingScheduled,
+                    InterviewDone,
+                    ClosedLost,
+                    NotAFit,
+                ],
+            ),
+            (
+                MeetingScheduled,
+                vec![CallBooked, InterviewDone, ClosedLost, NotAFit],
+            ),
+            (
+                InterviewDone,
+                vec![
+                    MeetingScheduled,
+                    InDiscussion,
+                    ActivationCandidate,
+                    ClosedLost,
+                    NotAFit,
+                ],
+            ),
+            (
+                InDiscussion,
+                vec![
+                    InterviewDone,
+                    ActivationCandidate,
+                    ClosedWon,
+                    ClosedLost,
+                    NotAFit,
+                ],
+            ),
+            (
+                ActivationCandidate,
+                vec![InDiscussion, ClosedWon, ClosedLost, NotAFit],
+            ),
+            (ClosedWon, vec![ActivationCandidate]),
+            (ClosedLost, vec![Lead, InDiscussion]),
+            (NotAFit, vec![Lead]),
+        ]);
+
+        Self {
+            edges: Arc::new(edges),
+        }
+    }
+}
+
+impl TransitionGraph {
+    pub fn from_env() -> Self {
+        match env::var("PIPELINE_TRANSITIONS") {
+            Ok(raw) if !raw.trim().is_empty() => Self::from_json(&raw).unwrap_or_else(|err| {
+                tracing::warn!(error = %err, "invalid PIPELINE_TRANSITIONS, using defaults");
+                Self::default()
+            }),
+            _ => Self::default(),
+        }
+    }
+
+    /// Build a graph from a JSON override. Stages missing from the override
+    /// keep their default transitions.
+    pub fn from_json(raw: &str) -> Result<Self, String> {
+        let overrides: HashMap<String, Vec<String>> =
+            serde_json::from_str(raw).map_err(|e| e.to_string())?;
+
+        let mut edges = (*Self::default().edges).clone();
+        for (from, targets) in overrides {
+            let from = from.parse::<PipelineStage>().map_err(|e| e.to_string())?;
+            let targets = targets
+                .iter()
+                .map(|target| target.parse::<PipelineStage>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| e.to_string())?;
+            edges.insert(from, targets);
+        }
+
+        Ok(Self {
+            edges: Arc::new(edges),
+        })
+    }
+
+    pub fn allowed_targets(&self, from: PipelineStage) -> &[PipelineStage] {
+        self.edges.get(&from).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn is_allowed(&self, from: PipelineStage, to: PipelineStage) -> bool {
+        self.allowed_targets(from).contains(&to)
+    }
+
+    /// Check a move against the graph. Backward moves and moves to
+    /// "Not a Fit" must carry a non-empty reason.
+    pub fn validate(
+        &self,
+        from: PipelineStage,
+        to: PipelineStage,
+        reason: Option<&str>,
+    ) -> Result<TransitionDirection, TransitionError> {
+        if from == to {
+            return Err(TransitionError::Unchanged);
+        }
+
+        if !self.is_allowed(from, to) {
+            return Err(TransitionError::NotAllowed { from, to });
+        }
+
+        let direction = if to.position() < from.position() {
+            TransitionDirection::Backward
+        } else {
+            TransitionDirection::Forward
+        };
+
+        let needs_reason =
+            direction == TransitionDirection::Backward || to == PipelineStage::NotAFit;
+        let has_reason = reason.map(|r| !r.trim().is_empty()).unwrap_or(false);
+        if needs_reason && !has_reason {
+            return Err(TransitionError::ReasonRequired);
+        }
+
+        Ok(direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_parsing() {
+        for stage in ALL_STAGES {
+            assert_eq!(PipelineStage::parse(stage.as_str()), Some(stage));
+        }
+        assert_eq!(
+            PipelineStage::parse("  call booked "),
+            Some(PipelineStage::CallBooked)
+        );
+        assert!(PipelineStage::parse("Call Boked").is_none());
+    }
+
+    #[test]
+    fn test_default_transitions() {
+        let graph = TransitionGraph::default();
+
+        assert_eq!(
+            graph.validate(PipelineStage::Lead, PipelineStage::Contacted, None),
+            Ok(TransitionDirection::Forward)
+        );
+        assert_eq!(
+            graph.validate(
+                PipelineStage::Lead,
+                PipelineStage::ActivationCandidate,
+                None
+            ),
+            Err(TransitionError::NotAllowed {
+                from: PipelineStage::Lead,
+                to: PipelineStage::ActivationCandidate,
+            })
+        );
+        assert_eq!(
+            graph.validate(PipelineStage::Lead, PipelineStage::Lead, None),
+            Err(TransitionError::Unchanged)
+        );
+    }
+
+    #[test]
+    fn test_reason_required() {
+        let graph = TransitionGraph::default();
+
+        assert_eq!(
+            graph.validate(PipelineStage::Contacted, PipelineStage::Lead, None),
+            Err(TransitionError::ReasonRequired)
+        );
+        assert_eq!(
+            graph.validate(PipelineStage::Contacted, PipelineStage::Lead, Some("  ")),
+            Err(TransitionError::ReasonRequired)
+        );
+        assert_eq!(
+            graph.validate(
+                PipelineStage::Contacted,
+                PipelineStage::Lead,
+                Some("Bounced")
+            ),
+            Ok(TransitionDirection::Backward)
+        );
+        assert_eq!(
+            graph.validate(PipelineStage::Lead, PipelineStage::NotAFit, None),
+            Err(TransitionError::ReasonRequired)
+        );
+    }
+
+    #[test]
+    fn test_json_override() {
+        let graph =
+            TransitionGraph::from_json(r#"{"Lead": ["Contacted", "Activation Candidate"]}"#)
+                .unwrap();
+
+        assert!(graph.is_allowed(PipelineStage::Lead, PipelineStage::ActivationCandidate));
+        assert!(!graph.is_allowed(PipelineStage::Lead, PipelineStage::NotAFit));
+        assert!(graph.is_allowed(PipelineStage::Contacted, PipelineStage::CallBooked));
+        assert!(TransitionGraph::from_json(r#"{"Leed": []}"#).is_err());
+    }
+}
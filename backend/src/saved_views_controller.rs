@@ -0,0 +1,539 @@
+use crate::auth::middleware::AuthUser;
+use crate::conversations_controller::{list_conversations, ConversationListQuery};
+use crate::entities::{saved_view, user};
+use crate::{
+    list_activity_feed, list_contacts, list_startups, non_empty, user_display_name,
+    ActivityFeedQuery, AppState, ContactListQuery, StartupListQuery,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// The list endpoints a view can be saved for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewEntity {
+    Startup,
+    Contact,
+    Conversation,
+    Activity,
+}
+
+impl ViewEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ViewEntity::Startup => "startup",
+            ViewEntity::Contact => "contact",
+            ViewEntity::Conversation => "conversation",
+            ViewEntity::Activity => "activity",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "startup" => Some(ViewEntity::Startup),
+            "contact" => Some(ViewEntity::Contact),
+            "conversation" => Some(ViewEntity::Conversation),
+            "activity" => Some(ViewEntity::Activity),
+            _ => None,
+        }
+    }
+
+    /// Parameters of this entity's list query. The query structs ignore
+    /// unknown keys, so a misspelt filter would otherwise be stored and then
+    /// silently match everything.
+    fn filter_keys(&self) -> &'static [&'static str] {
+        match self {
+            ViewEntity::Startup => &[
+                "page",
+                "page_size",
+                "search",
+                "status",
+                "category",
+                "next_step",
+                "admin_claimed",
+                "broken_links",
+                "owner_id",
+                "mine",
+                "last_contact_from",
+                "last_contact_to",
+                "sort_by",
+                "sort_order",
+                "trashed",
+                "custom_fields",
+                "tags",
+            ],
+            ViewEntity::Contact => &["trashed", "custom_fields", "tags"],
+            ViewEntity::Conversation => &[
+                "show_all",
+                "startup_id",
+                "unread_only",
+                "has_attachments",
+                "search",
+                "participant",
+                "contact_id",
+                "page",
+                "page_size",
+                "archived",
+                "tags",
+                "startup_status",
+            ],
+            ViewEntity::Activity => &[
+                "page",
+                "page_size",
+                "search",
+                "start_date",
+                "end_date",
+                "startup_id",
+                "contact_id",
+                "user_id",
+                "stage",
+                "activity_type",
+            ],
+        }
+    }
+
+    /// Whether the filters parse as this entity's list query.
+    fn accepts(&self, query: &str) -> bool {
+        fn parses<T: DeserializeOwned>(query: &str) -> bool {
+            serde_urlencoded::from_str::<T>(query).is_ok()
+        }
+
+        match self {
+            ViewEntity::Startup => parses::<StartupListQuery>(query),
+            ViewEntity::Contact => parses::<ContactListQuery>(query),
+            ViewEntity::Conversation => parses::<ConversationListQuery>(query),
+            ViewEntity::Activity => parses::<ActivityFeedQuery>(query),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct SavedViewListQuery {
+    pub entity_type: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateSavedViewRequest {
+    pub name: String,
+    pub entity_type: String,
+    /// Query parameters of the list endpoint, e.g.
+    /// `{ "status": ["Call Booked"], "category": "Fintech", "mine": true }`.
+    #[serde(default)]
+    pub filters: Map<String, Value>,
+    #[serde(default)]
+    pub is_shared: bool,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateSavedViewRequest {
+    pub name: Option<String>,
+    /// Replaces the stored filters.
+    pub filters: Option<Map<String, Value>>,
+    pub is_shared: Option<bool>,
+}
+
+#[derive(Serialize)]
+pub struct SavedViewResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub owner_name: Option<String>,
+    pub name: String,
+    pub entity_type: String,
+    pub filters: Value,
+    pub is_shared: bool,
+    /// Whether the caller can edit or delete the view.
+    pub can_edit: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+fn view_response(
+    view: saved_view::Model,
+    owner: Option<&user::Model>,
+    caller: &user::Model,
+) -> SavedViewResponse {
+    SavedViewResponse {
+        can_edit: can_edit_view(caller, &view),
+        id: view.id,
+        user_id: view.user_id,
+        owner_name: owner.map(user_display_name),
+        name: view.name,
+        entity_type: view.entity_type,
+        filters: view.filters,
+        is_shared: view.is_shared,
+        created_at: view.created_at,
+        updated_at: view.updated_at,
+    }
+}
+
+/// Flatten filter values to the strings a query string would carry: lists
+/// become comma-separated (as `status` and `tags` expect) and empty values
+/// are dropped. Nested objects are rejected.
+pub fn normalize_filters(filters: &Map<String, Value>) -> Option<BTreeMap<String, String>> {
+    fn scalar(value: &Value) -> Option<Option<String>> {
+        match value {
+            Value::Null => Some(None),
+            Value::Bool(flag) => Some(Some(flag.to_string())),
+            Value::Number(number) => Some(Some(number.to_string())),
+            Value::String(text) => Some(non_empty(Some(text)).map(str::to_string)),
+            Value::Array(_) | Value::Object(_) => None,
+        }
+    }
+
+    let mut normalized = BTreeMap::new();
+    for (key, value) in filters {
+        let key = key.trim();
+        if key.is_empty() {
+            return None;
+        }
+        let value = match value {
+            Value::Array(items) => {
+                let parts = items
+                    .iter()
+                    .map(scalar)
+                    .collect::<Option<Vec<_>>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                (!parts.is_empty()).then(|| parts.join(","))
+            }
+            other => scalar(other)?,
+        };
+        if let Some(value) = value {
+            normalized.insert(key.to_string(), value);
+        }
+    }
+
+    Some(normalized)
+}
+
+fn stored_filters(view: &saved_view::Model) -> BTreeMap<String, String> {
+    serde_json::from_value(view.filters.clone()).unwrap_or_default()
+}
+
+/// Normalize the filters and check them against the entity's list query.
+fn validated_filters(
+    entity: ViewEntity,
+    filters: &Map<String, Value>,
+) -> Result<Value, StatusCode> {
+    let normalized = normalize_filters(filters).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    let keys = entity.filter_keys();
+    if !normalized.keys().all(|key| keys.contains(&key.as_str())) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let query =
+        serde_urlencoded::to_string(&normalized).map_err(|_| StatusCode::UNPROCESSABLE_ENTITY)?;
+    if !entity.accepts(&query) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    serde_json::to_value(normalized).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+fn can_edit_view(user: &user::Model, view: &saved_view::Model) -> bool {
+    user.is_admin() || view.user_id == user.id
+}
+
+/// The caller's own views and views others have shared.
+fn visible_to(user: &user::Model) -> Condition {
+    Condition::any()
+        .add(saved_view::Column::UserId.eq(user.id))
+        .add(saved_view::Column::IsShared.eq(true))
+}
+
+async fn find_visible_view(
+    state: &AppState,
+    user: &user::Model,
+    id: Uuid,
+) -> Result<saved_view::Model, StatusCode> {
+    saved_view::Entity::find_by_id(id)
+        .filter(visible_to(user))
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn name_taken(
+    state: &AppState,
+    user_id: Uuid,
+    entity_type: &str,
+    name: &str,
+    except: Option<Uuid>,
+) -> Result<bool, StatusCode> {
+    let mut query = saved_view::Entity::find()
+        .filter(saved_view::Column::UserId.eq(user_id))
+        .filter(saved_view::Column::EntityType.eq(entity_type))
+        .filter(saved_view::Column::Name.eq(name));
+    if let Some(id) = except {
+        query = query.filter(saved_view::Column::Id.ne(id));
+    }
+    let existing = query
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(existing.is_some())
+}
+
+async fn single_response(
+    state: &AppState,
+    view: saved_view::Model,
+    caller: &user::Model,
+) -> Result<SavedViewResponse, StatusCode> {
+    let owner = user::Entity::find_by_id(view.user_id)
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(view_response(view, owner.as_ref(), caller))
+}
+
+/// GET /api/views[?entity_type=]
+/// The caller's views first, then views shared by teammates, each by name.
+pub async fn list_saved_views(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    query: Option<Query<SavedViewListQuery>>,
+) -> Result<Json<Vec<SavedViewResponse>>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+
+    let mut views = saved_view::Entity::find().filter(visible_to(&user));
+    if let Some(entity_type) = non_empty(params.entity_type.as_deref()) {
+        let entity = ViewEntity::parse(entity_type).ok_or(StatusCode::BAD_REQUEST)?;
+        views = views.filter(saved_view::Column::EntityType.eq(entity.as_str()));
+    }
+    let mut views = views
+        .order_by_asc(saved_view::Column::Name)
+        .find_also_related(user::Entity)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    views.sort_by_key(|(view, _)| view.user_id != user.id);
+
+    Ok(Json(
+        views
+            .into_iter()
+            .map(|(view, owner)| view_response(view, owner.as_ref(), &user))
+            .collect(),
+    ))
+}
+
+/// GET /api/views/:id
+pub async fn get_saved_view(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<SavedViewResponse>, StatusCode> {
+    let view = find_visible_view(&state, &user, id).await?;
+    Ok(Json(single_response(&state, view, &user).await?))
+}
+
+/// POST /api/views
+pub async fn create_saved_view(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Json(payload): Json<CreateSavedViewRequest>,
+) -> Result<(StatusCode, Json<SavedViewResponse>), StatusCode> {
+    let name = non_empty(Some(&payload.name))
+        .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?
+        .to_string();
+    let entity = ViewEntity::parse(&payload.entity_type).ok_or(StatusCode::BAD_REQUEST)?;
+    let filters = validated_filters(entity, &payload.filters)?;
+    if name_taken(&state, user.id, entity.as_str(), &name, None).await? {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    let now = Utc::now().naive_utc();
+    let view = saved_view::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        user_id: Set(user.id),
+        name: Set(name),
+        entity_type: Set(entity.as_str().to_string()),
+        filters: Set(filters),
+        is_shared: Set(payload.is_shared),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(&state.db)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(view_response(view, Some(&user), &user)),
+    ))
+}
+
+/// PUT /api/views/:id
+/// Only the owner (or an admin) can change a view, shared or not.
+pub async fn update_saved_view(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateSavedViewRequest>,
+) -> Result<Json<SavedViewResponse>, StatusCode> {
+    let existing = find_visible_view(&state, &user, id).await?;
+    if !can_edit_view(&user, &existing) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let entity =
+        ViewEntity::parse(&existing.entity_type).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+
+    let mut active: saved_view::ActiveModel = existing.clone().into();
+    if let Some(name) = payload.name {
+        let name = non_empty(Some(&name))
+            .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?
+            .to_string();
+        if name_taken(
+            &state,
+            existing.user_id,
+            &existing.entity_type,
+            &name,
+            Some(existing.id),
+        )
+        .await?
+        {
+            return Err(StatusCode::CONFLICT);
+        }
+        active.name = Set(name);
+    }
+    if let Some(filters) = payload.filters {
+        active.filters = Set(validated_filters(entity, &filters)?);
+    }
+    if let Some(is_shared) = payload.is_shared {
+        active.is_shared = Set(is_shared);
+    }
+    active.updated_at = Set(Utc::now().naive_utc());
+
+    let view = active
+        .update(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(single_response(&state, view, &user).await?))
+}
+
+/// DELETE /api/views/:id
+pub async fn delete_saved_view(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let existing = find_visible_view(&state, &user, id).await?;
+    if !can_edit_view(&user, &existing) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    saved_view::Entity::delete_by_id(existing.id)
+        .exec(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/views/:id/apply[?page=&...]
+/// Run the view's list query as the caller and return what the list endpoint
+/// would. Query parameters given here (e.g. `page`) override the stored ones,
+/// and filters such as `mine=true` apply to the caller, not the view's owner.
+pub async fn apply_saved_view(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Query(overrides): Query<HashMap<String, String>>,
+) -> Result<Response, StatusCode> {
+    let view = find_visible_view(&state, &user, id).await?;
+    let entity = ViewEntity::parse(&view.entity_type).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+
+    let mut filters = stored_filters(&view);
+    filters.extend(overrides);
+    let query = serde_urlencoded::to_string(&filters).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    fn parse<T: DeserializeOwned>(query: &str) -> Result<T, StatusCode> {
+        serde_urlencoded::from_str(query).map_err(|_| StatusCode::BAD_REQUEST)
+    }
+
+    let user = AuthUser(user);
+    Ok(match entity {
+        ViewEntity::Startup => list_startups(State(state), user, Some(Query(parse(&query)?)))
+            .await?
+            .into_response(),
+        ViewEntity::Contact => list_contacts(State(state), user, Some(Query(parse(&query)?)))
+            .await?
+            .into_response(),
+        ViewEntity::Conversation => list_conversations(State(state), user, Query(parse(&query)?))
+            .await?
+            .into_response(),
+        ViewEntity::Activity => list_activity_feed(State(state), user, Some(Query(parse(&query)?)))
+            .await?
+            .into_response(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_normalize_filters_flattens_to_query_values() {
+        let filters = json!({
+            "status": ["Call Booked", "Interview Done"],
+            "category": " Fintech ",
+            "mine": true,
+            "page_size": 50,
+            "search": "",
+            "owner_id": null,
+        });
+        let normalized = normalize_filters(filters.as_object().unwrap()).unwrap();
+
+        assert_eq!(
+            normalized,
+            BTreeMap::from([
+                ("category".to_string(), "Fintech".to_string()),
+                ("mine".to_string(), "true".to_string()),
+                ("page_size".to_string(), "50".to_string()),
+                (
+                    "status".to_string(),
+                    "Call Booked,Interview Done".to_string()
+                ),
+            ])
+        );
+        assert!(
+            normalize_filters(json!({ "status": { "in": [] } }).as_object().unwrap()).is_none()
+        );
+    }
+
+    #[test]
+    fn test_filters_are_checked_against_entity_query() {
+        let ok = json!({ "unread_only": true, "startup_status": "Interview Done" });
+        assert!(validated_filters(ViewEntity::Conversation, ok.as_object().unwrap()).is_ok());
+
+        let bad = json!({ "page": "two" });
+        assert_eq!(
+            validated_filters(ViewEntity::Startup, bad.as_object().unwrap()),
+            Err(StatusCode::UNPROCESSABLE_ENTITY)
+        );
+
+        for unknown in [
+            json!({ "stage": "Call Booked" }),
+            json!({ "stauts": "Lead", "mine": true }),
+        ] {
+            assert_eq!(
+                validated_filters(ViewEntity::Startup, unknown.as_object().unwrap()),
+                Err(StatusCode::UNPROCESSABLE_ENTITY)
+            );
+        }
+        let activity = json!({ "stage": "Call Booked" });
+        assert!(validated_filters(ViewEntity::Activity, activity.as_object().unwrap()).is_ok());
+    }
+}
@@ -0,0 +1,279 @@
+use crate::auth::middleware::AuthUser;
+use crate::AppState;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDateTime;
+use sea_orm::{DbBackend, FromQueryResult, Statement, Value};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+const DEFAULT_SEARCH_LIMIT: u64 = 20;
+const MAX_SEARCH_LIMIT: u64 = 100;
+
+/// `ts_headline` wraps matches in `<mark>` and keeps a short fragment around
+/// them; the surrounding text is escaped before it is returned.
+const HEADLINE_OPTIONS: &str =
+    "StartSel=<mark>, StopSel=</mark>, MaxWords=25, MinWords=8, MaxFragments=2";
+
+/// The record types covered by the `search_vector` columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchKind {
+    Startup,
+    Contact,
+    Message,
+    Interview,
+    Insight,
+}
+
+impl SearchKind {
+    const ALL: [SearchKind; 5] = [
+        SearchKind::Startup,
+        SearchKind::Contact,
+        SearchKind::Message,
+        SearchKind::Interview,
+        SearchKind::Insight,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchKind::Startup => "startup",
+            SearchKind::Contact => "contact",
+            SearchKind::Message => "message",
+            SearchKind::Interview => "interview",
+            SearchKind::Insight => "insight",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == value.trim().to_ascii_lowercase())
+    }
+
+    /// Every query selects the same columns so rows map onto `HitRow`.
+    /// `$1` is the tsquery text, `$2` the limit; messages also take the
+    /// caller id (`$3`) and whether they may see every mailbox (`$4`).
+    fn sql(&self) -> String {
+        let query = match self {
+            SearchKind::Startup => {
+                "SELECT s.id, s.name AS title, \
+                     nullif(concat_ws(' · ', s.category, s.website), '') AS subtitle, \
+                     s.id AS startup_id, s.name AS startup_name, NULL::uuid AS parent_id, \
+                     ts_headline('english', concat_ws(' ', s.name, s.website, s.newsroom_url, s.category), q, $opts) AS highlight, \
+                     ts_rank(s.search_vector, q) AS rank, s.updated_at AS occurred_at \
+                 FROM startup s, to_tsquery('english', $1) q \
+                 WHERE s.search_vector @@ q AND NOT s.is_trashed"
+            }
+            SearchKind::Contact => {
+                "SELECT c.id, c.name AS title, nullif(concat_ws(' · ', c.role, c.email), '') AS subtitle, \
+                     s.id AS startup_id, s.name AS startup_name, NULL::uuid AS parent_id, \
+                     ts_headline('english', concat_ws(' ', c.name, c.email, c.role, c.notes), q, $opts) AS highlight, \
+                     ts_rank(c.search_vector, q) AS rank, NULL::timestamp AS occurred_at \
+                 FROM contact c JOIN startup s ON s.id = c.startup_id, to_tsquery('english', $1) q \
+                 WHERE c.search_vector @@ q AND NOT c.is_trashed AND NOT s.is_trashed"
+            }
+            SearchKind::Message => {
+                "SELECT m.id, m.subject AS title, coalesce(m.sender_name, m.sender_email) AS subtitle, \
+                     s.id AS startup_id, s.name AS startup_name, m.conversation_id AS parent_id, \
+                     ts_headline('english', concat_ws(' ', m.subject, coalesce(m.body_text, m.snippet)), q, $opts) AS highlight, \
+                     ts_rank(m.search_vector, q) AS rank, (m.sent_at AT TIME ZONE 'UTC') AS occurred_at \
+                 FROM messages m JOIN conversations cv ON cv.id = m.conversation_id \
+                     LEFT JOIN startup s ON s.id = cv.startup_id, to_tsquery('english', $1) q \
+                 WHERE m.search_vector @@ q AND (cv.user_id = $3 OR $4)"
+            }
+            SearchKind::Interview => {
+                "SELECT i.id, initcap(i.type) || ' interview' AS title, ct.name AS subtitle, \
+                     s.id AS startup_id, s.name AS startup_name, NULL::uuid AS parent_id, \
+                     ts_headline('english', coalesce(i.summary, ''), q, $opts) AS highlight, \
+                     ts_rank(i.search_vector, q) AS rank, i.date AS occurred_at \
+                 FROM interview i JOIN startup s ON s.id = i.startup_id \
+                     LEFT JOIN contact ct ON ct.id = i.contact_id, to_tsquery('english', $1) q \
+                 WHERE i.search_vector @@ q AND NOT s.is_trashed"
+            }
+            SearchKind::Insight => {
+                "SELECT ii.id, 'Interview insight' AS title, ii.interest_level AS subtitle, \
+                     s.id AS startup_id, s.name AS startup_name, ii.interview_id AS parent_id, \
+                     ts_headline('english', concat_ws(' ', ii.current_workflow, ii.jtbd_functional, \
+                         ii.jtbd_social, ii.jtbd_emotional, ii.biggest_pains::text, ii.desired_outcomes::text, \
+                         ii.main_objections::text, ii.excited_features::text, ii.ignored_features::text, \
+                         ii.real_owner_role, ii.willing_to_use_monthly), q, $opts) AS highlight, \
+                     ts_rank(ii.search_vector, q) AS rank, i.date AS occurred_at \
+                 FROM interview_insight ii JOIN interview i ON i.id = ii.interview_id \
+                     JOIN startup s ON s.id = i.startup_id, to_tsquery('english', $1) q \
+                 WHERE ii.search_vector @@ q AND NOT s.is_trashed"
+            }
+        };
+
+        format!("{query} ORDER BY rank DESC LIMIT $2")
+            .replace("$opts", &format!("'{HEADLINE_OPTIONS}'"))
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct SearchQuery {
+    pub q: Option<String>,
+    /// Comma-separated subset of `startup,contact,message,interview,insight`.
+    pub types: Option<String>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct SearchHit {
+    pub kind: &'static str,
+    pub id: Uuid,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub startup_id: Option<Uuid>,
+    pub startup_name: Option<String>,
+    /// Conversation for message hits, interview for insight hits.
+    pub parent_id: Option<Uuid>,
+    pub highlight: String,
+    pub rank: f32,
+    pub occurred_at: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize)]
+pub struct SearchResponse {
+    pub query: String,
+    pub total: usize,
+    pub results: Vec<SearchHit>,
+}
+
+#[derive(FromQueryResult)]
+struct HitRow {
+    id: Uuid,
+    title: Option<String>,
+    subtitle: Option<String>,
+    startup_id: Option<Uuid>,
+    startup_name: Option<String>,
+    parent_id: Option<Uuid>,
+    highlight: Option<String>,
+    rank: f32,
+    occurred_at: Option<NaiveDateTime>,
+}
+
+/// Builds a prefix-matching tsquery (`acme:* & robot:*`) from free text.
+/// Only alphanumeric runs are kept, so user input can never produce tsquery
+/// syntax errors.
+pub fn prefix_tsquery(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("{}:*", term.to_lowercase()))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" & "))
+    }
+}
+
+/// Escapes the headline for HTML while keeping the `<mark>` tags added by
+/// `ts_headline`.
+pub fn escape_highlight(headline: &str) -> String {
+    headline
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace("&lt;mark&gt;", "<mark>")
+        .replace("&lt;/mark&gt;", "</mark>")
+}
+
+fn parse_kinds(types: Option<&str>) -> Result<Vec<SearchKind>, StatusCode> {
+    let Some(types) = types.filter(|types| !types.trim().is_empty()) else {
+        return Ok(SearchKind::ALL.to_vec());
+    };
+
+    let mut kinds = Vec::new();
+    for value in types.split(',').filter(|value| !value.trim().is_empty()) {
+        let kind = SearchKind::parse(value).ok_or(StatusCode::BAD_REQUEST)?;
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+    }
+    Ok(kinds)
+}
+
+pub async fn search(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    params: Option<Query<SearchQuery>>,
+) -> Result<Json<SearchResponse>, StatusCode> {
+    let Query(params) = params.unwrap_or_default();
+    let query = params.q.unwrap_or_default().trim().to_string();
+    let tsquery = prefix_tsquery(&query).ok_or(StatusCode::BAD_REQUEST)?;
+    let kinds = parse_kinds(params.types.as_deref())?;
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+    let see_all_mail = user.is_admin();
+
+    let mut results = Vec::new();
+    for kind in kinds {
+        let mut values: Vec<Value> = vec![tsquery.clone().into(), (limit as i64).into()];
+        if kind == SearchKind::Message {
+            values.push(user.id.into());
+            values.push(see_all_mail.into());
+        }
+
+        let rows = HitRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            kind.sql(),
+            values,
+        ))
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        results.extend(rows.into_iter().map(|row| SearchHit {
+            kind: kind.as_str(),
+            id: row.id,
+            title: row.title.unwrap_or_default(),
+            subtitle: row.subtitle,
+            startup_id: row.startup_id,
+            startup_name: row.startup_name,
+            parent_id: row.parent_id,
+            highlight: escape_highlight(&row.highlight.unwrap_or_default()),
+            rank: row.rank,
+            occurred_at: row.occurred_at,
+        }));
+    }
+
+    results.sort_by(|a, b| b.rank.partial_cmp(&a.rank).unwrap_or(Ordering::Equal));
+    results.truncate(limit as usize);
+
+    Ok(Json(SearchResponse {
+        query,
+        total: results.len(),
+        results,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_tsquery_strips_operators() {
+        assert_eq!(
+            prefix_tsquery("Acme & (robots)!"),
+            Some("acme:* & robots:*".to_string())
+        );
+        assert_eq!(prefix_tsquery("  !:* "), None);
+    }
+
+    #[test]
+    fn test_escape_highlight_keeps_marks_only() {
+        assert_eq!(
+            escape_highlight("<b>x</b> <mark>Acme</mark> & co"),
+            "&lt;b&gt;x&lt;/b&gt; <mark>Acme</mark> &amp; co"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Permanently deletes contacts that have sat in the trash longer than the
+//! retention window. The window is 30 days unless overridden with
+//! `CONTACT_TRASH_RETENTION_DAYS`; `0` turns the purge off. Every run is
+//! recorded in `contact_purge_run`, including the contacts it removed.
+
+use crate::entities::{contact, contact_purge_run, startup};
+use crate::tags_controller::{delete_tag_assignments, TaggableEntity};
+use chrono::{Duration, NaiveDateTime, Utc};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::env;
+use uuid::Uuid;
+
+pub const PURGE_TRIGGER_SCHEDULED: &str = "scheduled";
+pub const PURGE_TRIGGER_MANUAL: &str = "manual";
+
+const DEFAULT_RETENTION_DAYS: i64 = 30;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContactRetention {
+    days: Option<i64>,
+}
+
+impl Default for ContactRetention {
+    fn default() -> Self {
+        Self {
+            days: Some(DEFAULT_RETENTION_DAYS),
+        }
+    }
+}
+
+impl ContactRetention {
+    pub fn from_env() -> Self {
+        match env::var("CONTACT_TRASH_RETENTION_DAYS") {
+            Ok(raw) if !raw.trim().is_empty() => Self::parse(&raw).unwrap_or_else(|err| {
+                tracing::warn!(error = %err, "invalid CONTACT_TRASH_RETENTION_DAYS, using default");
+                Self::default()
+            }),
+            _ => Self::default(),
+        }
+    }
+
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let days: i64 = raw
+            .trim()
+            .parse()
+            .map_err(|_| format!("expected a number of days, got {raw:?}"))?;
+        match days {
+            0 => Ok(Self { days: None }),
+            days if days > 0 => Ok(Self { days: Some(days) }),
+            days => Err(format!("retention must not be negative, got {days}")),
+        }
+    }
+
+    /// `None` when the purge is turned off.
+    pub fn days(&self) -> Option<i64> {
+        self.days
+    }
+
+    /// Contacts trashed before this are due for purging.
+    pub fn cutoff(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        self.days.map(|days| now - Duration::days(days))
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PurgeCandidate {
+    pub id: Uuid,
+    pub name: String,
+    pub startup_id: Uuid,
+    pub startup_name: Option<String>,
+    pub trashed_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PurgePreview {
+    pub enabled: bool,
+    pub retention_days: Option<i64>,
+    pub cutoff: Option<NaiveDateTime>,
+    pub total: usize,
+    pub contacts: Vec<PurgeCandidate>,
+}
+
+/// Trashed contacts trashed before `cutoff`, oldest first. With `lock`, the
+/// rows stay locked until the surrounding transaction ends so a restore
+/// cannot race the purge.
+async fn find_candidates<C: ConnectionTrait>(
+    db: &C,
+    cutoff: NaiveDateTime,
+    lock: bool,
+) -> Result<Vec<PurgeCandidate>, DbErr> {
+    let mut query = contact::Entity::find()
+        .filter(contact::Column::IsTrashed.eq(true))
+        .filter(contact::Column::TrashedAt.lt(cutoff))
+        .order_by_asc(contact::Column::TrashedAt);
+    if lock {
+        query = query.lock_exclusive();
+    }
+    let contacts = query.all(db).await?;
+
+    let startup_ids: Vec<Uuid> = contacts.iter().map(|contact| contact.startup_id).collect();
+    let startups: HashMap<Uuid, String> = startup::Entity::find()
+        .filter(startup::Column::Id.is_in(startup_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|startup| (startup.id, startup.name))
+        .collect();
+
+    Ok(contacts
+        .into_iter()
+        .filter_map(|contact| {
+            Some(PurgeCandidate {
+                trashed_at: contact.trashed_at?,
+                startup_name: startups.get(&contact.startup_id).cloned(),
+                id: contact.id,
+                name: contact.name,
+                startup_id: contact.startup_id,
+            })
+        })
+        .collect())
+}
+
+/// What a purge run right now would delete.
+pub async fn preview_contact_purge<C: ConnectionTrait>(
+    db: &C,
+    retention: ContactRetention,
+) -> Result<PurgePreview, DbErr> {
+    let cutoff = retention.cutoff(Utc::now().naive_utc());
+    let contacts = match cutoff {
+        Some(cutoff) => find_candidates(db, cutoff, false).await?,
+        None => Vec::new(),
+    };
+
+    Ok(PurgePreview {
+        enabled: cutoff.is_some(),
+        retention_days: retention.days(),
+        cutoff,
+        total: contacts.len(),
+        contacts,
+    })
+}
+
+/// Deletes every contact past the retention window and records the run.
+/// Returns `None` when the purge is turned off. A failed purge is rolled
+/// back and still recorded, with its error.
+pub async fn purge_trashed_contacts(
+    db: &DatabaseConnection,
+    retention: ContactRetention,
+    trigger: &str,
+    triggered_by_id: Option<Uuid>,
+) -> Result<Option<contact_purge_run::Model>, DbErr> {
+    let (Some(days), Some(cutoff)) = (retention.days(), retention.cutoff(Utc::now().naive_utc()))
+    else {
+        return Ok(None);
+    };
+    let started_at = Utc::now().naive_utc();
+
+    let (purged, error) = match delete_candidates(db, cutoff).await {
+        Ok(purged) => (purged, None),
+        Err(err) => {
+            tracing::error!(error = ?err, "failed to purge trashed contacts");
+            (Vec::new(), Some(err.to_string()))
+        }
+    };
+
+    let run = contact_purge_run::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        trigger: Set(trigger.to_string()),
+        triggered_by_id: Set(triggered_by_id),
+        retention_days: Set(days as i32),
+        cutoff: Set(cutoff),
+        purged_count: Set(purged.len() as i32),
+        contacts: Set(json!(purged)),
+        error: Set(error),
+        started_at: Set(started_at),
+        finished_at: Set(Utc::now().naive_utc()),
+    };
+    let run = contact_purge_run::Entity::insert(run)
+        .exec_with_returning(db)
+        .await?;
+
+    Ok(Some(run))
+}
+
+async fn delete_candidates(
+    db: &DatabaseConnection,
+    cutoff: NaiveDateTime,
+) -> Result<Vec<PurgeCandidate>, DbErr> {
+    let txn = db.begin().await?;
+    let candidates = find_candidates(&txn, cutoff, true).await?;
+    let ids: Vec<Uuid> = candidates.iter().map(|candidate| candidate.id).collect();
+
+    if !ids.is_empty() {
+        delete_tag_assignments(&txn, TaggableEntity::Contact, ids.clone()).await?;
+        contact::Entity::delete_many()
+            .filter(contact::Column::Id.is_in(ids))
+            .exec(&txn)
+            .await?;
+    }
+    txn.commit().await?;
+
+    Ok(candidates)
+}
+
+/// Past purge runs, newest first.
+pub async fn list_purge_runs<C: ConnectionTrait>(
+    db: &C,
+    limit: u64,
+) -> Result<Vec<contact_purge_run::Model>, DbErr> {
+    contact_purge_run::Entity::find()
+        .order_by_desc(contact_purge_run::Column::StartedAt)
+        .limit(limit)
+        .all(db)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_retention() {
+        let now = NaiveDate::from_ymd_opt(2025, 6, 30)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+
+        let retention = ContactRetention::parse(" 14 ").unwrap();
+        assert_eq!(retention.days(), Some(14));
+        assert_eq!(
+            retention.cutoff(now),
+            NaiveDate::from_ymd_opt(2025, 6, 16)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+        );
+        assert_eq!(ContactRetention::parse("0").unwrap().cutoff(now), None);
+        assert!(ContactRetention::parse("-3").is_err());
+        assert!(ContactRetention::parse("two weeks").is_err());
+    }
+}
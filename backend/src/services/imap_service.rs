@@ -1,5 +1,9 @@
-use crate::entities::{contact, conversation, email_attachment, email_credential, message};
+use crate::contact_identities_controller::find_contacts_by_email;
+use crate::entities::{conversation, email_attachment, email_credential, message};
 use crate::services::encryption_service::EncryptionService;
+use crate::services::intros::{detect_intros_made, normalize_addresses, IntroMessage};
+use crate::services::stale_leads::clear_stale_follow_ups;
+use crate::services::startup_activity::refresh_startup_contact_fields;
 use async_native_tls::TlsConnector;
 use chrono::{DateTime, FixedOffset, Utc};
 use futures::StreamExt;
@@ -67,15 +71,18 @@ impl ImapService {
             .encryption_service
             .decrypt(&creds.encrypted_password, &creds.nonce)?;
 
-        let result = self.perform_sync(&creds, &password).await.map_err(|err| {
-            let mut active: email_credential::ActiveModel = status.clone().into();
-            let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
-            active.last_sync_attempt_at = Set(Some(now));
-            active.last_sync_error = Set(Some(err.clone()));
-            active.sync_status = Set("error".to_string());
-            let _ = active.update(&self.db);
-            err
-        })?;
+        let result = match self.perform_sync(&creds, &password).await {
+            Ok(result) => result,
+            Err(err) => {
+                let mut active: email_credential::ActiveModel = status.clone().into();
+                let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+                active.last_sync_attempt_at = Set(Some(now));
+                active.last_sync_error = Set(Some(err.clone()));
+                active.sync_status = Set("error".to_string());
+                let _ = active.update(&self.db).await;
+                return Err(err);
+            }
+        };
 
         let mut active: email_credential::ActiveModel = status.into();
         let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
@@ -281,9 +288,9 @@ impl ImapService {
             id: Set(Uuid::new_v4()),
             conversation_id: Set(conversation_id),
             user_id: Set(creds.user_id),
-            sender_name: Set(from_addrs.get(0).and_then(|addr| addr.name.clone())),
+            sender_name: Set(from_addrs.first().and_then(|addr| addr.name.clone())),
             sender_email: Set(from_addrs
-                .get(0)
+                .first()
                 .map(|addr| addr.email.clone())
                 .unwrap_or_default()),
             subject: Set(subject),
@@ -332,14 +339,45 @@ impl ImapService {
                 .map_err(|e| e.to_string())?;
         }
 
-        self.update_conversation_state(
-            conversation_id,
-            sent_at,
-            snippet,
-            has_new_attachments,
-            direction == "received" && !is_read,
-        )
-        .await?;
+        let conversation_startup_id = self
+            .update_conversation_state(
+                conversation_id,
+                sent_at,
+                snippet,
+                has_new_attachments,
+                direction == "received" && !is_read,
+            )
+            .await?;
+
+        if let Some(startup_id) = conversation_startup_id {
+            // The message is stored; a failed refresh must not abort the sync.
+            if let Err(err) = refresh_startup_contact_fields(&self.db, startup_id).await {
+                tracing::warn!(error = ?err, "failed to refresh startup contact fields");
+            }
+            if direction == "received" {
+                if let Err(err) = clear_stale_follow_ups(&self.db, startup_id).await {
+                    tracing::warn!(error = ?err, "failed to clear stale follow-ups");
+                }
+            }
+            let from = normalize_addresses(from_addrs.iter().map(|addr| addr.email.as_str()));
+            let recipients = normalize_addresses(
+                to_addrs
+                    .iter()
+                    .chain(&cc_addrs)
+                    .map(|addr| addr.email.as_str()),
+            );
+            let intro_message = IntroMessage {
+                message_id: inserted.id,
+                conversation_id,
+                mailbox_email: creds.email.trim().to_lowercase(),
+                from: &from,
+                recipients: &recipients,
+                sent_at: sent_at.naive_utc(),
+            };
+            if let Err(err) = detect_intros_made(&self.db, startup_id, &intro_message).await {
+                tracing::warn!(error = ?err, "failed to check message for intros");
+            }
+        }
 
         Ok(())
     }
@@ -388,6 +426,7 @@ impl ImapService {
         Ok(inserted.id)
     }
 
+    /// Returns the startup the conversation belongs to, if any.
     async fn update_conversation_state(
         &self,
         conversation_id: Uuid,
@@ -395,7 +434,7 @@ impl ImapService {
         snippet: Option<String>,
         has_attachments: bool,
         increment_unread: bool,
-    ) -> Result<(), String> {
+    ) -> Result<Option<Uuid>, String> {
         let conversation = conversation::Entity::find_by_id(conversation_id)
             .one(&self.db)
             .await
@@ -416,9 +455,9 @@ impl ImapService {
         active.message_count = Set(message_count);
         active.unread_count = Set(unread_count);
         active.is_read = Set(unread_count == 0);
-        active.update(&self.db).await.map_err(|e| e.to_string())?;
+        let updated = active.update(&self.db).await.map_err(|e| e.to_string())?;
 
-        Ok(())
+        Ok(updated.startup_id)
     }
 
     async fn lookup_startup_id(
@@ -439,11 +478,13 @@ impl ImapService {
             if addr.email.eq_ignore_ascii_case(user_email) {
                 continue;
             }
-            if let Some(contact) = contact::Entity::find()
-                .filter(contact::Column::Email.eq(addr.email.clone()))
-                .one(&self.db)
+            // Any of a contact's addresses count; merged duplicates are
+            // trashed and skipped.
+            if let Some(contact) = find_contacts_by_email(&self.db, &addr.email)
                 .await
                 .map_err(|e| e.to_string())?
+                .into_iter()
+                .next()
             {
                 return Ok(Some(contact.startup_id));
             }
@@ -0,0 +1,300 @@
+//! Spots intros being made in synced mail. When a requester with an open
+//! intro emails us and copies someone new to the thread from the same
+//! startup, the intro is marked made and linked to the conversation.
+
+use crate::contact_identities_controller::{contact_emails, normalize_email, IdentityKind};
+use crate::entities::{contact, contact_identity, intro_request, message, startup};
+use crate::import_controller::normalize_domain;
+use crate::intro_requests_controller::{record_intro_made, INTRO_STATUS_MADE, OPEN_INTRO_STATUSES};
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, Set,
+};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Webmail domains say nothing about where someone works.
+const FREE_MAIL_DOMAINS: &[&str] = &[
+    "gmail.com",
+    "googlemail.com",
+    "outlook.com",
+    "hotmail.com",
+    "live.com",
+    "yahoo.com",
+    "icloud.com",
+    "me.com",
+    "aol.com",
+    "proton.me",
+    "protonmail.com",
+    "gmx.com",
+    "gmx.de",
+    "web.de",
+    "fastmail.com",
+];
+
+pub fn is_free_mail_domain(domain: &str) -> bool {
+    FREE_MAIL_DOMAINS.contains(&domain)
+}
+
+fn email_domain(email: &str) -> Option<&str> {
+    email.rsplit_once('@').map(|(_, domain)| domain)
+}
+
+/// The message an intro may have been made in. Addresses are normalized.
+pub struct IntroMessage<'a> {
+    pub message_id: Uuid,
+    pub conversation_id: Uuid,
+    pub mailbox_email: String,
+    pub from: &'a [String],
+    pub recipients: &'a [String],
+    pub sent_at: NaiveDateTime,
+}
+
+/// Picks the recipient the requester introduced. The intro's target wins
+/// when copied; otherwise the first recipient new to the thread who is a
+/// known contact of the startup or shares one of its email domains.
+/// `excluded` holds the requester's and the mailbox owner's addresses,
+/// `thread_participants` everyone on earlier messages of the conversation.
+pub fn pick_introduced<'a>(
+    recipients: &'a [String],
+    excluded: &HashSet<String>,
+    thread_participants: &HashSet<String>,
+    target_emails: &[String],
+    known_emails: &HashMap<String, Uuid>,
+    domains: &HashSet<String>,
+) -> Option<&'a String> {
+    let candidates: Vec<&String> = recipients
+        .iter()
+        .filter(|email| !excluded.contains(*email))
+        .collect();
+
+    candidates
+        .iter()
+        .find(|email| target_emails.contains(email))
+        .or_else(|| {
+            candidates.iter().find(|email| {
+                !thread_participants.contains(**email)
+                    && (known_emails.contains_key(**email)
+                        || email_domain(email).is_some_and(|domain| domains.contains(domain)))
+            })
+        })
+        .copied()
+}
+
+/// Sender and to/cc addresses of a stored message, normalized.
+fn message_participants(message: &message::Model) -> Vec<String> {
+    let recipients = [&message.to_emails, &message.cc_emails]
+        .into_iter()
+        .filter_map(|value| value.as_array())
+        .flatten()
+        .filter_map(|entry| entry.get("email").and_then(|email| email.as_str()));
+    normalize_addresses(std::iter::once(message.sender_email.as_str()).chain(recipients))
+}
+
+/// Marks open intros of the startup as made when the message is one of
+/// them. Returns how many were updated.
+pub async fn detect_intros_made<C: ConnectionTrait>(
+    db: &C,
+    startup_id: Uuid,
+    message: &IntroMessage<'_>,
+) -> Result<usize, DbErr> {
+    let intros = intro_request::Entity::find()
+        .filter(intro_request::Column::StartupId.eq(startup_id))
+        .filter(intro_request::Column::Status.is_in(OPEN_INTRO_STATUSES))
+        .filter(intro_request::Column::RequesterContactId.is_not_null())
+        .all(db)
+        .await?;
+    if intros.is_empty() || message.recipients.is_empty() {
+        return Ok(0);
+    }
+
+    let Some(startup) = startup::Entity::find_by_id(startup_id).one(db).await? else {
+        return Ok(0);
+    };
+    let contact_ids: Vec<Uuid> = contact::Entity::find()
+        .filter(contact::Column::StartupId.eq(startup_id))
+        .filter(contact::Column::IsTrashed.eq(false))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|contact| contact.id)
+        .collect();
+    let known_emails: HashMap<String, Uuid> = contact_identity::Entity::find()
+        .filter(contact_identity::Column::ContactId.is_in(contact_ids))
+        .filter(contact_identity::Column::Kind.eq(IdentityKind::Email.as_str()))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|identity| (identity.normalized_value, identity.contact_id))
+        .collect();
+    let website_domain = startup.website.as_deref().and_then(normalize_domain);
+    let thread_participants: HashSet<String> = message::Entity::find()
+        .filter(message::Column::ConversationId.eq(message.conversation_id))
+        .filter(message::Column::Id.ne(message.message_id))
+        .filter(message::Column::SentAt.lte(message.sent_at.and_utc()))
+        .all(db)
+        .await?
+        .iter()
+        .flat_map(message_participants)
+        .collect();
+
+    let mut updated = 0;
+    for intro in intros {
+        let Some(requester_id) = intro.requester_contact_id else {
+            continue;
+        };
+        // Old mail, e.g. from a first sync, cannot fulfil a later request.
+        if message.sent_at < intro.requested_at {
+            continue;
+        }
+        let requester_emails = contact_emails(db, requester_id).await?;
+        if !message
+            .from
+            .iter()
+            .any(|email| requester_emails.contains(email))
+        {
+            continue;
+        }
+
+        let mut excluded: HashSet<String> = requester_emails.iter().cloned().collect();
+        excluded.insert(message.mailbox_email.clone());
+        let mut domains: HashSet<String> = website_domain.iter().cloned().collect();
+        domains.extend(
+            requester_emails
+                .iter()
+                .filter_map(|email| email_domain(email))
+                .filter(|domain| !is_free_mail_domain(domain))
+                .map(str::to_string),
+        );
+        let target_emails = match intro.target_contact_id {
+            Some(target_id) => contact_emails(db, target_id).await?,
+            None => Vec::new(),
+        };
+
+        let Some(introduced) = pick_introduced(
+            message.recipients,
+            &excluded,
+            &thread_participants,
+            &target_emails,
+            &known_emails,
+            &domains,
+        ) else {
+            continue;
+        };
+
+        let mut active: intro_request::ActiveModel = intro.clone().into();
+        active.status = Set(INTRO_STATUS_MADE.to_string());
+        active.made_at = Set(Some(message.sent_at));
+        active.conversation_id = Set(Some(message.conversation_id));
+        active.introduced_email = Set(Some(introduced.clone()));
+        if let Some(contact_id) = known_emails.get(introduced) {
+            if *contact_id != requester_id {
+                active.target_contact_id = Set(Some(*contact_id));
+            }
+        }
+        active.updated_at = Set(Utc::now().naive_utc());
+        let intro = active.update(db).await?;
+        updated += 1;
+
+        if let Err(err) = record_intro_made(db, &intro, None, Some(message.message_id)).await {
+            tracing::warn!(error = ?err, "failed to record intro activity");
+        }
+    }
+
+    Ok(updated)
+}
+
+/// Normalizes addresses for [`IntroMessage`], dropping anything that is not
+/// an email.
+pub fn normalize_addresses<'a>(emails: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    emails.into_iter().filter_map(normalize_email).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_free_mail_domains() {
+        assert!(is_free_mail_domain("gmail.com"));
+        assert!(!is_free_mail_domain("acme.io"));
+    }
+
+    #[test]
+    fn test_pick_introduced() {
+        let recipients = vec![
+            "me@fund.vc".to_string(),
+            "friend@gmail.com".to_string(),
+            "cto@acme.io".to_string(),
+            "ceo@acme.io".to_string(),
+        ];
+        let excluded: HashSet<String> = ["me@fund.vc".to_string(), "ada@acme.io".to_string()]
+            .into_iter()
+            .collect();
+        let domains: HashSet<String> = ["acme.io".to_string()].into_iter().collect();
+        let known = HashMap::new();
+        let thread = HashSet::new();
+
+        assert_eq!(
+            pick_introduced(&recipients, &excluded, &thread, &[], &known, &domains),
+            Some(&"cto@acme.io".to_string())
+        );
+        assert_eq!(
+            pick_introduced(
+                &recipients,
+                &excluded,
+                &thread,
+                &["ceo@acme.io".to_string()],
+                &known,
+                &domains
+            ),
+            Some(&"ceo@acme.io".to_string())
+        );
+        assert_eq!(
+            pick_introduced(
+                &recipients,
+                &excluded,
+                &thread,
+                &[],
+                &known,
+                &HashSet::new()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pick_introduced_ignores_reply_all() {
+        let recipients = vec!["me@fund.vc".to_string(), "cto@acme.io".to_string()];
+        let excluded: HashSet<String> = ["me@fund.vc".to_string(), "ada@acme.io".to_string()]
+            .into_iter()
+            .collect();
+        let domains: HashSet<String> = ["acme.io".to_string()].into_iter().collect();
+        let mut known = HashMap::new();
+        known.insert("cto@acme.io".to_string(), Uuid::new_v4());
+        // The CTO was already on the thread, so replying to all is no intro.
+        let thread: HashSet<String> = [
+            "me@fund.vc".to_string(),
+            "ada@acme.io".to_string(),
+            "cto@acme.io".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            pick_introduced(&recipients, &excluded, &thread, &[], &known, &domains),
+            None
+        );
+        assert_eq!(
+            pick_introduced(
+                &recipients,
+                &excluded,
+                &thread,
+                &["cto@acme.io".to_string()],
+                &known,
+                &domains
+            ),
+            Some(&"cto@acme.io".to_string())
+        );
+    }
+}
@@ -1,3 +1,9 @@
+pub mod contact_retention;
 pub mod encryption_service;
 pub mod imap_service;
+pub mod intros;
+pub mod signal_score;
 pub mod smtp_service;
+pub mod stale_leads;
+pub mod startup_activity;
+pub mod url_health;
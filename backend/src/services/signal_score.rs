@@ -0,0 +1,301 @@
+//! Lead signal score: a 0-100 number per startup built from interview
+//! insights, outreach replies, inbound email and how recently we were in
+//! touch. The per-factor breakdown is stored next to the score so the UI can
+//! explain it.
+
+use crate::entities::{conversation, interview, interview_insight, message, outreach_log, startup};
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, JoinType, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+const POSITIVE_OUTCOMES: [&str; 3] = ["Replied", "Intro Made", "Call Booked"];
+const DECLINED_OUTCOME: &str = "Declined";
+
+/// Everything the score is computed from.
+#[derive(Debug, Clone, Default)]
+pub struct ScoreInputs {
+    /// From the insight of the most recent interview.
+    pub interest_level: Option<String>,
+    pub willing_to_use_monthly: Option<String>,
+    /// True when any interview flagged the startup as an activation candidate.
+    pub activation_candidate: bool,
+    pub positive_replies: u64,
+    pub declines: u64,
+    pub inbound_messages: u64,
+    pub last_contact_date: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoreFactor {
+    pub factor: &'static str,
+    pub points: i32,
+    pub max_points: i32,
+    pub detail: String,
+}
+
+/// Score the inputs as of `now`. Factors can go negative (e.g. a decline) but
+/// the total is kept within 0-100.
+pub fn score_signals(inputs: &ScoreInputs, now: NaiveDateTime) -> (i32, Vec<ScoreFactor>) {
+    let mut factors = Vec::with_capacity(6);
+
+    let interest = inputs.interest_level.as_deref().unwrap_or("");
+    factors.push(ScoreFactor {
+        factor: "interest_level",
+        points: match interest {
+            "High" => 25,
+            "Medium" => 15,
+            "Low" => 5,
+            "Negative" => -15,
+            _ => 0,
+        },
+        max_points: 25,
+        detail: if interest.is_empty() {
+            "No interview insight yet".to_string()
+        } else {
+            format!("Latest interest: {interest}")
+        },
+    });
+
+    let willing = inputs
+        .willing_to_use_monthly
+        .as_deref()
+        .map(str::trim)
+        .unwrap_or("");
+    let lowered = willing.to_ascii_lowercase();
+    factors.push(ScoreFactor {
+        factor: "willing_to_use_monthly",
+        points: if lowered.starts_with("yes") {
+            15
+        } else if lowered.starts_with("maybe") {
+            7
+        } else {
+            0
+        },
+        max_points: 15,
+        detail: if willing.is_empty() {
+            "Not asked yet".to_string()
+        } else {
+            format!("Willing to use monthly: {willing}")
+        },
+    });
+
+    factors.push(ScoreFactor {
+        factor: "activation_candidate",
+        points: if inputs.activation_candidate { 20 } else { 0 },
+        max_points: 20,
+        detail: if inputs.activation_candidate {
+            "Flagged as activation candidate".to_string()
+        } else {
+            "Not an activation candidate".to_string()
+        },
+    });
+
+    let replies =
+        (inputs.positive_replies.min(3) as i32) * 5 - (inputs.declines.min(1) as i32) * 10;
+    factors.push(ScoreFactor {
+        factor: "outreach_replies",
+        points: replies,
+        max_points: 15,
+        detail: format!(
+            "{} positive replies, {} declines",
+            inputs.positive_replies, inputs.declines
+        ),
+    });
+
+    factors.push(ScoreFactor {
+        factor: "inbound_messages",
+        points: inputs.inbound_messages.min(5) as i32 * 2,
+        max_points: 10,
+        detail: format!("{} emails received", inputs.inbound_messages),
+    });
+
+    let days = inputs
+        .last_contact_date
+        .map(|date| (now - date).num_days().max(0));
+    factors.push(ScoreFactor {
+        factor: "recency",
+        points: match days {
+            Some(0..=7) => 15,
+            Some(8..=30) => 10,
+            Some(31..=90) => 5,
+            _ => 0,
+        },
+        max_points: 15,
+        // The date rather than a day count, so the breakdown only changes
+        // when the points do.
+        detail: match inputs.last_contact_date {
+            Some(date) => format!("Last contact on {}", date.format("%Y-%m-%d")),
+            None => "No contact yet".to_string(),
+        },
+    });
+
+    let total = factors.iter().map(|factor| factor.points).sum::<i32>();
+    (total.clamp(0, 100), factors)
+}
+
+async fn load_inputs<C: ConnectionTrait>(
+    db: &C,
+    existing: &startup::Model,
+) -> Result<ScoreInputs, DbErr> {
+    let latest_insight = interview_insight::Entity::find()
+        .join(
+            JoinType::InnerJoin,
+            interview_insight::Relation::Interview.def(),
+        )
+        .filter(interview::Column::StartupId.eq(existing.id))
+        .order_by_desc(interview::Column::Date)
+        .one(db)
+        .await?;
+    let activation_candidate = interview_insight::Entity::find()
+        .join(
+            JoinType::InnerJoin,
+            interview_insight::Relation::Interview.def(),
+        )
+        .filter(interview::Column::StartupId.eq(existing.id))
+        .filter(interview_insight::Column::ActivationCandidate.eq(true))
+        .count(db)
+        .await?
+        > 0;
+
+    let positive_replies = outreach_log::Entity::find()
+        .filter(outreach_log::Column::StartupId.eq(existing.id))
+        .filter(outreach_log::Column::Outcome.is_in(POSITIVE_OUTCOMES))
+        .count(db)
+        .await?;
+    let declines = outreach_log::Entity::find()
+        .filter(outreach_log::Column::StartupId.eq(existing.id))
+        .filter(outreach_log::Column::Outcome.eq(DECLINED_OUTCOME))
+        .count(db)
+        .await?;
+
+    let inbound_messages = message::Entity::find()
+        .join(JoinType::InnerJoin, message::Relation::Conversation.def())
+        .filter(conversation::Column::StartupId.eq(existing.id))
+        .filter(message::Column::Direction.eq("received"))
+        .count(db)
+        .await?;
+
+    Ok(ScoreInputs {
+        interest_level: latest_insight
+            .as_ref()
+            .map(|insight| insight.interest_level.clone()),
+        willing_to_use_monthly: latest_insight.and_then(|insight| insight.willing_to_use_monthly),
+        activation_candidate,
+        positive_replies,
+        declines,
+        inbound_messages,
+        last_contact_date: existing.last_contact_date,
+    })
+}
+
+/// Recompute the score for one startup, writing only when it changed.
+/// Returns the updated startup in that case. `updated_at` is left alone since
+/// the score also moves as time passes.
+pub async fn rescore_startup<C: ConnectionTrait>(
+    db: &C,
+    startup_id: Uuid,
+) -> Result<Option<startup::Model>, DbErr> {
+    let Some(existing) = startup::Entity::find_by_id(startup_id).one(db).await? else {
+        return Ok(None);
+    };
+
+    let inputs = load_inputs(db, &existing).await?;
+    let (score, factors) = score_signals(&inputs, Utc::now().naive_utc());
+    let breakdown = serde_json::to_value(&factors).map_err(|err| DbErr::Custom(err.to_string()))?;
+    if existing.signal_score == score && existing.signal_breakdown == breakdown {
+        return Ok(None);
+    }
+
+    let mut active: startup::ActiveModel = existing.into();
+    active.signal_score = Set(score);
+    active.signal_breakdown = Set(breakdown);
+    Ok(Some(active.update(db).await?))
+}
+
+/// Rescore every startup that is not in the trash. Run daily so recency
+/// decays even without new activity. Returns how many scores changed.
+pub async fn rescore_all_startups<C: ConnectionTrait>(db: &C) -> Result<u64, DbErr> {
+    let ids: Vec<Uuid> = startup::Entity::find()
+        .select_only()
+        .column(startup::Column::Id)
+        .filter(startup::Column::IsTrashed.eq(false))
+        .order_by_asc(startup::Column::CreatedAt)
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    let mut changed = 0;
+    for id in ids {
+        if rescore_startup(db, id).await?.is_some() {
+            changed += 1;
+        }
+    }
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, NaiveDate};
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 4, 25)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    fn points(factors: &[ScoreFactor], factor: &str) -> i32 {
+        factors
+            .iter()
+            .find(|item| item.factor == factor)
+            .map(|item| item.points)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_strong_lead_scores_full_marks() {
+        let inputs = ScoreInputs {
+            interest_level: Some("High".to_string()),
+            willing_to_use_monthly: Some("Yes, if priced per seat".to_string()),
+            activation_candidate: true,
+            positive_replies: 4,
+            declines: 0,
+            inbound_messages: 9,
+            last_contact_date: Some(now() - Duration::days(2)),
+        };
+        let (score, factors) = score_signals(&inputs, now());
+
+        assert_eq!(score, 100);
+        assert_eq!(factors.len(), 6);
+        assert!(factors.iter().all(|item| item.points == item.max_points));
+    }
+
+    #[test]
+    fn test_negative_signals_and_clamping() {
+        let inputs = ScoreInputs {
+            interest_level: Some("Negative".to_string()),
+            willing_to_use_monthly: Some("maybe".to_string()),
+            positive_replies: 1,
+            declines: 2,
+            last_contact_date: Some(now() - Duration::days(45)),
+            ..Default::default()
+        };
+        let (score, factors) = score_signals(&inputs, now());
+
+        assert_eq!(points(&factors, "interest_level"), -15);
+        assert_eq!(points(&factors, "willing_to_use_monthly"), 7);
+        assert_eq!(points(&factors, "outreach_replies"), -5);
+        assert_eq!(points(&factors, "recency"), 5);
+        assert_eq!(score, 0);
+
+        let (score, factors) = score_signals(&ScoreInputs::default(), now());
+        assert_eq!(score, 0);
+        assert_eq!(points(&factors, "recency"), 0);
+    }
+}
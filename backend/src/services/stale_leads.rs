@@ -0,0 +1,272 @@
+//! Finds leads nobody has been in touch with for too long and gives their
+//! owner a follow-up task. Thresholds are per stage and can be overridden with
+//! `STALE_LEAD_THRESHOLDS`, a JSON object mapping a stage name to a number of
+//! days (`null` turns the check off for that stage).
+
+use crate::entities::{contact, startup, task};
+use crate::pipeline::PipelineStage;
+use crate::tasks_controller::{TASK_SOURCE_STALE_FOLLOW_UP, TASK_STATUS_DONE, TASK_STATUS_OPEN};
+use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::env;
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct StaleThresholds {
+    days: HashMap<PipelineStage, i64>,
+}
+
+impl Default for StaleThresholds {
+    fn default() -> Self {
+        use PipelineStage::*;
+
+        Self {
+            days: HashMap::from([
+                (Lead, 14),
+                (Contacted, 10),
+                (IntroSecured, 7),
+                (CallBooked, 7),
+                (MeetingScheduled, 7),
+                (InterviewDone, 10),
+                (InDiscussion, 10),
+                (ActivationCandidate, 7),
+            ]),
+        }
+    }
+}
+
+impl StaleThresholds {
+    pub fn from_env() -> Self {
+        match env::var("STALE_LEAD_THRESHOLDS") {
+            Ok(raw) if !raw.trim().is_empty() => Self::from_json(&raw).unwrap_or_else(|err| {
+                tracing::warn!(error = %err, "invalid STALE_LEAD_THRESHOLDS, using defaults");
+                Self::default()
+            }),
+            _ => Self::default(),
+        }
+    }
+
+    /// Stages missing from the override keep their default threshold.
+    pub fn from_json(raw: &str) -> Result<Self, String> {
+        let overrides: HashMap<String, Option<i64>> =
+            serde_json::from_str(raw).map_err(|e| e.to_string())?;
+
+        let mut thresholds = Self::default();
+        for (stage, days) in overrides {
+            let stage = stage.parse::<PipelineStage>().map_err(|e| e.to_string())?;
+            match days {
+                Some(days) if days > 0 => {
+                    thresholds.days.insert(stage, days);
+                }
+                Some(days) => {
+                    return Err(format!(
+                        "threshold for {stage} must be positive, got {days}"
+                    ))
+                }
+                None => {
+                    thresholds.days.remove(&stage);
+                }
+            }
+        }
+
+        Ok(thresholds)
+    }
+
+    pub fn for_stage(&self, stage: PipelineStage) -> Option<i64> {
+        self.days.get(&stage).copied()
+    }
+
+    /// The day a startup in `stage`, last contacted on `last_contact`, became
+    /// stale, or `None` while it is still fresh.
+    pub fn stale_since(
+        &self,
+        stage: PipelineStage,
+        last_contact: NaiveDate,
+        today: NaiveDate,
+    ) -> Option<NaiveDate> {
+        let due = last_contact + Duration::days(self.for_stage(stage)?);
+        (due <= today).then_some(due)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct StaleSweepReport {
+    pub stale: u64,
+    pub tasks_created: u64,
+    pub tasks_cleared: u64,
+}
+
+/// Create a follow-up task for every stale startup that does not already have
+/// one open, and close follow-ups for startups that are no longer stale (e.g.
+/// they moved to a closed stage or were trashed).
+pub async fn sweep_stale_leads<C: ConnectionTrait>(
+    db: &C,
+    thresholds: &StaleThresholds,
+) -> Result<StaleSweepReport, DbErr> {
+    let now = Utc::now().naive_utc();
+    let today = now.date();
+
+    let follow_ups: Vec<(Option<Uuid>, String, NaiveDateTime)> = task::Entity::find()
+        .select_only()
+        .column(task::Column::StartupId)
+        .column(task::Column::Status)
+        .column(task::Column::CreatedAt)
+        .filter(task::Column::Source.eq(TASK_SOURCE_STALE_FOLLOW_UP))
+        .filter(task::Column::StartupId.is_not_null())
+        .into_tuple()
+        .all(db)
+        .await?;
+    let mut with_open_task = HashSet::new();
+    let mut latest_follow_up: HashMap<Uuid, NaiveDateTime> = HashMap::new();
+    for (startup_id, status, created_at) in follow_ups {
+        let Some(startup_id) = startup_id else {
+            continue;
+        };
+        if status == TASK_STATUS_OPEN {
+            with_open_task.insert(startup_id);
+        }
+        let latest = latest_follow_up.entry(startup_id).or_insert(created_at);
+        *latest = (*latest).max(created_at);
+    }
+
+    let startups = startup::Entity::find()
+        .filter(startup::Column::IsTrashed.eq(false))
+        .order_by_asc(startup::Column::CreatedAt)
+        .all(db)
+        .await?;
+
+    let mut report = StaleSweepReport::default();
+    for startup in startups {
+        let last_contact = startup.last_contact_date.unwrap_or(startup.created_at);
+        let due = PipelineStage::parse(&startup.status)
+            .and_then(|stage| thresholds.stale_since(stage, last_contact.date(), today));
+        let Some(due) = due else {
+            continue;
+        };
+
+        report.stale += 1;
+        // One follow-up per quiet spell: a task someone closed by hand is not
+        // recreated until there has been contact again.
+        let already_followed_up = with_open_task.remove(&startup.id)
+            || latest_follow_up
+                .get(&startup.id)
+                .is_some_and(|created_at| *created_at >= last_contact);
+        if !already_followed_up {
+            let days = (today - last_contact.date()).num_days();
+            create_follow_up(db, &startup, due, days).await?;
+            report.tasks_created += 1;
+        }
+    }
+
+    // Anything left has an open follow-up but is no longer stale.
+    for startup_id in with_open_task {
+        report.tasks_cleared += clear_stale_follow_ups(db, startup_id).await?;
+    }
+
+    Ok(report)
+}
+
+async fn create_follow_up<C: ConnectionTrait>(
+    db: &C,
+    startup: &startup::Model,
+    due: NaiveDate,
+    days_without_contact: i64,
+) -> Result<task::Model, DbErr> {
+    let contact = contact::Entity::find()
+        .filter(contact::Column::StartupId.eq(startup.id))
+        .filter(contact::Column::IsTrashed.eq(false))
+        .order_by_desc(contact::Column::IsPrimary)
+        .order_by_asc(contact::Column::Name)
+        .one(db)
+        .await?;
+    let assignee_id = startup
+        .owner_id
+        .or_else(|| contact.as_ref().and_then(|contact| contact.owner_id));
+    let now = Utc::now().naive_utc();
+
+    task::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        title: Set(format!(
+            "Follow up with {}: no contact for {} days",
+            startup.name, days_without_contact
+        )),
+        due_date: Set(due),
+        assignee_id: Set(assignee_id),
+        status: Set(TASK_STATUS_OPEN.to_string()),
+        source: Set(TASK_SOURCE_STALE_FOLLOW_UP.to_string()),
+        startup_id: Set(Some(startup.id)),
+        contact_id: Set(contact.map(|contact| contact.id)),
+        interview_id: Set(None),
+        conversation_id: Set(None),
+        created_by_id: Set(None),
+        completed_at: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(db)
+    .await
+}
+
+/// Close open stale-lead follow-ups for a startup. Called when outreach is
+/// logged or an email arrives, since the lead is no longer cold.
+pub async fn clear_stale_follow_ups<C: ConnectionTrait>(
+    db: &C,
+    startup_id: Uuid,
+) -> Result<u64, DbErr> {
+    let now = Utc::now().naive_utc();
+    let result = task::Entity::update_many()
+        .col_expr(task::Column::Status, Expr::value(TASK_STATUS_DONE))
+        .col_expr(task::Column::CompletedAt, Expr::value(now))
+        .col_expr(task::Column::UpdatedAt, Expr::value(now))
+        .filter(task::Column::StartupId.eq(startup_id))
+        .filter(task::Column::Source.eq(TASK_SOURCE_STALE_FOLLOW_UP))
+        .filter(task::Column::Status.eq(TASK_STATUS_OPEN))
+        .exec(db)
+        .await?;
+
+    Ok(result.rows_affected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 4, day).unwrap()
+    }
+
+    #[test]
+    fn test_stale_since_uses_stage_threshold() {
+        let thresholds = StaleThresholds::default();
+
+        assert_eq!(
+            thresholds.stale_since(PipelineStage::Contacted, day(1), day(11)),
+            Some(day(11))
+        );
+        assert_eq!(
+            thresholds.stale_since(PipelineStage::Contacted, day(1), day(10)),
+            None
+        );
+        assert_eq!(
+            thresholds.stale_since(PipelineStage::ClosedWon, day(1), day(30)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_thresholds_from_json_overrides_defaults() {
+        let thresholds = StaleThresholds::from_json(r#"{"Contacted": 3, "Lead": null}"#).unwrap();
+
+        assert_eq!(thresholds.for_stage(PipelineStage::Contacted), Some(3));
+        assert_eq!(thresholds.for_stage(PipelineStage::Lead), None);
+        assert_eq!(thresholds.for_stage(PipelineStage::CallBooked), Some(7));
+        assert!(StaleThresholds::from_json(r#"{"Contacted": 0}"#).is_err());
+        assert!(StaleThresholds::from_json(r#"{"Nope": 3}"#).is_err());
+    }
+}
@@ -0,0 +1,323 @@
+//! Keeps `startup.last_contact_date` and `startup.next_step` in line with the
+//! latest outreach, interview and email activity for each startup, and
+//! rescores the startup whenever that activity changes.
+
+use crate::entities::{conversation, interview, message, outreach_log, startup};
+use crate::history_controller::record_startup_revisions;
+use crate::services::signal_score::rescore_startup;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::sea_query::{Expr, ExprTrait};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, JoinType,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set,
+};
+use uuid::Uuid;
+
+/// The most recent interaction of each kind we know about for a startup.
+#[derive(Debug, Clone, Default)]
+pub struct ActivitySignals {
+    pub latest_outreach: Option<OutreachSignal>,
+    pub latest_email: Option<EmailSignal>,
+    /// Latest interview that already happened.
+    pub latest_interview: Option<NaiveDateTime>,
+    /// Earliest interview still ahead.
+    pub upcoming_interview: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OutreachSignal {
+    pub date: NaiveDateTime,
+    pub channel: String,
+    pub direction: String,
+    pub outcome: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailSignal {
+    pub date: NaiveDateTime,
+    pub received: bool,
+}
+
+enum Touch<'a> {
+    Outreach(&'a OutreachSignal),
+    Email(&'a EmailSignal),
+    Interview,
+}
+
+/// Work out `(last_contact_date, next_step)` from the signals. The next step
+/// follows from whichever interaction happened last, except that a booked
+/// interview always comes first.
+pub fn derive_contact_fields(signals: &ActivitySignals) -> (Option<NaiveDateTime>, Option<String>) {
+    let mut touches: Vec<(NaiveDateTime, Touch)> = Vec::new();
+    if let Some(outreach) = &signals.latest_outreach {
+        touches.push((outreach.date, Touch::Outreach(outreach)));
+    }
+    if let Some(email) = &signals.latest_email {
+        touches.push((email.date, Touch::Email(email)));
+    }
+    if let Some(date) = signals.latest_interview {
+        touches.push((date, Touch::Interview));
+    }
+    // On ties the earlier entry wins, so a logged outreach beats the synced
+    // copy of the same email.
+    let latest = touches
+        .into_iter()
+        .reduce(|best, next| if next.0 > best.0 { next } else { best });
+    let last_contact_date = latest.as_ref().map(|(date, _)| *date);
+
+    if let Some(date) = signals.upcoming_interview {
+        return (
+            last_contact_date,
+            Some(format!("Interview on {}", date.format("%Y-%m-%d"))),
+        );
+    }
+
+    let next_step = latest.and_then(|(_, touch)| match touch {
+        Touch::Interview => Some("Send interview follow-up".to_string()),
+        Touch::Email(email) if email.received => Some("Reply to email".to_string()),
+        Touch::Email(_) => Some("Follow up if no reply to email".to_string()),
+        Touch::Outreach(outreach) => outreach_next_step(outreach),
+    });
+
+    (last_contact_date, next_step)
+}
+
+fn outreach_next_step(outreach: &OutreachSignal) -> Option<String> {
+    let channel = &outreach.channel;
+    match outreach.outcome.as_str() {
+        "Declined" => None,
+        "Call Booked" => Some(format!("Hold booked call ({channel})")),
+        "Intro Made" => Some("Follow up on intro".to_string()),
+        "Replied" => Some(format!("Respond to {channel} reply")),
+        _ if outreach.direction.eq_ignore_ascii_case("inbound") => {
+            Some(format!("Reply via {channel}"))
+        }
+        _ => Some(format!("Follow up via {channel}")),
+    }
+}
+
+async fn load_signals<C: ConnectionTrait>(
+    db: &C,
+    startup_id: Uuid,
+    now: NaiveDateTime,
+) -> Result<ActivitySignals, DbErr> {
+    let latest_outreach = outreach_log::Entity::find()
+        .filter(outreach_log::Column::StartupId.eq(startup_id))
+        .filter(outreach_log::Column::Date.lte(now))
+        .order_by_desc(outreach_log::Column::Date)
+        .one(db)
+        .await?
+        .map(|log| OutreachSignal {
+            date: log.date,
+            channel: log.channel,
+            direction: log.direction,
+            outcome: log.outcome,
+        });
+
+    let latest_email = message::Entity::find()
+        .join(JoinType::InnerJoin, message::Relation::Conversation.def())
+        .filter(conversation::Column::StartupId.eq(startup_id))
+        .filter(message::Column::SentAt.lte(now.and_utc()))
+        .order_by_desc(message::Column::SentAt)
+        .one(db)
+        .await?
+        .map(|message| EmailSignal {
+            date: message.sent_at.naive_utc(),
+            received: message.direction == "received",
+        });
+
+    let latest_interview = interview::Entity::find()
+        .filter(interview::Column::StartupId.eq(startup_id))
+        .filter(interview::Column::Date.lte(now))
+        .order_by_desc(interview::Column::Date)
+        .one(db)
+        .await?
+        .map(|interview| interview.date);
+    let upcoming_interview = interview::Entity::find()
+        .filter(interview::Column::StartupId.eq(startup_id))
+        .filter(interview::Column::Date.gt(now))
+        .order_by_asc(interview::Column::Date)
+        .one(db)
+        .await?
+        .map(|interview| interview.date);
+
+    Ok(ActivitySignals {
+        latest_outreach,
+        latest_email,
+        latest_interview,
+        upcoming_interview,
+    })
+}
+
+/// Recompute the derived fields for one startup, writing only when they
+/// changed, then rescore it since the signal score depends on recency.
+/// Returns the updated startup when anything changed.
+pub async fn refresh_startup_contact_fields<C: ConnectionTrait>(
+    db: &C,
+    startup_id: Uuid,
+) -> Result<Option<startup::Model>, DbErr> {
+    let Some(existing) = startup::Entity::find_by_id(startup_id).one(db).await? else {
+        return Ok(None);
+    };
+
+    let now = Utc::now().naive_utc();
+    let signals = load_signals(db, startup_id, now).await?;
+    let (last_contact_date, next_step) = derive_contact_fields(&signals);
+    let mut refreshed = None;
+    if existing.last_contact_date != last_contact_date || existing.next_step != next_step {
+        let before = existing.clone();
+        let mut active: startup::ActiveModel = existing.into();
+        active.last_contact_date = Set(last_contact_date);
+        active.next_step = Set(next_step);
+        active.updated_at = Set(now);
+        let updated = active.update(db).await?;
+        record_startup_revisions(db, &before, &updated, None).await?;
+        refreshed = Some(updated);
+    }
+
+    if let Some(rescored) = rescore_startup(db, startup_id).await? {
+        refreshed = Some(rescored);
+    }
+
+    Ok(refreshed)
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct BackfillReport {
+    pub scanned: u64,
+    pub updated: u64,
+}
+
+/// Recompute the derived fields for every startup, e.g. after deploying this
+/// or importing historical data.
+pub async fn backfill_startup_contact_fields<C: ConnectionTrait>(
+    db: &C,
+) -> Result<BackfillReport, DbErr> {
+    let ids: Vec<Uuid> = startup::Entity::find()
+        .select_only()
+        .column(startup::Column::Id)
+        .order_by_asc(startup::Column::CreatedAt)
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    let mut report = BackfillReport::default();
+    for id in ids {
+        report.scanned += 1;
+        if refresh_startup_contact_fields(db, id).await?.is_some() {
+            report.updated += 1;
+        }
+    }
+
+    Ok(report)
+}
+
+/// Refresh startups with an interview that has happened since their derived
+/// fields were last written. Nothing is saved when an interview date passes,
+/// so without this `next_step` keeps pointing at the past interview and
+/// `last_contact_date` stays behind it.
+pub async fn refresh_passed_interviews<C: ConnectionTrait>(
+    db: &C,
+) -> Result<BackfillReport, DbErr> {
+    let now = Utc::now().naive_utc();
+    let ids: Vec<Uuid> = interview::Entity::find()
+        .select_only()
+        .column(interview::Column::StartupId)
+        .distinct()
+        .join(JoinType::InnerJoin, interview::Relation::Startup.def())
+        .filter(startup::Column::IsTrashed.eq(false))
+        .filter(interview::Column::Date.lte(now))
+        .filter(
+            Condition::any()
+                .add(startup::Column::LastContactDate.is_null())
+                .add(
+                    Expr::col((startup::Entity, startup::Column::LastContactDate))
+                        .lt(Expr::col((interview::Entity, interview::Column::Date))),
+                ),
+        )
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    let mut report = BackfillReport::default();
+    for id in ids {
+        report.scanned += 1;
+        if refresh_startup_contact_fields(db, id).await?.is_some() {
+            report.updated += 1;
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 4, day)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap()
+    }
+
+    fn outreach(day: u32, direction: &str, outcome: &str) -> OutreachSignal {
+        OutreachSignal {
+            date: at(day),
+            channel: "LinkedIn".to_string(),
+            direction: direction.to_string(),
+            outcome: outcome.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_latest_interaction_drives_next_step() {
+        let signals = ActivitySignals {
+            latest_outreach: Some(outreach(3, "Outbound", "No Response")),
+            latest_email: Some(EmailSignal {
+                date: at(5),
+                received: true,
+            }),
+            latest_interview: Some(at(1)),
+            upcoming_interview: None,
+        };
+        assert_eq!(
+            derive_contact_fields(&signals),
+            (Some(at(5)), Some("Reply to email".to_string()))
+        );
+
+        let signals = ActivitySignals {
+            latest_email: None,
+            ..signals
+        };
+        assert_eq!(
+            derive_contact_fields(&signals),
+            (Some(at(3)), Some("Follow up via LinkedIn".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_upcoming_interview_and_outcomes() {
+        let signals = ActivitySignals {
+            latest_outreach: Some(outreach(3, "Outbound", "Declined")),
+            ..Default::default()
+        };
+        assert_eq!(derive_contact_fields(&signals), (Some(at(3)), None));
+
+        let signals = ActivitySignals {
+            latest_outreach: Some(outreach(3, "Inbound", "No Response")),
+            upcoming_interview: Some(at(20)),
+            ..Default::default()
+        };
+        assert_eq!(
+            derive_contact_fields(&signals),
+            (Some(at(3)), Some("Interview on 2025-04-20".to_string()))
+        );
+
+        assert_eq!(
+            derive_contact_fields(&ActivitySignals::default()),
+            (None, None)
+        );
+    }
+}
@@ -0,0 +1,442 @@
+//! Periodically fetches each startup's `website` and `newsroom_url`, keeps
+//! the latest result per URL in `url_check` and mirrors the broken state onto
+//! the startup so it shows up in startup responses. A URL only counts as
+//! broken after two failed checks in a row, so a single timeout does not flap
+//! the flag or the activity feed.
+//!
+//! The URLs are user-supplied, so every hop is resolved first and refused
+//! unless all its addresses are public; redirects are followed by hand for
+//! the same reason.
+
+use crate::entities::{startup, url_check};
+use crate::{
+    record_activity_event, ActivityEventInput, ACTIVITY_NEWSROOM_DOWN, ACTIVITY_NEWSROOM_RECOVERED,
+};
+use chrono::Utc;
+use futures::{stream, StreamExt};
+use reqwest::header::LOCATION;
+use reqwest::{redirect, Client, Url};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, Set,
+};
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+use tokio::net::lookup_host;
+use uuid::Uuid;
+
+pub const URL_KIND_WEBSITE: &str = "website";
+pub const URL_KIND_NEWSROOM: &str = "newsroom";
+
+const BROKEN_AFTER_FAILURES: i32 = 2;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const CONCURRENT_CHECKS: usize = 8;
+const MAX_REDIRECTS: usize = 5;
+const USER_AGENT: &str = "Poblysh link checker";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchOutcome {
+    pub status_code: Option<u16>,
+    pub latency_ms: i32,
+    pub final_url: Option<String>,
+    pub error: Option<String>,
+}
+
+impl FetchOutcome {
+    /// Anything below 400 after following redirects is fine.
+    pub fn is_ok(&self) -> bool {
+        self.status_code.is_some_and(|code| code < 400)
+    }
+}
+
+/// `(is_broken, consecutive_failures)` after a check, given the previous
+/// failure streak.
+pub fn next_health(previous_failures: i32, ok: bool) -> (bool, i32) {
+    if ok {
+        (false, 0)
+    } else {
+        let failures = previous_failures + 1;
+        (failures >= BROKEN_AFTER_FAILURES, failures)
+    }
+}
+
+/// Stored URLs are often typed without a scheme.
+fn request_url(url: &str) -> String {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        url.to_string()
+    } else {
+        format!("https://{url}")
+    }
+}
+
+/// Whether the checker may connect to the address: loopback, private,
+/// link-local, unique-local, CGNAT and unspecified ranges are refused.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || first == 0
+                || (first == 100 && second & 0xc0 == 64))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_ip(IpAddr::V4(mapped)),
+            None => {
+                let first = ip.segments()[0];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || first & 0xfe00 == 0xfc00
+                    || first & 0xffc0 == 0xfe80)
+            }
+        },
+    }
+}
+
+/// The addresses to connect to for the URL, if they are all public.
+async fn resolve_public(url: &Url) -> Result<Vec<SocketAddr>, String> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(format!("unsupported scheme {}", url.scheme()));
+    }
+    let host = url
+        .host_str()
+        .ok_or_else(|| "URL has no host".to_string())?;
+    let port = url.port_or_known_default().unwrap_or(443);
+    let addrs: Vec<SocketAddr> = lookup_host((host.trim_matches(['[', ']']), port))
+        .await
+        .map_err(|err| format!("could not resolve {host}: {err}"))?
+        .collect();
+    if addrs.is_empty() {
+        return Err(format!("could not resolve {host}"));
+    }
+    if let Some(addr) = addrs.iter().find(|addr| !is_public_ip(addr.ip())) {
+        return Err(format!(
+            "{host} resolves to non-public address {}",
+            addr.ip()
+        ));
+    }
+    Ok(addrs)
+}
+
+/// GETs the URL hop by hop, pinning each connection to the addresses that
+/// were vetted. Returns the final status and URL.
+async fn fetch_public(target: &str) -> Result<(u16, Url), String> {
+    let mut url = Url::parse(target).map_err(|err| err.to_string())?;
+    for _ in 0..=MAX_REDIRECTS {
+        let addrs = resolve_public(&url).await?;
+        let host = url.host_str().unwrap_or_default().to_string();
+        let client = Client::builder()
+            .redirect(redirect::Policy::none())
+            .user_agent(USER_AGENT)
+            .resolve_to_addrs(&host, &addrs)
+            .build()
+            .map_err(|err| err.to_string())?;
+        let response = client
+            .get(url.clone())
+            .send()
+            .await
+            .map_err(|err| err.to_string())?;
+        let status = response.status();
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok());
+        match location {
+            Some(location) if status.is_redirection() => {
+                url = url
+                    .join(location)
+                    .map_err(|err| format!("invalid redirect: {err}"))?;
+            }
+            _ => return Ok((status.as_u16(), url)),
+        }
+    }
+    Err(format!("more than {MAX_REDIRECTS} redirects"))
+}
+
+async fn fetch(url: &str) -> FetchOutcome {
+    let target = request_url(url);
+    let started = Instant::now();
+    let result = tokio::time::timeout(REQUEST_TIMEOUT, fetch_public(&target))
+        .await
+        .unwrap_or_else(|_| Err("request timed out".to_string()));
+    let latency_ms = started.elapsed().as_millis().min(i32::MAX as u128) as i32;
+
+    match result {
+        Ok((status_code, final_url)) => {
+            let final_url = final_url.to_string();
+            FetchOutcome {
+                status_code: Some(status_code),
+                latency_ms,
+                final_url: (final_url.trim_end_matches('/') != target.trim_end_matches('/'))
+                    .then_some(final_url),
+                error: None,
+            }
+        }
+        Err(error) => FetchOutcome {
+            status_code: None,
+            latency_ms,
+            final_url: None,
+            error: Some(error),
+        },
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UrlHealthReport {
+    pub checked: u64,
+    pub broken: u64,
+    pub newsrooms_down: u64,
+    pub newsrooms_recovered: u64,
+}
+
+struct Target {
+    startup: startup::Model,
+    kind: &'static str,
+    url: String,
+}
+
+/// Check every website and newsroom URL of startups that are not trashed.
+pub async fn check_all_urls<C: ConnectionTrait>(db: &C) -> Result<UrlHealthReport, DbErr> {
+    let startups = startup::Entity::find()
+        .filter(startup::Column::IsTrashed.eq(false))
+        .all(db)
+        .await?;
+    let mut previous: HashMap<(Uuid, String), url_check::Model> = url_check::Entity::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|check| ((check.startup_id, check.kind.clone()), check))
+        .collect();
+
+    let mut targets = Vec::new();
+    for startup in startups {
+        let urls = [
+            (URL_KIND_WEBSITE, startup.website.clone()),
+            (URL_KIND_NEWSROOM, startup.newsroom_url.clone()),
+        ];
+        for (kind, url) in urls {
+            match url
+                .map(|url| url.trim().to_string())
+                .filter(|url| !url.is_empty())
+            {
+                Some(url) => targets.push(Target {
+                    startup: startup.clone(),
+                    kind,
+                    url,
+                }),
+                None => {
+                    // The URL was removed; forget its history and flag.
+                    if let Some(stale) = previous.remove(&(startup.id, kind.to_string())) {
+                        url_check::Entity::delete_by_id(stale.id).exec(db).await?;
+                    }
+                    set_broken_flag(db, &startup, kind, false).await?;
+                }
+            }
+        }
+    }
+
+    let results: Vec<(Target, FetchOutcome)> = stream::iter(targets)
+        .map(|target| async move {
+            let outcome = fetch(&target.url).await;
+            (target, outcome)
+        })
+        .buffer_unordered(CONCURRENT_CHECKS)
+        .collect()
+        .await;
+
+    let mut report = UrlHealthReport::default();
+    for (target, outcome) in results {
+        let existing = previous.remove(&(target.startup.id, target.kind.to_string()));
+        // A changed URL starts with a clean slate.
+        let same_url = existing.as_ref().filter(|check| check.url == target.url);
+        let was_broken = same_url.is_some_and(|check| check.is_broken);
+        let (is_broken, failures) = next_health(
+            same_url.map_or(0, |check| check.consecutive_failures),
+            outcome.is_ok(),
+        );
+        let keep_last_ok = same_url.is_some();
+
+        let now = Utc::now().naive_utc();
+        let mut active = match existing {
+            Some(check) => {
+                let mut active: url_check::ActiveModel = check.into();
+                if !keep_last_ok {
+                    active.last_ok_at = Set(None);
+                }
+                active
+            }
+            None => url_check::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                startup_id: Set(target.startup.id),
+                kind: Set(target.kind.to_string()),
+                last_ok_at: Set(None),
+                ..Default::default()
+            },
+        };
+        active.url = Set(target.url.clone());
+        active.status_code = Set(outcome.status_code.map(i32::from));
+        active.latency_ms = Set(Some(outcome.latency_ms));
+        active.redirect_url = Set(outcome.final_url.clone());
+        active.error = Set(outcome.error.clone());
+        active.is_broken = Set(is_broken);
+        active.consecutive_failures = Set(failures);
+        active.checked_at = Set(now);
+        if outcome.is_ok() {
+            active.last_ok_at = Set(Some(now));
+        }
+        active.save(db).await?;
+
+        report.checked += 1;
+        if is_broken {
+            report.broken += 1;
+        }
+        set_broken_flag(db, &target.startup, target.kind, is_broken).await?;
+
+        if target.kind == URL_KIND_NEWSROOM && was_broken != is_broken {
+            record_newsroom_change(db, &target, &outcome, is_broken).await?;
+            if is_broken {
+                report.newsrooms_down += 1;
+            } else {
+                report.newsrooms_recovered += 1;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Like the signal score, the flag is maintained by the server and does not
+/// touch `updated_at`.
+async fn set_broken_flag<C: ConnectionTrait>(
+    db: &C,
+    startup: &startup::Model,
+    kind: &str,
+    broken: bool,
+) -> Result<(), DbErr> {
+    let current = if kind == URL_KIND_NEWSROOM {
+        startup.newsroom_url_broken
+    } else {
+        startup.website_broken
+    };
+    if current == broken {
+        return Ok(());
+    }
+
+    let mut active: startup::ActiveModel = startup.clone().into();
+    if kind == URL_KIND_NEWSROOM {
+        active.newsroom_url_broken = Set(broken);
+    } else {
+        active.website_broken = Set(broken);
+    }
+    active.update(db).await?;
+    Ok(())
+}
+
+async fn record_newsroom_change<C: ConnectionTrait>(
+    db: &C,
+    target: &Target,
+    outcome: &FetchOutcome,
+    is_broken: bool,
+) -> Result<(), DbErr> {
+    let (activity_type, description) = if is_broken {
+        (
+            ACTIVITY_NEWSROOM_DOWN,
+            format!("Newsroom for {} is down", target.startup.name),
+        )
+    } else {
+        (
+            ACTIVITY_NEWSROOM_RECOVERED,
+            format!("Newsroom for {} is back up", target.startup.name),
+        )
+    };
+
+    record_activity_event(
+        db,
+        ActivityEventInput {
+            activity_type,
+            description,
+            user_id: None,
+            user_name: None,
+            startup_id: Some(target.startup.id),
+            startup_name: Some(target.startup.name.clone()),
+            contact_id: None,
+            contact_name: None,
+            stage_from: None,
+            stage_to: None,
+            metadata: Some(json!({
+                "url": target.url,
+                "status_code": outcome.status_code,
+                "error": outcome.error,
+            })),
+            occurred_at: None,
+        },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broken_after_consecutive_failures() {
+        assert_eq!(next_health(0, false), (false, 1));
+        assert_eq!(next_health(1, false), (true, 2));
+        assert_eq!(next_health(5, false), (true, 6));
+        assert_eq!(next_health(5, true), (false, 0));
+    }
+
+    #[test]
+    fn test_outcome_and_url_helpers() {
+        let outcome = FetchOutcome {
+            status_code: Some(301),
+            latency_ms: 40,
+            final_url: None,
+            error: None,
+        };
+        assert!(outcome.is_ok());
+        assert!(!FetchOutcome {
+            status_code: Some(404),
+            ..outcome.clone()
+        }
+        .is_ok());
+        assert!(!FetchOutcome {
+            status_code: None,
+            error: Some("timed out".to_string()),
+            ..outcome
+        }
+        .is_ok());
+
+        assert_eq!(request_url("acme.com/news"), "https://acme.com/news");
+        assert_eq!(request_url("http://acme.com"), "http://acme.com");
+    }
+
+    #[test]
+    fn test_is_public_ip() {
+        for ip in ["93.184.216.34", "2606:4700::1111"] {
+            assert!(is_public_ip(ip.parse().unwrap()), "{ip}");
+        }
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.8",
+            "172.16.4.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public_ip(ip.parse().unwrap()), "{ip}");
+        }
+    }
+}
@@ -0,0 +1,673 @@
+use crate::auth::middleware::{AdminUser, AuthUser};
+use crate::entities::{contact, conversation, interview, startup, tag, tag_assignment};
+use crate::{non_empty, parse_date_str, AppState};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use sea_orm::sea_query::{Expr, ExprTrait, Func, OnConflict, Query as SeaQuery};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, SqlErr, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Record types that can carry tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaggableEntity {
+    Startup,
+    Contact,
+    Interview,
+    Conversation,
+}
+
+impl TaggableEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaggableEntity::Startup => "startup",
+            TaggableEntity::Contact => "contact",
+            TaggableEntity::Interview => "interview",
+            TaggableEntity::Conversation => "conversation",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "startup" => Some(TaggableEntity::Startup),
+            "contact" => Some(TaggableEntity::Contact),
+            "interview" => Some(TaggableEntity::Interview),
+            "conversation" => Some(TaggableEntity::Conversation),
+            _ => None,
+        }
+    }
+
+    async fn count_existing<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        ids: Vec<Uuid>,
+    ) -> Result<u64, DbErr> {
+        match self {
+            TaggableEntity::Startup => {
+                startup::Entity::find()
+                    .filter(startup::Column::Id.is_in(ids))
+                    .count(db)
+                    .await
+            }
+            TaggableEntity::Contact => {
+                contact::Entity::find()
+                    .filter(contact::Column::Id.is_in(ids))
+                    .count(db)
+                    .await
+            }
+            TaggableEntity::Interview => {
+                interview::Entity::find()
+                    .filter(interview::Column::Id.is_in(ids))
+                    .count(db)
+                    .await
+            }
+            TaggableEntity::Conversation => {
+                conversation::Entity::find()
+                    .filter(conversation::Column::Id.is_in(ids))
+                    .count(db)
+                    .await
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct TagUsage {
+    pub startup: u64,
+    pub contact: u64,
+    pub interview: u64,
+    pub conversation: u64,
+    pub total: u64,
+}
+
+#[derive(Serialize)]
+pub struct TagResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub color: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+    pub usage: TagUsage,
+}
+
+#[derive(Deserialize, Default)]
+pub struct TagListQuery {
+    pub entity_type: Option<String>,
+    /// Only count assignments made on or after this date (YYYY-MM-DD).
+    pub from: Option<String>,
+    /// Only count assignments made on or before this date (YYYY-MM-DD).
+    pub to: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct TagRequest {
+    pub name: String,
+    pub color: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateTagRequest {
+    pub name: Option<String>,
+    pub color: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct BulkTagRequest {
+    pub entity_type: TaggableEntity,
+    pub entity_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub tag_ids: Vec<Uuid>,
+    /// Tags to apply by name; unknown names are created.
+    #[serde(default)]
+    pub tag_names: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct BulkTagResponse {
+    pub tags: Vec<tag::Model>,
+    pub affected: u64,
+}
+
+#[derive(Deserialize)]
+pub struct TagAssignmentsQuery {
+    pub entity_type: String,
+    /// Comma-separated record ids.
+    pub entity_ids: String,
+}
+
+pub async fn list_tags(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    query: Option<Query<TagListQuery>>,
+) -> Result<Json<Vec<TagResponse>>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+
+    let tags = tag::Entity::find()
+        .order_by_asc(tag::Column::Name)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut assignments = tag_assignment::Entity::find()
+        .select_only()
+        .column(tag_assignment::Column::TagId)
+        .column(tag_assignment::Column::EntityType);
+    if let Some(entity_type) = non_empty(params.entity_type.as_deref()) {
+        let entity = TaggableEntity::parse(entity_type).ok_or(StatusCode::BAD_REQUEST)?;
+        assignments = assignments.filter(tag_assignment::Column::EntityType.eq(entity.as_str()));
+    }
+    if let Some(from) = non_empty(params.from.as_deref()) {
+        let start = parse_date_str(from)?.and_hms_opt(0, 0, 0).unwrap();
+        assignments = assignments.filter(tag_assignment::Column::CreatedAt.gte(start));
+    }
+    if let Some(to) = non_empty(params.to.as_deref()) {
+        let end = parse_date_str(to)?.and_hms_opt(23, 59, 59).unwrap();
+        assignments = assignments.filter(tag_assignment::Column::CreatedAt.lte(end));
+    }
+
+    let rows: Vec<(Uuid, String)> = assignments
+        .into_tuple()
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut usage: HashMap<Uuid, TagUsage> = HashMap::new();
+    for (tag_id, entity_type) in rows {
+        let counts = usage.entry(tag_id).or_default();
+        match TaggableEntity::parse(&entity_type) {
+            Some(TaggableEntity::Startup) => counts.startup += 1,
+            Some(TaggableEntity::Contact) => counts.contact += 1,
+            Some(TaggableEntity::Interview) => counts.interview += 1,
+            Some(TaggableEntity::Conversation) => counts.conversation += 1,
+            None => continue,
+        }
+        counts.total += 1;
+    }
+
+    let response = tags
+        .into_iter()
+        .map(|model| TagResponse {
+            usage: usage.remove(&model.id).unwrap_or_default(),
+            id: model.id,
+            name: model.name,
+            color: model.color,
+            created_at: model.created_at,
+        })
+        .collect();
+
+    Ok(Json(response))
+}
+
+pub async fn create_tag(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    Json(payload): Json<TagRequest>,
+) -> Result<Json<tag::Model>, StatusCode> {
+    let name = payload.name.trim().to_string();
+    if name.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    if find_tag_by_name(&state.db, &name)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .is_some()
+    {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    let created = tag::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        name: Set(name),
+        color: Set(payload.color),
+        created_at: Set(Utc::now().naive_utc()),
+    }
+    .insert(&state.db)
+    .await
+    .map_err(tag_write_error)?;
+
+    Ok(Json(created))
+}
+
+/// A concurrent request may take the name between the lookup and the write;
+/// the unique index turns that into a 409 rather than a 500.
+fn tag_write_error(err: DbErr) -> StatusCode {
+    match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => StatusCode::CONFLICT,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+pub async fn update_tag(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateTagRequest>,
+) -> Result<Json<tag::Model>, StatusCode> {
+    let existing = tag::Entity::find_by_id(id)
+        .one(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let mut active: tag::ActiveModel = existing.into();
+    if let Some(name) = payload.name {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        let clash = find_tag_by_name(&state.db, &name)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        if clash.map(|other| other.id != id).unwrap_or(false) {
+            return Err(StatusCode::CONFLICT);
+        }
+        active.name = Set(name);
+    }
+    if let Some(color) = payload.color {
+        active.color = Set(Some(color));
+    }
+
+    let updated = active.update(&state.db).await.map_err(tag_write_error)?;
+
+    Ok(Json(updated))
+}
+
+pub async fn delete_tag(
+    State(state): State<AppState>,
+    AdminUser(_admin): AdminUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let result = tag::Entity::delete_by_id(id)
+        .exec(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if result.rows_affected == 0 {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/tags/assign
+/// Apply every listed tag to every listed record. Already-applied tags are
+/// left alone.
+pub async fn bulk_assign_tags(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Json(payload): Json<BulkTagRequest>,
+) -> Result<Json<BulkTagResponse>, StatusCode> {
+    let entity_ids = unique_ids(payload.entity_ids);
+    if entity_ids.is_empty() || (payload.tag_ids.is_empty() && payload.tag_names.is_empty()) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    ensure_entities_exist(&txn, payload.entity_type, &entity_ids).await?;
+
+    let mut tags = tag::Entity::find()
+        .filter(tag::Column::Id.is_in(unique_ids(payload.tag_ids.clone())))
+        .all(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if tags.len() != unique_ids(payload.tag_ids).len() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    for name in payload.tag_names {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            continue;
+        }
+        let model = find_or_create_tag(&txn, &name)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        if !tags.iter().any(|existing| existing.id == model.id) {
+            tags.push(model);
+        }
+    }
+
+    let now = Utc::now().naive_utc();
+    let rows: Vec<tag_assignment::ActiveModel> = tags
+        .iter()
+        .flat_map(|model| {
+            entity_ids
+                .iter()
+                .map(move |entity_id| tag_assignment::ActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    tag_id: Set(model.id),
+                    entity_type: Set(payload.entity_type.as_str().to_string()),
+                    entity_id: Set(*entity_id),
+                    created_by: Set(Some(user.id)),
+                    created_at: Set(now),
+                })
+        })
+        .collect();
+
+    let affected = if rows.is_empty() {
+        0
+    } else {
+        tag_assignment::Entity::insert_many(rows)
+            .on_conflict(
+                OnConflict::columns([
+                    tag_assignment::Column::TagId,
+                    tag_assignment::Column::EntityType,
+                    tag_assignment::Column::EntityId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(&txn)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    };
+
+    txn.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(BulkTagResponse { tags, affected }))
+}
+
+/// POST /api/tags/unassign
+pub async fn bulk_remove_tags(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    Json(payload): Json<BulkTagRequest>,
+) -> Result<Json<BulkTagResponse>, StatusCode> {
+    let entity_ids = unique_ids(payload.entity_ids);
+    if entity_ids.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let mut tag_filter = Condition::any();
+    if !payload.tag_ids.is_empty() {
+        tag_filter = tag_filter.add(tag::Column::Id.is_in(unique_ids(payload.tag_ids)));
+    }
+    for name in payload
+        .tag_names
+        .iter()
+        .map(|n| n.trim())
+        .filter(|n| !n.is_empty())
+    {
+        tag_filter = tag_filter.add(lower_name_eq(name));
+    }
+    if tag_filter.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let tags = tag::Entity::find()
+        .filter(tag_filter)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let result = tag_assignment::Entity::delete_many()
+        .filter(tag_assignment::Column::EntityType.eq(payload.entity_type.as_str()))
+        .filter(tag_assignment::Column::EntityId.is_in(entity_ids))
+        .filter(tag_assignment::Column::TagId.is_in(tags.iter().map(|t| t.id)))
+        .exec(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(BulkTagResponse {
+        tags,
+        affected: result.rows_affected,
+    }))
+}
+
+/// GET /api/tags/assignments?entity_type=startup&entity_ids=a,b
+/// Tags on each of the given records, keyed by record id.
+pub async fn list_tag_assignments(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    Query(params): Query<TagAssignmentsQuery>,
+) -> Result<Json<HashMap<Uuid, Vec<tag::Model>>>, StatusCode> {
+    let entity = TaggableEntity::parse(&params.entity_type).ok_or(StatusCode::BAD_REQUEST)?;
+    let ids = params
+        .entity_ids
+        .split(',')
+        .filter(|value| !value.trim().is_empty())
+        .map(|value| Uuid::parse_str(value.trim()).map_err(|_| StatusCode::BAD_REQUEST))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let rows = tag_assignment::Entity::find()
+        .filter(tag_assignment::Column::EntityType.eq(entity.as_str()))
+        .filter(tag_assignment::Column::EntityId.is_in(ids.clone()))
+        .find_also_related(tag::Entity)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut by_entity: HashMap<Uuid, Vec<tag::Model>> =
+        ids.into_iter().map(|id| (id, Vec::new())).collect();
+    for (assignment, model) in rows {
+        if let Some(model) = model {
+            by_entity
+                .entry(assignment.entity_id)
+                .or_default()
+                .push(model);
+        }
+    }
+    for tags in by_entity.values_mut() {
+        tags.sort_by_key(|tag| tag.name.to_lowercase());
+    }
+
+    Ok(Json(by_entity))
+}
+
+/// Filter for list endpoints: `raw` is a comma-separated list of tag ids or
+/// names (case-insensitive) and a record must carry all of them.
+pub fn tag_filter<C: ColumnTrait>(column: C, entity: TaggableEntity, raw: &str) -> Condition {
+    let mut condition = Condition::all();
+
+    for token in raw.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let matches_tag = match Uuid::parse_str(token) {
+            Ok(id) => Expr::col((tag::Entity, tag::Column::Id)).eq(id),
+            Err(_) => lower_name_eq(token),
+        };
+
+        condition = condition.add(
+            column.in_subquery(
+                SeaQuery::select()
+                    .column((tag_assignment::Entity, tag_assignment::Column::EntityId))
+                    .from(tag_assignment::Entity)
+                    .inner_join(
+                        tag::Entity,
+                        Expr::col((tag::Entity, tag::Column::Id))
+                            .equals((tag_assignment::Entity, tag_assignment::Column::TagId)),
+                    )
+                    .and_where(
+                        Expr::col((tag_assignment::Entity, tag_assignment::Column::EntityType))
+                            .eq(entity.as_str()),
+                    )
+                    .and_where(matches_tag)
+                    .to_owned(),
+            ),
+        );
+    }
+
+    condition
+}
+
+/// How many tag assignments the records have.
+pub async fn count_tag_assignments<C: ConnectionTrait>(
+    db: &C,
+    entity: TaggableEntity,
+    ids: Vec<Uuid>,
+) -> Result<u64, DbErr> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    tag_assignment::Entity::find()
+        .filter(tag_assignment::Column::EntityType.eq(entity.as_str()))
+        .filter(tag_assignment::Column::EntityId.is_in(ids))
+        .count(db)
+        .await
+}
+
+/// Drop the tags of records that are being permanently deleted.
+pub async fn delete_tag_assignments<C: ConnectionTrait>(
+    db: &C,
+    entity: TaggableEntity,
+    ids: Vec<Uuid>,
+) -> Result<u64, DbErr> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    let result = tag_assignment::Entity::delete_many()
+        .filter(tag_assignment::Column::EntityType.eq(entity.as_str()))
+        .filter(tag_assignment::Column::EntityId.is_in(ids))
+        .exec(db)
+        .await?;
+
+    Ok(result.rows_affected)
+}
+
+/// Move tags from one record to another, e.g. when merging duplicates.
+/// Tags the target already has are dropped from the source.
+pub async fn move_tag_assignments<C: ConnectionTrait>(
+    db: &C,
+    entity: TaggableEntity,
+    from: Uuid,
+    to: Uuid,
+) -> Result<u64, DbErr> {
+    let target_tags: Vec<Uuid> = tag_assignment::Entity::find()
+        .select_only()
+        .column(tag_assignment::Column::TagId)
+        .filter(tag_assignment::Column::EntityType.eq(entity.as_str()))
+        .filter(tag_assignment::Column::EntityId.eq(to))
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    tag_assignment::Entity::delete_many()
+        .filter(tag_assignment::Column::EntityType.eq(entity.as_str()))
+        .filter(tag_assignment::Column::EntityId.eq(from))
+        .filter(tag_assignment::Column::TagId.is_in(target_tags))
+        .exec(db)
+        .await?;
+
+    let moved = tag_assignment::Entity::update_many()
+        .col_expr(tag_assignment::Column::EntityId, Expr::value(to))
+        .filter(tag_assignment::Column::EntityType.eq(entity.as_str()))
+        .filter(tag_assignment::Column::EntityId.eq(from))
+        .exec(db)
+        .await?;
+
+    Ok(moved.rows_affected)
+}
+
+fn lower_name_eq(name: &str) -> Expr {
+    Expr::expr(Func::lower(Expr::col((tag::Entity, tag::Column::Name)))).eq(name.to_lowercase())
+}
+
+pub fn unique_ids(ids: Vec<Uuid>) -> Vec<Uuid> {
+    let mut seen = HashSet::new();
+    ids.into_iter().filter(|id| seen.insert(*id)).collect()
+}
+
+async fn ensure_entities_exist<C: ConnectionTrait>(
+    db: &C,
+    entity: TaggableEntity,
+    ids: &[Uuid],
+) -> Result<(), StatusCode> {
+    let found = entity
+        .count_existing(db, ids.to_vec())
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if found as usize != ids.len() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    Ok(())
+}
+
+pub async fn find_tag_by_name<C: ConnectionTrait>(
+    db: &C,
+    name: &str,
+) -> Result<Option<tag::Model>, DbErr> {
+    tag::Entity::find()
+        .filter(lower_name_eq(name))
+        .one(db)
+        .await
+}
+
+/// Insert-or-ignore against the `lower(name)` unique index, so a concurrent
+/// request creating the same tag cannot fail this one (or abort its
+/// transaction); whichever insert won is read back.
+pub async fn find_or_create_tag<C: ConnectionTrait>(
+    db: &C,
+    name: &str,
+) -> Result<tag::Model, DbErr> {
+    if let Some(existing) = find_tag_by_name(db, name).await? {
+        return Ok(existing);
+    }
+
+    tag::Entity::insert(tag::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        name: Set(name.to_string()),
+        color: Set(None),
+        created_at: Set(Utc::now().naive_utc()),
+    })
+    .on_conflict(
+        OnConflict::new()
+            .expr(Func::lower(Expr::col(tag::Column::Name)))
+            .do_nothing()
+            .to_owned(),
+    )
+    .exec_without_returning(db)
+    .await?;
+
+    find_tag_by_name(db, name)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound(format!("tag {name}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::sea_query::PostgresQueryBuilder;
+    use sea_orm::QueryTrait;
+
+    #[test]
+    fn test_unique_ids_keeps_first_occurrence() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        assert_eq!(unique_ids(vec![a, b, a, b]), vec![a, b]);
+    }
+
+    #[test]
+    fn test_tag_filter_requires_every_tag() {
+        let id = Uuid::new_v4();
+        let raw = format!("Fintech, {id},,");
+        let sql = startup::Entity::find()
+            .filter(tag_filter(
+                startup::Column::Id,
+                TaggableEntity::Startup,
+                &raw,
+            ))
+            .into_query()
+            .to_string(PostgresQueryBuilder);
+
+        assert_eq!(sql.matches("IN (SELECT").count(), 2);
+        assert!(sql.contains("LOWER(\"tag\".\"name\") = 'fintech'"));
+        assert!(sql.contains(&id.to_string()));
+        assert!(sql.contains("\"entity_type\" = 'startup'"));
+    }
+}
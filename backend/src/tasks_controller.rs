@@ -0,0 +1,511 @@
+use crate::auth::middleware::AuthUser;
+use crate::entities::{contact, conversation, interview, startup, task, user};
+use crate::owners_controller::find_assignable_owner;
+use crate::{
+    compute_week_end, non_empty, normalize_to_week_start, parse_date_str, record_activity_event,
+    user_display_name, ActivityEventInput, AppState, ACTIVITY_TASK_COMPLETED,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub const TASK_STATUS_OPEN: &str = "open";
+pub const TASK_STATUS_DONE: &str = "done";
+pub const TASK_SOURCE_MANUAL: &str = "manual";
+pub const TASK_SOURCE_STALE_FOLLOW_UP: &str = "stale_follow_up";
+
+#[derive(Deserialize, Default)]
+pub struct TaskListQuery {
+    pub status: Option<String>,
+    pub assignee_id: Option<Uuid>,
+    /// Only tasks assigned to the caller.
+    pub mine: Option<bool>,
+    /// `today` or `week`; only open tasks are returned.
+    pub due: Option<String>,
+    pub startup_id: Option<Uuid>,
+    pub contact_id: Option<Uuid>,
+    pub interview_id: Option<Uuid>,
+    pub conversation_id: Option<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateTaskRequest {
+    pub title: String,
+    pub due_date: String,
+    /// Defaults to the caller.
+    pub assignee_id: Option<Uuid>,
+    pub startup_id: Option<Uuid>,
+    pub contact_id: Option<Uuid>,
+    pub interview_id: Option<Uuid>,
+    pub conversation_id: Option<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateTaskRequest {
+    pub title: Option<String>,
+    pub due_date: Option<String>,
+    pub assignee_id: Option<Uuid>,
+    pub status: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct OverdueTaskQuery {
+    pub assignee_id: Option<Uuid>,
+    /// Only tasks assigned to the caller.
+    pub mine: Option<bool>,
+}
+
+#[derive(Serialize)]
+pub struct TaskResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub due_date: NaiveDate,
+    pub status: String,
+    pub source: String,
+    pub assignee_id: Option<Uuid>,
+    pub assignee_name: Option<String>,
+    pub startup_id: Option<Uuid>,
+    pub startup_name: Option<String>,
+    pub contact_id: Option<Uuid>,
+    pub contact_name: Option<String>,
+    pub interview_id: Option<Uuid>,
+    pub conversation_id: Option<Uuid>,
+    pub created_by_id: Option<Uuid>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Inclusive due-date bounds for the `due` filter: `today`, or the rest of
+/// the current week (through Sunday).
+fn due_window(due: &str, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+    match due {
+        "today" => Some((today, today)),
+        "week" => Some((today, compute_week_end(normalize_to_week_start(today)))),
+        _ => None,
+    }
+}
+
+/// Assignees, creators and admins can change a task; unassigned tasks are
+/// open to everyone.
+fn can_edit_task(user: &user::Model, task: &task::Model) -> bool {
+    user.is_admin()
+        || task.assignee_id.is_none()
+        || task.assignee_id == Some(user.id)
+        || task.created_by_id == Some(user.id)
+}
+
+/// Attach assignee, startup and contact names, loading each kind in one query.
+pub async fn task_responses<C: ConnectionTrait>(
+    db: &C,
+    tasks: Vec<task::Model>,
+) -> Result<Vec<TaskResponse>, DbErr> {
+    let assignee_ids: Vec<Uuid> = tasks.iter().filter_map(|task| task.assignee_id).collect();
+    let startup_ids: Vec<Uuid> = tasks.iter().filter_map(|task| task.startup_id).collect();
+    let contact_ids: Vec<Uuid> = tasks.iter().filter_map(|task| task.contact_id).collect();
+
+    let assignees: HashMap<Uuid, String> = user::Entity::find()
+        .filter(user::Column::Id.is_in(assignee_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, user_display_name(&user)))
+        .collect();
+    let startups: HashMap<Uuid, String> = startup::Entity::find()
+        .filter(startup::Column::Id.is_in(startup_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|startup| (startup.id, startup.name))
+        .collect();
+    let contacts: HashMap<Uuid, String> = contact::Entity::find()
+        .filter(contact::Column::Id.is_in(contact_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|contact| (contact.id, contact.name))
+        .collect();
+
+    Ok(tasks
+        .into_iter()
+        .map(|task| TaskResponse {
+            assignee_name: task.assignee_id.and_then(|id| assignees.get(&id).cloned()),
+            startup_name: task.startup_id.and_then(|id| startups.get(&id).cloned()),
+            contact_name: task.contact_id.and_then(|id| contacts.get(&id).cloned()),
+            id: task.id,
+            title: task.title,
+            due_date: task.due_date,
+            status: task.status,
+            source: task.source,
+            assignee_id: task.assignee_id,
+            startup_id: task.startup_id,
+            contact_id: task.contact_id,
+            interview_id: task.interview_id,
+            conversation_id: task.conversation_id,
+            created_by_id: task.created_by_id,
+            completed_at: task.completed_at,
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+        })
+        .collect())
+}
+
+/// GET /api/tasks/overdue
+/// Open tasks due today or earlier, oldest first.
+pub async fn list_overdue_tasks(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    query: Option<Query<OverdueTaskQuery>>,
+) -> Result<Json<Vec<TaskResponse>>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    let today = Utc::now().date_naive();
+
+    let mut filters = Condition::all()
+        .add(task::Column::Status.eq(TASK_STATUS_OPEN))
+        .add(task::Column::DueDate.lte(today));
+    if params.mine.unwrap_or(false) {
+        filters = filters.add(task::Column::AssigneeId.eq(user.id));
+    } else if let Some(assignee_id) = params.assignee_id {
+        filters = filters.add(task::Column::AssigneeId.eq(assignee_id));
+    }
+
+    let tasks = task::Entity::find()
+        .filter(filters)
+        .order_by_asc(task::Column::DueDate)
+        .order_by_asc(task::Column::CreatedAt)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let responses = task_responses(&state.db, tasks)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(responses))
+}
+
+/// GET /api/tasks
+pub async fn list_tasks(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    query: Option<Query<TaskListQuery>>,
+) -> Result<Json<Vec<TaskResponse>>, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    let mut filters = Condition::all();
+
+    if let Some(due) = non_empty(params.due.as_deref()) {
+        let (from, to) = due_window(due, Utc::now().date_naive()).ok_or(StatusCode::BAD_REQUEST)?;
+        filters = filters
+            .add(task::Column::Status.eq(TASK_STATUS_OPEN))
+            .add(task::Column::DueDate.between(from, to));
+    } else if let Some(status) = non_empty(params.status.as_deref()) {
+        if status != TASK_STATUS_OPEN && status != TASK_STATUS_DONE {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        filters = filters.add(task::Column::Status.eq(status));
+    }
+    if params.mine.unwrap_or(false) {
+        filters = filters.add(task::Column::AssigneeId.eq(user.id));
+    } else if let Some(assignee_id) = params.assignee_id {
+        filters = filters.add(task::Column::AssigneeId.eq(assignee_id));
+    }
+    if let Some(startup_id) = params.startup_id {
+        filters = filters.add(task::Column::StartupId.eq(startup_id));
+    }
+    if let Some(contact_id) = params.contact_id {
+        filters = filters.add(task::Column::ContactId.eq(contact_id));
+    }
+    if let Some(interview_id) = params.interview_id {
+        filters = filters.add(task::Column::InterviewId.eq(interview_id));
+    }
+    if let Some(conversation_id) = params.conversation_id {
+        filters = filters.add(task::Column::ConversationId.eq(conversation_id));
+    }
+
+    let tasks = task::Entity::find()
+        .filter(filters)
+        .order_by_asc(task::Column::DueDate)
+        .order_by_asc(task::Column::CreatedAt)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let responses = task_responses(&state.db, tasks)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(responses))
+}
+
+/// GET /api/tasks/:id
+pub async fn get_task(
+    State(state): State<AppState>,
+    _auth_user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TaskResponse>, StatusCode> {
+    let task = find_task(&state.db, id).await?;
+    single_response(&state.db, task).await
+}
+
+/// POST /api/tasks
+/// Linking a contact, interview or conversation also links its startup;
+/// links that point at different startups are rejected.
+pub async fn create_task(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Json(payload): Json<CreateTaskRequest>,
+) -> Result<Json<TaskResponse>, StatusCode> {
+    let title = non_empty(Some(&payload.title))
+        .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?
+        .to_string();
+    let due_date = parse_date_str(&payload.due_date)?;
+    let assignee_id = match payload.assignee_id {
+        Some(assignee_id) => find_assignable_owner(&state.db, assignee_id).await?.id,
+        None => user.id,
+    };
+
+    let mut startup_id = payload.startup_id;
+    let mut link_startup = |linked: Option<Uuid>| -> Result<(), StatusCode> {
+        match (startup_id, linked) {
+            (Some(existing), Some(linked)) if existing != linked => {
+                Err(StatusCode::UNPROCESSABLE_ENTITY)
+            }
+            (None, linked) => {
+                startup_id = linked;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    };
+
+    if let Some(contact_id) = payload.contact_id {
+        let contact = contact::Entity::find_by_id(contact_id)
+            .filter(contact::Column::IsTrashed.eq(false))
+            .one(&state.db)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+        link_startup(Some(contact.startup_id))?;
+    }
+    if let Some(interview_id) = payload.interview_id {
+        let interview = interview::Entity::find_by_id(interview_id)
+            .one(&state.db)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+        link_startup(Some(interview.startup_id))?;
+    }
+    if let Some(conversation_id) = payload.conversation_id {
+        let conversation = conversation::Entity::find_by_id(conversation_id)
+            .one(&state.db)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .filter(|conversation| conversation.user_id == user.id || user.is_admin())
+            .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+        link_startup(conversation.startup_id)?;
+    }
+    if let Some(startup_id) = startup_id {
+        startup::Entity::find_by_id(startup_id)
+            .filter(startup::Column::IsTrashed.eq(false))
+            .one(&state.db)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    }
+
+    let now = Utc::now().naive_utc();
+    let task = task::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        title: Set(title),
+        due_date: Set(due_date),
+        assignee_id: Set(Some(assignee_id)),
+        status: Set(TASK_STATUS_OPEN.to_string()),
+        source: Set(TASK_SOURCE_MANUAL.to_string()),
+        startup_id: Set(startup_id),
+        contact_id: Set(payload.contact_id),
+        interview_id: Set(payload.interview_id),
+        conversation_id: Set(payload.conversation_id),
+        created_by_id: Set(Some(user.id)),
+        completed_at: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(&state.db)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    single_response(&state.db, task).await
+}
+
+/// PUT /api/tasks/:id
+/// Setting `status` to `done` completes the task and records a
+/// `task_completed` activity event; `open` reopens it.
+pub async fn update_task(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateTaskRequest>,
+) -> Result<Json<TaskResponse>, StatusCode> {
+    let existing = find_task(&state.db, id).await?;
+    if !can_edit_task(&user, &existing) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let was_open = existing.status == TASK_STATUS_OPEN;
+    let mut active: task::ActiveModel = existing.into();
+    if let Some(title) = payload.title {
+        let title = non_empty(Some(&title)).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+        active.title = Set(title.to_string());
+    }
+    if let Some(due_date) = payload.due_date {
+        active.due_date = Set(parse_date_str(&due_date)?);
+    }
+    if let Some(assignee_id) = payload.assignee_id {
+        active.assignee_id = Set(Some(
+            find_assignable_owner(&state.db, assignee_id).await?.id,
+        ));
+    }
+    let now = Utc::now().naive_utc();
+    let completing = match payload.status.as_deref() {
+        None => false,
+        Some(TASK_STATUS_DONE) => {
+            if was_open {
+                active.status = Set(TASK_STATUS_DONE.to_string());
+                active.completed_at = Set(Some(now));
+            }
+            was_open
+        }
+        Some(TASK_STATUS_OPEN) => {
+            active.status = Set(TASK_STATUS_OPEN.to_string());
+            active.completed_at = Set(None);
+            false
+        }
+        Some(_) => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+    };
+    active.updated_at = Set(now);
+
+    let updated = active
+        .update(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if completing {
+        if let Err(err) = record_task_completion(&state.db, &updated, &user).await {
+            tracing::warn!(error = ?err, "failed to record task completion activity");
+        }
+    }
+
+    single_response(&state.db, updated).await
+}
+
+/// DELETE /api/tasks/:id
+pub async fn delete_task(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let existing = find_task(&state.db, id).await?;
+    if !can_edit_task(&user, &existing) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    task::Entity::delete_by_id(existing.id)
+        .exec(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn find_task<C: ConnectionTrait>(db: &C, id: Uuid) -> Result<task::Model, StatusCode> {
+    task::Entity::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn single_response<C: ConnectionTrait>(
+    db: &C,
+    task: task::Model,
+) -> Result<Json<TaskResponse>, StatusCode> {
+    task_responses(db, vec![task])
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .pop()
+        .map(Json)
+        .ok_or(StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+async fn record_task_completion<C: ConnectionTrait>(
+    db: &C,
+    task: &task::Model,
+    actor: &user::Model,
+) -> Result<(), DbErr> {
+    let startup_name = match task.startup_id {
+        Some(startup_id) => startup::Entity::find_by_id(startup_id)
+            .one(db)
+            .await?
+            .map(|startup| startup.name),
+        None => None,
+    };
+    let contact_name = match task.contact_id {
+        Some(contact_id) => contact::Entity::find_by_id(contact_id)
+            .one(db)
+            .await?
+            .map(|contact| contact.name),
+        None => None,
+    };
+
+    record_activity_event(
+        db,
+        ActivityEventInput {
+            activity_type: ACTIVITY_TASK_COMPLETED,
+            description: format!("Completed task: {}", task.title),
+            user_id: Some(actor.id),
+            user_name: Some(user_display_name(actor)),
+            startup_id: task.startup_id,
+            startup_name,
+            contact_id: task.contact_id,
+            contact_name,
+            stage_from: None,
+            stage_to: None,
+            metadata: Some(json!({
+                "task_id": task.id,
+                "source": task.source,
+                "due_date": task.due_date,
+            })),
+            occurred_at: None,
+        },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_due_window() {
+        // A Wednesday.
+        let today = NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+
+        assert_eq!(due_window("today", today), Some((today, today)));
+        assert_eq!(
+            due_window("week", today),
+            Some((today, NaiveDate::from_ymd_opt(2025, 5, 4).unwrap()))
+        );
+        assert_eq!(due_window("month", today), None);
+    }
+}
@@ -0,0 +1,908 @@
+use crate::auth::middleware::AuthUser;
+use crate::contact_identities_controller::{
+    normalize_email, normalize_identity, sync_primary_identities, IdentityKind,
+};
+use crate::entities::{contact, contact_identity, startup, user};
+use crate::history_controller::record_contact_revisions;
+use crate::import_controller::{default_dry_run, ImportAction, DEFAULT_CONTACT_ROLE};
+use crate::{
+    can_edit_contact, fetch_contacts, find_active_startup, record_activity_event,
+    user_display_name, ActivityEventInput, AppState, ContactListQuery, ContactResponse,
+    ACTIVITY_CONTACT_CREATED,
+};
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::Response,
+    Json,
+};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+const MAX_VCARD_ENTRIES: usize = 1000;
+/// vCard lines are folded at 75 octets.
+const VCARD_LINE_LIMIT: usize = 75;
+
+/// A typed email address or phone number from a card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VCardValue {
+    pub value: String,
+    /// The first descriptive `TYPE`, e.g. `work` or `cell`.
+    pub label: Option<String>,
+    pub preferred: bool,
+}
+
+/// The parts of a vCard that map onto a contact.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VCard {
+    pub name: Option<String>,
+    pub emails: Vec<VCardValue>,
+    pub phones: Vec<VCardValue>,
+    pub org: Option<String>,
+    pub title: Option<String>,
+    pub urls: Vec<String>,
+    pub note: Option<String>,
+}
+
+impl VCard {
+    /// The preferred entry, or the first one.
+    fn primary(values: &[VCardValue]) -> Option<&VCardValue> {
+        values
+            .iter()
+            .find(|value| value.preferred)
+            .or_else(|| values.first())
+    }
+
+    fn linkedin_url(&self) -> Option<&String> {
+        self.urls
+            .iter()
+            .find(|url| url.to_lowercase().contains("linkedin.com/"))
+    }
+}
+
+/// Splits on `;` not preceded by a backslash, unescaping each component.
+fn split_components(value: &str) -> Vec<String> {
+    let mut components = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some('n') | Some('N') => current.push('\n'),
+                Some(other) => current.push(other),
+                None => {}
+            },
+            ';' => components.push(std::mem::take(&mut current)),
+            _ => current.push(ch),
+        }
+    }
+    components.push(current);
+    components
+}
+
+fn unescape(value: &str) -> String {
+    split_components(value).join(";")
+}
+
+fn non_empty_owned(value: String) -> Option<String> {
+    let trimmed = value.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// One unfolded `NAME;PARAM=VALUE:value` line.
+struct ContentLine {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+/// Splits a content line; the group prefix (`item1.EMAIL`) is dropped and
+/// names and parameter keys are uppercased.
+fn parse_line(line: &str) -> Option<ContentLine> {
+    let mut in_quotes = false;
+    let split = line.char_indices().find(|(_, ch)| {
+        if *ch == '"' {
+            in_quotes = !in_quotes;
+        }
+        *ch == ':' && !in_quotes
+    })?;
+    let (head, value) = (&line[..split.0], &line[split.0 + 1..]);
+
+    let mut parts = head.split(';');
+    let name = parts.next()?;
+    let name = name
+        .rsplit('.')
+        .next()
+        .unwrap_or(name)
+        .trim()
+        .to_uppercase();
+    let params = parts
+        .map(|param| match param.split_once('=') {
+            Some((key, value)) => (key.trim().to_uppercase(), value.trim().to_string()),
+            // vCard 2.1 style bare types, e.g. `TEL;CELL`.
+            None => ("TYPE".to_string(), param.trim().to_string()),
+        })
+        .collect();
+
+    Some(ContentLine {
+        name,
+        params,
+        value: value.to_string(),
+    })
+}
+
+fn typed_value(params: &[(String, String)], value: String) -> VCardValue {
+    let types: Vec<String> = params
+        .iter()
+        .filter(|(key, _)| key == "TYPE")
+        .flat_map(|(_, value)| value.trim_matches('"').split(',').map(str::to_lowercase))
+        .collect();
+    let preferred = types.iter().any(|kind| kind == "pref")
+        || params
+            .iter()
+            .any(|(key, value)| key == "PREF" && value.trim() == "1");
+    let label = types
+        .into_iter()
+        .find(|kind| !matches!(kind.as_str(), "pref" | "internet" | "voice" | "x400"));
+
+    VCardValue {
+        value,
+        label,
+        preferred,
+    }
+}
+
+/// Parses one or more vCard 3.0 or 4.0 entries.
+pub fn parse_vcards(input: &str) -> Result<Vec<VCard>, String> {
+    // Unfold continuation lines (starting with a space or tab).
+    let mut lines: Vec<String> = Vec::new();
+    for raw in input.lines() {
+        let raw = raw.trim_end_matches('\r');
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(previous)) => previous.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+
+    let mut cards = Vec::new();
+    let mut current: Option<(VCard, Option<String>)> = None;
+    let mut family_given: Option<String> = None;
+
+    for (number, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Some(ContentLine {
+            name,
+            params,
+            value,
+        }) = parse_line(line)
+        else {
+            if current.is_some() {
+                return Err(format!("line {}: malformed property", number + 1));
+            }
+            continue;
+        };
+
+        if name == "BEGIN" && value.trim().eq_ignore_ascii_case("VCARD") {
+            if current.is_some() {
+                return Err(format!("line {}: nested BEGIN:VCARD", number + 1));
+            }
+            current = Some((VCard::default(), None));
+            family_given = None;
+            continue;
+        }
+        if name == "END" && value.trim().eq_ignore_ascii_case("VCARD") {
+            let Some((mut card, version)) = current.take() else {
+                return Err(format!("line {}: END:VCARD without BEGIN", number + 1));
+            };
+            match version.as_deref() {
+                Some("3.0") | Some("4.0") => {}
+                Some(other) => return Err(format!("unsupported vCard version {other}")),
+                None => return Err(format!("line {}: vCard without VERSION", number + 1)),
+            }
+            if card.name.is_none() {
+                card.name = family_given.take();
+            }
+            cards.push(card);
+            continue;
+        }
+
+        match (name.as_str(), current.as_mut()) {
+            (_, None) => {}
+            ("VERSION", Some((_, version))) => *version = Some(value.trim().to_string()),
+            ("FN", Some((card, _))) => card.name = non_empty_owned(unescape(&value)),
+            ("N", Some(_)) => {
+                // Family; Given; Additional; Prefix; Suffix
+                let parts = split_components(&value);
+                let name = [parts.get(1), parts.get(2), parts.first()]
+                    .into_iter()
+                    .flatten()
+                    .map(|part| part.trim())
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                family_given = non_empty_owned(name);
+            }
+            ("EMAIL", Some((card, _))) => {
+                if let Some(email) = non_empty_owned(unescape(&value)) {
+                    card.emails.push(typed_value(&params, email));
+                }
+            }
+            ("TEL", Some((card, _))) => {
+                let value = unescape(&value);
+                let value = value
+                    .strip_prefix("tel:")
+                    .or_else(|| value.strip_prefix("TEL:"))
+                    .unwrap_or(&value)
+                    .to_string();
+                if let Some(phone) = non_empty_owned(value) {
+                    card.phones.push(typed_value(&params, phone));
+                }
+            }
+            ("ORG", Some((card, _))) => {
+                card.org = split_components(&value)
+                    .into_iter()
+                    .find_map(non_empty_owned);
+            }
+            ("TITLE", Some((card, _))) => card.title = non_empty_owned(unescape(&value)),
+            ("ROLE", Some((card, _))) if card.title.is_none() => {
+                card.title = non_empty_owned(unescape(&value))
+            }
+            ("URL", Some((card, _))) => {
+                if let Some(url) = non_empty_owned(unescape(&value)) {
+                    card.urls.push(url);
+                }
+            }
+            ("NOTE", Some((card, _))) => card.note = non_empty_owned(unescape(&value)),
+            _ => {}
+        }
+    }
+
+    if current.is_some() {
+        return Err("missing END:VCARD".to_string());
+    }
+    Ok(cards)
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+}
+
+/// Appends `line` folded at 75 octets, never inside a UTF-8 character.
+fn push_folded(out: &mut String, line: &str) {
+    let mut width = 0;
+    for ch in line.chars() {
+        let len = ch.len_utf8();
+        if width + len > VCARD_LINE_LIMIT {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(ch);
+        width += len;
+    }
+    out.push_str("\r\n");
+}
+
+fn typed_property(name: &str, identity: &contact_identity::Model) -> String {
+    let mut types = Vec::new();
+    if name == "EMAIL" {
+        types.push("INTERNET".to_string());
+    }
+    if let Some(label) = identity.label.as_deref().filter(|label| {
+        !label.is_empty()
+            && label
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+    }) {
+        types.push(label.to_uppercase());
+    }
+    if identity.is_primary {
+        types.push("PREF".to_string());
+    }
+
+    if types.is_empty() {
+        format!("{name}:{}", escape(&identity.value))
+    } else {
+        format!(
+            "{name};TYPE={}:{}",
+            types.join(","),
+            escape(&identity.value)
+        )
+    }
+}
+
+/// A vCard 3.0 entry for a contact, its identities and startup.
+pub fn write_vcard(
+    contact: &ContactResponse,
+    startup_name: Option<&str>,
+    identities: &[contact_identity::Model],
+) -> String {
+    let mut out = String::new();
+    let mut words: Vec<&str> = contact.name.split_whitespace().collect();
+    let family = if words.len() > 1 {
+        words.pop().unwrap_or_default()
+    } else {
+        ""
+    };
+
+    for line in [
+        "BEGIN:VCARD".to_string(),
+        "VERSION:3.0".to_string(),
+        format!("UID:urn:uuid:{}", contact.id),
+        format!("FN:{}", escape(&contact.name)),
+        format!("N:{};{};;;", escape(family), escape(&words.join(" "))),
+    ] {
+        push_folded(&mut out, &line);
+    }
+    if let Some(startup_name) = startup_name {
+        push_folded(&mut out, &format!("ORG:{}", escape(startup_name)));
+    }
+    if !contact.role.trim().is_empty() && contact.role != DEFAULT_CONTACT_ROLE {
+        push_folded(&mut out, &format!("TITLE:{}", escape(&contact.role)));
+    }
+
+    let emails: Vec<&contact_identity::Model> = identities
+        .iter()
+        .filter(|identity| identity.kind == IdentityKind::Email.as_str())
+        .collect();
+    let phones: Vec<&contact_identity::Model> = identities
+        .iter()
+        .filter(|identity| identity.kind == IdentityKind::Phone.as_str())
+        .collect();
+    // Identities are kept in step with the contact row; fall back to it in
+    // case they were never synced.
+    if emails.is_empty() {
+        if let Some(email) = &contact.email {
+            push_folded(&mut out, &format!("EMAIL;TYPE=INTERNET:{}", escape(email)));
+        }
+    }
+    for identity in emails {
+        push_folded(&mut out, &typed_property("EMAIL", identity));
+    }
+    if phones.is_empty() {
+        if let Some(phone) = &contact.phone {
+            push_folded(&mut out, &format!("TEL:{}", escape(phone)));
+        }
+    }
+    for identity in phones {
+        push_folded(&mut out, &typed_property("TEL", identity));
+    }
+
+    if let Some(url) = &contact.linkedin_url {
+        push_folded(&mut out, &format!("URL:{}", escape(url)));
+    }
+    if let Some(notes) = contact.notes.as_deref().filter(|notes| !notes.is_empty()) {
+        push_folded(&mut out, &format!("NOTE:{}", escape(notes)));
+    }
+    push_folded(&mut out, "END:VCARD");
+    out
+}
+
+#[derive(Deserialize)]
+pub struct VCardImportRequest {
+    /// Contents of a `.vcf` file with one or more entries.
+    pub vcard: String,
+    #[serde(default = "default_dry_run")]
+    pub dry_run: bool,
+}
+
+#[derive(Serialize)]
+pub struct VCardCardReport {
+    pub index: usize,
+    pub name: Option<String>,
+    pub action: ImportAction,
+    pub contact_id: Option<Uuid>,
+    pub messages: Vec<String>,
+}
+
+#[derive(Serialize, Default)]
+pub struct VCardImportSummary {
+    pub total_cards: usize,
+    pub contacts_created: usize,
+    pub contacts_updated: usize,
+    pub unchanged: usize,
+    pub conflicts: usize,
+}
+
+#[derive(Serialize)]
+pub struct VCardImportReport {
+    pub dry_run: bool,
+    pub committed: bool,
+    pub summary: VCardImportSummary,
+    pub cards: Vec<VCardCardReport>,
+}
+
+/// Adds the card's addresses the contact does not have yet as identities,
+/// returning how many were added.
+async fn add_identities<C: ConnectionTrait>(
+    db: &C,
+    contact_id: Uuid,
+    card: &VCard,
+) -> Result<usize, DbErr> {
+    let existing: Vec<(String, String)> = contact_identity::Entity::find()
+        .filter(contact_identity::Column::ContactId.eq(contact_id))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|identity| (identity.kind, identity.normalized_value))
+        .collect();
+
+    let mut added = Vec::new();
+    let values = card
+        .emails
+        .iter()
+        .map(|value| (IdentityKind::Email, value))
+        .chain(card.phones.iter().map(|value| (IdentityKind::Phone, value)));
+    for (kind, value) in values {
+        let Some(normalized) = normalize_identity(kind, &value.value) else {
+            continue;
+        };
+        let key = (kind.as_str().to_string(), normalized.clone());
+        if existing.contains(&key) || added.contains(&key) {
+            continue;
+        }
+        contact_identity::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            contact_id: Set(contact_id),
+            kind: Set(kind.as_str().to_string()),
+            value: Set(value.value.clone()),
+            normalized_value: Set(normalized),
+            label: Set(value.label.clone()),
+            is_primary: Set(false),
+            created_at: Set(Utc::now().naive_utc()),
+        }
+        .insert(db)
+        .await?;
+        added.push(key);
+    }
+
+    Ok(added.len())
+}
+
+/// Creates or updates the contact for one card, returning the action taken.
+async fn import_card<C: ConnectionTrait>(
+    db: &C,
+    user: &user::Model,
+    startup: &startup::Model,
+    contacts: &mut Vec<contact::Model>,
+    card: &VCard,
+    report: &mut VCardCardReport,
+) -> Result<(), DbErr> {
+    let Some(name) = card.name.clone() else {
+        report.action = ImportAction::Conflict;
+        report.messages.push("card has no name".to_string());
+        return Ok(());
+    };
+    if let Some(org) = &card.org {
+        if !org.eq_ignore_ascii_case(&startup.name) {
+            report.messages.push(format!(
+                "organization \"{org}\" differs from {}",
+                startup.name
+            ));
+        }
+    }
+
+    // Match on any of the card's emails, or on the name when it has none.
+    let card_emails: Vec<String> = card
+        .emails
+        .iter()
+        .filter_map(|value| normalize_email(&value.value))
+        .collect();
+    let matched = if card_emails.is_empty() {
+        contacts
+            .iter()
+            .find(|candidate| candidate.name.trim().eq_ignore_ascii_case(&name))
+            .cloned()
+    } else {
+        let owner = contact_identity::Entity::find()
+            .filter(
+                contact_identity::Column::ContactId
+                    .is_in(contacts.iter().map(|candidate| candidate.id)),
+            )
+            .filter(contact_identity::Column::Kind.eq(IdentityKind::Email.as_str()))
+            .filter(contact_identity::Column::NormalizedValue.is_in(card_emails))
+            .one(db)
+            .await?;
+        owner.and_then(|identity| {
+            contacts
+                .iter()
+                .find(|candidate| candidate.id == identity.contact_id)
+                .cloned()
+        })
+    };
+
+    let email = VCard::primary(&card.emails).map(|value| value.value.clone());
+    let phone = VCard::primary(&card.phones).map(|value| value.value.clone());
+
+    let Some(existing) = matched else {
+        let role = card
+            .title
+            .clone()
+            .unwrap_or_else(|| DEFAULT_CONTACT_ROLE.to_string());
+        let inserted = contact::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            startup_id: Set(startup.id),
+            name: Set(name.clone()),
+            role: Set(role.clone()),
+            email: Set(email),
+            phone: Set(phone),
+            linkedin_url: Set(card.linkedin_url().cloned()),
+            is_primary: Set(false),
+            notes: Set(card.note.clone()),
+            is_trashed: Set(false),
+            trashed_at: Set(None),
+            owner_id: Set(Some(user.id)),
+            custom_fields: Set(json!({})),
+        }
+        .insert(db)
+        .await?;
+        sync_primary_identities(db, &inserted).await?;
+        add_identities(db, inserted.id, card).await?;
+
+        record_activity_event(
+            db,
+            ActivityEventInput {
+                activity_type: ACTIVITY_CONTACT_CREATED,
+                description: format!("Imported contact {} ({})", name, role),
+                user_id: Some(user.id),
+                user_name: Some(user_display_name(user)),
+                startup_id: Some(startup.id),
+                startup_name: Some(startup.name.clone()),
+                contact_id: Some(inserted.id),
+                contact_name: Some(name),
+                stage_from: None,
+                stage_to: None,
+                metadata: Some(json!({ "contact_role": role, "source": "vcard_import" })),
+                occurred_at: None,
+            },
+        )
+        .await?;
+
+        report.action = ImportAction::Create;
+        report.contact_id = Some(inserted.id);
+        contacts.push(inserted);
+        return Ok(());
+    };
+
+    report.contact_id = Some(existing.id);
+    if !can_edit_contact(user, &existing) {
+        report.action = ImportAction::Conflict;
+        report
+            .messages
+            .push("matching contact belongs to another user".to_string());
+        return Ok(());
+    }
+
+    // Card values replace the contact's, as with CSV imports; the primary
+    // email stays and the card's other addresses are added alongside.
+    let mut active: contact::ActiveModel = existing.clone().into();
+    active.name = Set(name);
+    if let Some(title) = &card.title {
+        active.role = Set(title.clone());
+    }
+    if existing.email.is_none() {
+        active.email = Set(email);
+    }
+    if phone.is_some() {
+        active.phone = Set(phone);
+    }
+    if let Some(url) = card.linkedin_url() {
+        active.linkedin_url = Set(Some(url.clone()));
+    }
+    if let Some(note) = &card.note {
+        active.notes = Set(Some(note.clone()));
+    }
+    let updated = active.update(db).await?;
+    sync_primary_identities(db, &updated).await?;
+    let added = add_identities(db, updated.id, card).await?;
+    record_contact_revisions(db, &existing, &updated, Some(user)).await?;
+
+    report.action = if updated != existing || added > 0 {
+        ImportAction::Update
+    } else {
+        ImportAction::Unchanged
+    };
+    if let Some(slot) = contacts.iter_mut().find(|model| model.id == updated.id) {
+        *slot = updated;
+    }
+    Ok(())
+}
+
+/// POST /api/startups/:startup_id/contacts/vcard
+/// Import the entries of a `.vcf` file as contacts of the startup. Cards
+/// whose email (or, without one, name) matches an existing contact update
+/// it. Defaults to a dry run; pass `dry_run: false` to commit.
+pub async fn import_vcards(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(startup_id): Path<Uuid>,
+    Json(payload): Json<VCardImportRequest>,
+) -> Result<Json<VCardImportReport>, StatusCode> {
+    let cards = parse_vcards(&payload.vcard).map_err(|err| {
+        warn!(error = %err, "failed to parse vcard import");
+        StatusCode::BAD_REQUEST
+    })?;
+    if cards.len() > MAX_VCARD_ENTRIES {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let startup = find_active_startup(&txn, startup_id).await?;
+    let mut contacts = contact::Entity::find()
+        .filter(contact::Column::StartupId.eq(startup_id))
+        .filter(contact::Column::IsTrashed.eq(false))
+        .order_by_asc(contact::Column::Name)
+        .all(&txn)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut summary = VCardImportSummary {
+        total_cards: cards.len(),
+        ..Default::default()
+    };
+    let mut reports = Vec::new();
+    for (index, card) in cards.iter().enumerate() {
+        let mut report = VCardCardReport {
+            index,
+            name: card.name.clone(),
+            action: ImportAction::Unchanged,
+            contact_id: None,
+            messages: Vec::new(),
+        };
+        import_card(&txn, &user, &startup, &mut contacts, card, &mut report)
+            .await
+            .map_err(|err| {
+                error!(error = ?err, "failed to import vcard");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        match report.action {
+            ImportAction::Create => summary.contacts_created += 1,
+            ImportAction::Update => summary.contacts_updated += 1,
+            ImportAction::Unchanged => summary.unchanged += 1,
+            ImportAction::Conflict | ImportAction::Invalid => summary.conflicts += 1,
+        }
+        reports.push(report);
+    }
+
+    if payload.dry_run {
+        txn.rollback()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    } else {
+        txn.commit()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
+    Ok(Json(VCardImportReport {
+        dry_run: payload.dry_run,
+        committed: !payload.dry_run,
+        summary,
+        cards: reports,
+    }))
+}
+
+async fn vcard_response(
+    state: &AppState,
+    contacts: Vec<ContactResponse>,
+    file_name: &str,
+) -> Result<Response, StatusCode> {
+    let contact_ids: Vec<Uuid> = contacts.iter().map(|contact| contact.id).collect();
+    let mut identities: HashMap<Uuid, Vec<contact_identity::Model>> = HashMap::new();
+    for identity in contact_identity::Entity::find()
+        .filter(contact_identity::Column::ContactId.is_in(contact_ids))
+        .order_by_desc(contact_identity::Column::IsPrimary)
+        .order_by_asc(contact_identity::Column::CreatedAt)
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    {
+        identities
+            .entry(identity.contact_id)
+            .or_default()
+            .push(identity);
+    }
+
+    let startup_ids: Vec<Uuid> = contacts.iter().map(|contact| contact.startup_id).collect();
+    let startup_names: HashMap<Uuid, String> = startup::Entity::find()
+        .filter(startup::Column::Id.is_in(startup_ids))
+        .all(&state.db)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .into_iter()
+        .map(|model| (model.id, model.name))
+        .collect();
+
+    let body: String = contacts
+        .iter()
+        .map(|contact| {
+            write_vcard(
+                contact,
+                startup_names.get(&contact.startup_id).map(String::as_str),
+                identities
+                    .get(&contact.id)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+            )
+        })
+        .collect();
+
+    let mut response = Response::new(Body::from(body));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        "text/vcard; charset=utf-8"
+            .parse()
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+    );
+    response.headers_mut().insert(
+        header::CONTENT_DISPOSITION,
+        format!("attachment; filename=\"{}\"", file_name)
+            .parse()
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+    );
+    Ok(response)
+}
+
+/// GET /api/contacts/vcard
+/// Accepts the same filters as GET /api/contacts.
+pub async fn export_vcards(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    query: Option<Query<ContactListQuery>>,
+) -> Result<Response, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    let trashed = params.trashed.unwrap_or(false);
+    if trashed && !user.is_admin() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let contacts = fetch_contacts(&state.db, None, trashed, &params).await?;
+    let stamp = Utc::now().format("%Y%m%d");
+    vcard_response(&state, contacts, &format!("contacts-{stamp}.vcf")).await
+}
+
+/// GET /api/startups/:startup_id/contacts/vcard
+pub async fn export_startup_vcards(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(startup_id): Path<Uuid>,
+    query: Option<Query<ContactListQuery>>,
+) -> Result<Response, StatusCode> {
+    let params = query.map(|q| q.0).unwrap_or_default();
+    let trashed = params.trashed.unwrap_or(false);
+    if trashed && !user.is_admin() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let startup = find_active_startup(&state.db, startup_id).await?;
+    let contacts = fetch_contacts(&state.db, Some(startup_id), trashed, &params).await?;
+    let slug: String = startup
+        .name
+        .to_lowercase()
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '-' })
+        .collect();
+    vcard_response(
+        &state,
+        contacts,
+        &format!("{}-contacts.vcf", slug.trim_matches('-')),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vcards() {
+        let input = "BEGIN:VCARD\r\nVERSION:3.0\r\nN:Okafor;Ada;;;\r\nEMAIL;TYPE=INTERNET,WORK:ada@acme.io\r\n\
+            item1.EMAIL;TYPE=INTERNET,HOME,PREF:ada@gmail.com\r\nTEL;TYPE=CELL:+234 801 234\r\n 5678\r\n\
+            ORG:Acme\\, Inc.;Engineering\r\nTITLE:CTO\r\nURL:https://www.linkedin.com/in/ada\r\n\
+            NOTE:Met at demo day\\nLikes tea\r\nEND:VCARD\r\n\
+            BEGIN:VCARD\nVERSION:4.0\nFN:Bola Ade\nTEL;VALUE=uri;PREF=1;TYPE=\"voice,work\":tel:+1-555-0100\nEND:VCARD\n";
+
+        let cards = parse_vcards(input).unwrap();
+
+        assert_eq!(cards.len(), 2);
+        let ada = &cards[0];
+        assert_eq!(ada.name.as_deref(), Some("Ada Okafor"));
+        assert_eq!(
+            VCard::primary(&ada.emails).map(|email| email.value.as_str()),
+            Some("ada@gmail.com")
+        );
+        assert_eq!(ada.emails[0].label.as_deref(), Some("work"));
+        assert_eq!(ada.phones[0].value, "+234 801 2345678");
+        assert_eq!(ada.phones[0].label.as_deref(), Some("cell"));
+        assert_eq!(ada.org.as_deref(), Some("Acme, Inc."));
+        assert_eq!(ada.title.as_deref(), Some("CTO"));
+        assert_eq!(
+            ada.linkedin_url().map(String::as_str),
+            Some("https://www.linkedin.com/in/ada")
+        );
+        assert_eq!(ada.note.as_deref(), Some("Met at demo day\nLikes tea"));
+        assert_eq!(
+            cards[1].phones,
+            vec![VCardValue {
+                value: "+1-555-0100".to_string(),
+                label: Some("work".to_string()),
+                preferred: true,
+            }]
+        );
+
+        assert!(parse_vcards("BEGIN:VCARD\nVERSION:2.1\nFN:Old\nEND:VCARD\n").is_err());
+        assert!(parse_vcards("BEGIN:VCARD\nVERSION:3.0\nFN:Open\n").is_err());
+    }
+
+    #[test]
+    fn test_write_vcard_round_trips() {
+        let contact = ContactResponse {
+            id: Uuid::new_v4(),
+            startup_id: Uuid::new_v4(),
+            name: "Ada Okafor".to_string(),
+            role: "CTO; Co-founder".to_string(),
+            email: Some("ada@acme.io".to_string()),
+            phone: None,
+            linkedin_url: None,
+            is_primary: true,
+            notes: Some(format!("{}\nsecond line", "long note ".repeat(12))),
+            is_trashed: false,
+            trashed_at: None,
+            owner_id: None,
+            owner_name: None,
+            owner_email: None,
+            custom_fields: json!({}),
+        };
+        let identity = |kind: &str, value: &str, primary: bool| contact_identity::Model {
+            id: Uuid::new_v4(),
+            contact_id: contact.id,
+            kind: kind.to_string(),
+            value: value.to_string(),
+            normalized_value: value.to_lowercase(),
+            label: Some("work".to_string()),
+            is_primary: primary,
+            created_at: Utc::now().naive_utc(),
+        };
+        let identities = vec![
+            identity("email", "ada@acme.io", true),
+            identity("email", "ada@gmail.com", false),
+            identity("phone", "+234 801 234 5678", true),
+        ];
+
+        let text = write_vcard(&contact, Some("Acme, Inc."), &identities);
+
+        assert!(text.lines().all(|line| line.len() <= VCARD_LINE_LIMIT + 1));
+        let cards = parse_vcards(&text).unwrap();
+        assert_eq!(cards.len(), 1);
+        let card = &cards[0];
+        assert_eq!(card.name.as_deref(), Some("Ada Okafor"));
+        assert_eq!(card.title.as_deref(), Some("CTO; Co-founder"));
+        assert_eq!(card.org.as_deref(), Some("Acme, Inc."));
+        assert_eq!(card.emails.len(), 2);
+        assert!(card.emails[0].preferred);
+        assert_eq!(card.phones[0].value, "+234 801 234 5678");
+        assert_eq!(
+            card.note,
+            contact.notes.map(|notes| notes.trim().to_string())
+        );
+    }
+}
@@ -6,8 +6,8 @@ import { Sidebar } from '@/components/Sidebar';
 import React from 'react';
 import { useAuth } from '@/lib/auth-context';
 
-const PUBLIC_ROUTES = new Set(['/login', '/forgot-password', '/reset-password']);
-const SIDEBAR_HIDDEN_ROUTES = new Set(['/login', '/forgot-password', '/reset-password']);
+const PUBLIC_ROUTES = new Set(['/login', '/forgot-password', '/reset-password', '/claim']);
+const SIDEBAR_HIDDEN_ROUTES = new Set(['/login', '/forgot-password', '/reset-password', '/claim']);
 
 export function AppShell({ children }: { children: React.ReactNode }) {
     const pathname = usePathname() || '';
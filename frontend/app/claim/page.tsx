@@ -0,0 +1,85 @@
+'use client';
+
+import { useSearchParams } from 'next/navigation';
+import { Suspense, useEffect, useState } from 'react';
+import { api, type ClaimPreview } from '@/lib/api';
+
+function ClaimNewsroom() {
+    const searchParams = useSearchParams();
+    const token = searchParams.get('token');
+
+    const [claim, setClaim] = useState<ClaimPreview | null>(null);
+    const [error, setError] = useState('');
+    const [loading, setLoading] = useState(false);
+
+    useEffect(() => {
+        if (!token) return;
+        api.getClaim(token)
+            .then(setClaim)
+            .catch((err: Error) => setError(err.message));
+    }, [token]);
+
+    const handleClaim = async () => {
+        if (!token) return;
+        setError('');
+        setLoading(true);
+        try {
+            setClaim(await api.acceptClaim(token));
+        } catch (err) {
+            setError(err instanceof Error ? err.message : 'Failed to claim newsroom');
+        } finally {
+            setLoading(false);
+        }
+    };
+
+    return (
+        <div className="min-h-screen flex items-center justify-center bg-background">
+            <div className="w-full max-w-md p-8 bg-card rounded-lg shadow-md">
+                <h1 className="text-2xl font-bold mb-2 text-foreground">Claim your newsroom</h1>
+
+                {!token && (
+                    <div className="p-3 bg-destructive/10 border border-destructive text-destructive rounded">
+                        Invalid or missing claim link.
+                    </div>
+                )}
+
+                {error && (
+                    <div className="mb-4 p-3 bg-destructive/10 border border-destructive text-destructive rounded">
+                        {error}
+                    </div>
+                )}
+
+                {claim && claim.claimed_at && (
+                    <div className="p-3 bg-accent/10 border border-accent text-accent-foreground rounded">
+                        The {claim.startup_name} newsroom has been claimed. Thanks, {claim.contact_name}!
+                    </div>
+                )}
+
+                {claim && !claim.claimed_at && (
+                    <>
+                        <p className="text-sm text-muted-foreground mb-6">
+                            Hi {claim.contact_name}, confirm below to claim the {claim.startup_name} newsroom.
+                            This link expires on {new Date(claim.expires_at + 'Z').toLocaleDateString()}.
+                        </p>
+                        <button
+                            type="button"
+                            onClick={handleClaim}
+                            disabled={loading}
+                            className="w-full py-2 px-4 bg-primary text-white rounded hover:opacity-90 disabled:opacity-50 disabled:cursor-not-allowed font-medium"
+                        >
+                            {loading ? 'Claiming...' : 'Claim newsroom'}
+                        </button>
+                    </>
+                )}
+            </div>
+        </div>
+    );
+}
+
+export default function ClaimPage() {
+    return (
+        <Suspense fallback={<div className="min-h-screen flex items-center justify-center">Loading...</div>}>
+            <ClaimNewsroom />
+        </Suspense>
+    );
+}
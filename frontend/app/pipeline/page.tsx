@@ -1,21 +1,30 @@
 'use client';
 
 import { useState, useMemo } from 'react';
-import { useStartups, useUpdateStartup } from '@/lib/hooks';
+import { usePipelineStages, useStartups, useTransitionStartup } from '@/lib/hooks';
 import { PlanInterviewModal } from '@/components/PlanInterviewModal';
 import Link from 'next/link';
 import type { Startup } from '@/lib/api';
 import { AddStartupSheet } from '@/components/AddStartupSheet';
-import { PIPELINE_STATUSES } from '@/lib/pipeline-statuses';
+import { PIPELINE_STATUSES, allowedTransitions, transitionNeedsReason } from '@/lib/pipeline-statuses';
+
+interface PendingMove {
+    startup: Startup;
+    toStage: string;
+}
 
 export default function PipelinePage() {
     const { data: startups, isLoading, error } = useStartups();
-    const updateStartup = useUpdateStartup();
+    const { data: stages } = usePipelineStages();
+    const transitionStartup = useTransitionStartup();
     const [draggedStartup, setDraggedStartup] = useState<Startup | null>(null);
     const [dragOverColumn, setDragOverColumn] = useState<string | null>(null);
     const [isAddStartupOpen, setIsAddStartupOpen] = useState(false);
     const [planInterviewOpen, setPlanInterviewOpen] = useState(false);
     const [selectedStartupId, setSelectedStartupId] = useState<string | undefined>();
+    const [pendingMove, setPendingMove] = useState<PendingMove | null>(null);
+    const [moveReason, setMoveReason] = useState('');
+    const [moveError, setMoveError] = useState<string | null>(null);
 
     // Group startups by status for kanban
     const kanbanColumns = useMemo(() => {
@@ -27,6 +36,11 @@ export default function PipelinePage() {
         return columns;
     }, [startups]);
 
+    const dropTargets = useMemo(
+        () => (draggedStartup ? allowedTransitions(stages, draggedStartup.status) : []),
+        [stages, draggedStartup],
+    );
+
     // Drag and drop handlers
     const handleDragStart = (e: React.DragEvent, startup: Startup) => {
         setDraggedStartup(startup);
@@ -44,6 +58,8 @@ export default function PipelinePage() {
     };
 
     const handleDragOver = (e: React.DragEvent, status: string) => {
+        // Leaving the default in place marks the column as not droppable.
+        if (!dropTargets.includes(status)) return;
         e.preventDefault();
         e.dataTransfer.dropEffect = 'move';
         setDragOverColumn(status);
@@ -53,31 +69,48 @@ export default function PipelinePage() {
         setDragOverColumn(null);
     };
 
+    const moveStartup = async (startup: Startup, toStage: string, reason?: string) => {
+        setMoveError(null);
+        try {
+            await transitionStartup.mutateAsync({
+                id: startup.id,
+                data: { to_stage: toStage, reason },
+            });
+            return true;
+        } catch (error) {
+            setMoveError(
+                error instanceof Error ? error.message : `Failed to move ${startup.name}`,
+            );
+            return false;
+        }
+    };
+
     const handleDrop = async (e: React.DragEvent, newStatus: string) => {
         e.preventDefault();
         setDragOverColumn(null);
 
-        if (!draggedStartup || draggedStartup.status === newStatus) {
-            setDraggedStartup(null);
+        const startup = draggedStartup;
+        setDraggedStartup(null);
+        if (!startup || startup.status === newStatus || !dropTargets.includes(newStatus)) {
             return;
         }
 
-        try {
-            await updateStartup.mutateAsync({
-                id: draggedStartup.id,
-                data: {
-                    name: draggedStartup.name,
-                    status: newStatus,
-                    category: draggedStartup.category || undefined,
-                    website: draggedStartup.website || undefined,
-                    newsroom_url: draggedStartup.newsroom_url || undefined,
-                },
-            });
-        } catch (error) {
-            console.error('Failed to update startup status:', error);
+        if (transitionNeedsReason(stages, startup.status, newStatus)) {
+            setMoveReason('');
+            setPendingMove({ startup, toStage: newStatus });
+            return;
         }
 
-        setDraggedStartup(null);
+        await moveStartup(startup, newStatus);
+    };
+
+    const handleConfirmMove = async (e: React.FormEvent) => {
+        e.preventDefault();
+        if (!pendingMove || !moveReason.trim()) return;
+
+        if (await moveStartup(pendingMove.startup, pendingMove.toStage, moveReason.trim())) {
+            setPendingMove(null);
+        }
     };
 
     if (isLoading) {
@@ -123,12 +156,19 @@ export default function PipelinePage() {
                     </div>
                 </div>
 
+                {moveError && (
+                    <div className="mb-4 p-3 bg-destructive/10 border border-destructive text-destructive rounded text-sm">
+                        {moveError}
+                    </div>
+                )}
+
                 {/* Kanban View */}
                 <div className="overflow-x-auto pb-4">
                     <div className="flex gap-4 min-w-max">
                         {PIPELINE_STATUSES.map((status) => (
                             <div key={status} className="flex-shrink-0 w-80">
                                 <div className={`bg-card border rounded-lg shadow-sm transition-all ${dragOverColumn === status ? 'border-primary border-2 bg-primary/5' : 'border-border'
+                                    } ${draggedStartup && draggedStartup.status !== status && !dropTargets.includes(status) ? 'opacity-40' : ''
                                     }`}>
                                     {/* Column Header */}
                                     <div className="p-4 border-b border-border bg-muted/30">
@@ -210,6 +250,55 @@ export default function PipelinePage() {
                 onClose={() => setIsAddStartupOpen(false)}
             />
 
+            {pendingMove && (
+                <div className="fixed inset-0 bg-black/50 flex items-center justify-center z-50">
+                    <div className="bg-card p-6 rounded-lg shadow-lg w-full max-w-md">
+                        <h2 className="text-xl font-bold mb-2 text-foreground">
+                            Move to {pendingMove.toStage}
+                        </h2>
+                        <p className="text-sm text-muted-foreground mb-4">
+                            Moving {pendingMove.startup.name} from {pendingMove.startup.status} needs a reason.
+                        </p>
+
+                        {moveError && (
+                            <div className="mb-4 p-3 bg-destructive/10 border border-destructive text-destructive rounded text-sm">
+                                {moveError}
+                            </div>
+                        )}
+
+                        <form onSubmit={handleConfirmMove} className="space-y-4">
+                            <textarea
+                                value={moveReason}
+                                onChange={(e) => setMoveReason(e.target.value)}
+                                required
+                                rows={3}
+                                autoFocus
+                                className="w-full px-3 py-2 border border-input rounded bg-background text-foreground focus:outline-none focus:ring-2 focus:ring-ring"
+                            />
+                            <div className="flex justify-end gap-3">
+                                <button
+                                    type="button"
+                                    onClick={() => {
+                                        setPendingMove(null);
+                                        setMoveError(null);
+                                    }}
+                                    className="px-4 py-2 bg-secondary text-secondary-foreground rounded-lg hover:opacity-90 transition-opacity"
+                                >
+                                    Cancel
+                                </button>
+                                <button
+                                    type="submit"
+                                    disabled={transitionStartup.isPending || !moveReason.trim()}
+                                    className="px-4 py-2 bg-primary text-white rounded-lg hover:opacity-90 transition-opacity disabled:opacity-50"
+                                >
+                                    {transitionStartup.isPending ? 'Moving...' : 'Move'}
+                                </button>
+                            </div>
+                        </form>
+                    </div>
+                </div>
+            )}
+
             {planInterviewOpen && (
                 <PlanInterviewModal
                     isOpen={planInterviewOpen}
@@ -1,19 +1,11 @@
 'use client';
 
-import { useState, useEffect } from 'react';
+import { useState, useEffect, useMemo } from 'react';
 import { useRouter, useParams } from 'next/navigation';
-import { useStartup, useUpdateStartup } from '@/lib/hooks';
+import { usePipelineStages, useStartup, useTransitionStartup, useUpdateStartup } from '@/lib/hooks';
+import { allowedTransitions, transitionNeedsReason } from '@/lib/pipeline-statuses';
 import Link from 'next/link';
 
-const STATUSES = [
-    'Lead',
-    'Intro Secured',
-    'Call Booked',
-    'Interview Done',
-    'Activation Candidate',
-    'Not a Fit',
-];
-
 const CATEGORIES = [
     'Fintech',
     'SaaS',
@@ -31,6 +23,8 @@ export default function EditStartupPage() {
 
     const { data: startup, isLoading } = useStartup(id);
     const updateStartup = useUpdateStartup();
+    const transitionStartup = useTransitionStartup();
+    const { data: stages } = usePipelineStages();
 
     const [formData, setFormData] = useState({
         name: '',
@@ -38,6 +32,7 @@ export default function EditStartupPage() {
         website: '',
         newsroom_url: '',
         status: 'Lead',
+        reason: '',
     });
 
     useEffect(() => {
@@ -49,12 +44,25 @@ export default function EditStartupPage() {
                 website: startup.website || '',
                 newsroom_url: startup.newsroom_url || '',
                 status: startup.status,
+                reason: '',
             });
         }
     }, [startup]);
 
+    // The current status plus the stages the transition graph allows from it.
+    const statusOptions = useMemo(() => {
+        if (!startup) return [];
+        return [startup.status, ...allowedTransitions(stages, startup.status)];
+    }, [startup, stages]);
+
+    const currentStatus = startup?.status;
+    const statusChanged = currentStatus !== undefined && formData.status !== currentStatus;
+    const needsReason =
+        statusChanged && transitionNeedsReason(stages, currentStatus, formData.status);
+
     const handleSubmit = async (e: React.FormEvent) => {
         e.preventDefault();
+        if (!startup) return;
 
         try {
             await updateStartup.mutateAsync({
@@ -64,10 +72,21 @@ export default function EditStartupPage() {
                     category: formData.category || undefined,
                     website: formData.website || undefined,
                     newsroom_url: formData.newsroom_url || undefined,
-                    status: formData.status,
+                    // Stage moves go through the transition endpoint below.
+                    status: startup.status,
                 },
             });
 
+            if (statusChanged) {
+                await transitionStartup.mutateAsync({
+                    id,
+                    data: {
+                        to_stage: formData.status,
+                        reason: formData.reason.trim() || undefined,
+                    },
+                });
+            }
+
             router.push(`/startups/${id}`);
         } catch (error) {
             console.error('Failed to update startup:', error);
@@ -142,7 +161,7 @@ export default function EditStartupPage() {
                                 onChange={(e) => setFormData({ ...formData, status: e.target.value })}
                                 className="w-full px-3 py-2 bg-background border border-input rounded-md text-foreground focus:outline-none focus:ring-2 focus:ring-ring"
                             >
-                                {STATUSES.map((status) => (
+                                {statusOptions.map((status) => (
                                     <option key={status} value={status}>
                                         {status}
                                     </option>
@@ -150,6 +169,23 @@ export default function EditStartupPage() {
                             </select>
                         </div>
 
+                        {/* Reason for backward or Not a Fit moves */}
+                        {needsReason && (
+                            <div>
+                                <label htmlFor="reason" className="block text-sm font-medium text-foreground mb-1">
+                                    Reason for moving to {formData.status} <span className="text-destructive">*</span>
+                                </label>
+                                <textarea
+                                    id="reason"
+                                    required
+                                    rows={3}
+                                    value={formData.reason}
+                                    onChange={(e) => setFormData({ ...formData, reason: e.target.value })}
+                                    className="w-full px-3 py-2 bg-background border border-input rounded-md text-foreground focus:outline-none focus:ring-2 focus:ring-ring"
+                                />
+                            </div>
+                        )}
+
                         {/* Website */}
                         <div>
                             <label htmlFor="website" className="block text-sm font-medium text-foreground mb-1">
@@ -183,10 +219,10 @@ export default function EditStartupPage() {
                     <div className="flex gap-3 mt-6">
                         <button
                             type="submit"
-                            disabled={updateStartup.isPending}
+                            disabled={updateStartup.isPending || transitionStartup.isPending}
                             className="px-4 py-2 bg-primary text-white rounded-lg hover:opacity-90 transition-opacity disabled:opacity-50"
                         >
-                            {updateStartup.isPending ? 'Saving...' : 'Save Changes'}
+                            {updateStartup.isPending || transitionStartup.isPending ? 'Saving...' : 'Save Changes'}
                         </button>
                         <Link
                             href={`/startups/${id}`}
@@ -201,6 +237,11 @@ export default function EditStartupPage() {
                             Failed to update startup. Please try again.
                         </p>
                     )}
+                    {transitionStartup.isError && (
+                        <p className="mt-4 text-destructive text-sm">
+                            {transitionStartup.error.message}
+                        </p>
+                    )}
                 </form>
             </div>
         </div>
@@ -8,8 +8,10 @@ import {
     useContactsForStartup,
     useCreateInterview,
     useCreateInterviewInsight,
-    useUpdateStartup,
+    usePipelineStages,
+    useTransitionStartup,
 } from '@/lib/hooks';
+import { allowedTransitions, transitionNeedsReason } from '@/lib/pipeline-statuses';
 import {
     ChevronDown,
     MessageSquare,
@@ -138,7 +140,8 @@ export default function LiveInterviewPage() {
     const { data: contacts } = useContactsForStartup(startupId);
     const createInterview = useCreateInterview();
     const createInsight = useCreateInterviewInsight();
-    const updateStartup = useUpdateStartup();
+    const transitionStartup = useTransitionStartup();
+    const { data: stages } = usePipelineStages();
 
     const [activeStep, setActiveStep] = useState(0);
     const [timer, setTimer] = useState(0);
@@ -228,16 +231,14 @@ export default function LiveInterviewPage() {
                 activation_candidate: formData.signal === 'High',
             });
 
-            if (startup.status !== 'Interview Done') {
-                await updateStartup.mutateAsync({
+            // Only advance the stage; startups already past it keep their status.
+            if (
+                allowedTransitions(stages, startup.status).includes('Interview Done') &&
+                !transitionNeedsReason(stages, startup.status, 'Interview Done')
+            ) {
+                await transitionStartup.mutateAsync({
                     id: startup.id,
-                    data: {
-                        name: startup.name,
-                        status: 'Interview Done',
-                        category: startup.category || undefined,
-                        website: startup.website || undefined,
-                        newsroom_url: startup.newsroom_url || undefined,
-                    },
+                    data: { to_stage: 'Interview Done' },
                 });
             }
 
@@ -2,7 +2,7 @@
 
 import { useEffect, useState } from 'react';
 import { useAuth } from '@/lib/auth-context';
-import { ACTIVITY_EVENT_TYPES, PIPELINE_STATUSES } from '@/lib/activity-constants';
+import { ACTIVITY_EVENT_TYPES, OUTPUT_STAGES } from '@/lib/activity-constants';
 import type { WeeklyMetricInput, WeeklyPlan } from '@/lib/api';
 import {
     useCloseWeeklyActivityPlan,
@@ -35,7 +35,7 @@ const buildMetrics = (plan: WeeklyPlan | undefined, type: 'input' | 'output'): E
             owner_id: metric.owner_id ?? undefined,
             target_value: metric.target_value,
             activity_type: type === 'input' ? metric.activity_type ?? ACTIVITY_EVENT_TYPES[0]?.value : undefined,
-            stage_name: type === 'output' ? metric.stage_name ?? PIPELINE_STATUSES[0] : undefined,
+            stage_name: type === 'output' ? metric.stage_name ?? OUTPUT_STAGES[0] : undefined,
             sort_order: index,
         }));
 };
@@ -85,7 +85,7 @@ export function ActivitySettingsPanel(): JSX.Element {
             owner_name: undefined,
             target_value: 0,
             activity_type: type === 'input' ? ACTIVITY_EVENT_TYPES[0]?.value : undefined,
-            stage_name: type === 'output' ? PIPELINE_STATUSES[0] : undefined,
+            stage_name: type === 'output' ? OUTPUT_STAGES[0] : undefined,
         };
 
         if (type === 'input') {
@@ -364,10 +364,10 @@ const MetricCard = ({ metric, type, onChange, onRemove }: MetricCardProps): JSX.
                 ) : (
                     <select
                         className="w-full rounded-md border border-border bg-card p-2 text-sm"
-                        value={metric.stage_name ?? PIPELINE_STATUSES[0]}
+                        value={metric.stage_name ?? OUTPUT_STAGES[0]}
                         onChange={(e) => onChange(type, metric.localId, 'stage_name', e.target.value)}
                     >
-                        {PIPELINE_STATUSES.map((stage) => (
+                        {OUTPUT_STAGES.map((stage) => (
                             <option key={stage} value={stage}>
                                 {stage}
                             </option>
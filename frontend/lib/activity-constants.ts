@@ -12,10 +12,22 @@ export const PIPELINE_STATUSES = [
   'Not a Fit',
 ];
 
+// Output metrics can also count events that are not stage moves.
+export const OUTPUT_STAGES = [...PIPELINE_STATUSES, 'Newsroom Claimed'];
+
 export const ACTIVITY_EVENT_TYPES = [
   { value: 'contact_created', label: 'Contact Added' },
   { value: 'startup_created', label: 'Startup Added' },
   { value: 'outreach_logged', label: 'Outreach Logged' },
   { value: 'meeting_logged', label: 'Interview Logged' },
   { value: 'stage_moved', label: 'Stage Moved' },
+  { value: 'startup_merged', label: 'Startups Merged' },
+  { value: 'contact_merged', label: 'Contacts Merged' },
+  { value: 'owner_changed', label: 'Owner Changed' },
+  { value: 'task_completed', label: 'Task Completed' },
+  { value: 'newsroom_down', label: 'Newsroom Down' },
+  { value: 'newsroom_recovered', label: 'Newsroom Recovered' },
+  { value: 'newsroom_claimed', label: 'Newsroom Claimed' },
+  { value: 'startup_updated', label: 'Startup Updated' },
+  { value: 'intro_made', label: 'Intro Made' },
 ];
@@ -19,7 +19,7 @@
 
 import { Result, Ok, Err } from 'oxide.ts';
 import { safeFetch, FetchError } from './result-utils';
-import type { Startup, CreateStartupRequest, Contact, CreateContactRequest } from './api';
+import type { Startup, StartupListResponse, CreateStartupRequest, Contact, CreateContactRequest } from './api';
 
 const API_BASE_URL = process.env.NEXT_PUBLIC_API_URL || 'http://localhost:3001';
 
@@ -34,7 +34,7 @@ const API_BASE_URL = process.env.NEXT_PUBLIC_API_URL || 'http://localhost:3001';
  */
 export const apiWithResults = {
   /**
-   * Fetch all startups.
+   * Fetch all startups, following pages until the list is complete.
    * 
    * @example
    * const result = await apiWithResults.getStartups();
@@ -47,9 +47,20 @@ export const apiWithResults = {
    * }
    */
   async getStartups(): Promise<Result<Startup[], FetchError>> {
-    return safeFetch<Startup[]>(`${API_BASE_URL}/api/startups`, {
-      credentials: 'include',
-    });
+    const startups: Startup[] = [];
+    let page = 1;
+    for (;;) {
+      const result = await safeFetch<StartupListResponse>(
+        `${API_BASE_URL}/api/startups?page=${page}&page_size=100`,
+        { credentials: 'include' }
+      );
+      if (result.isErr()) return result as Result<never, FetchError>;
+      const response = result.unwrap();
+      startups.push(...response.results);
+      if (response.results.length === 0 || startups.length >= response.total) break;
+      page += 1;
+    }
+    return Ok(startups);
   },
 
   /**
@@ -10,16 +10,160 @@ export interface Startup {
   last_contact_date: string | null;
   next_step: string | null;
   admin_claimed: boolean;
+  owner_id: string | null;
+  is_trashed: boolean;
+  trashed_at: string | null;
+  custom_fields: Record<string, unknown>;
+  signal_score: number;
+  signal_breakdown: SignalScoreFactor[];
+  website_broken: boolean;
+  newsroom_url_broken: boolean;
+  claimed_at: string | null;
+  claimed_by_contact_id: string | null;
   created_at: string;
   updated_at: string;
 }
 
+export interface SignalScoreFactor {
+  factor: string;
+  points: number;
+  max_points: number;
+  detail: string;
+}
+
+export interface UrlCheck {
+  id: string;
+  startup_id: string;
+  kind: 'website' | 'newsroom';
+  url: string;
+  status_code: number | null;
+  latency_ms: number | null;
+  redirect_url: string | null;
+  error: string | null;
+  is_broken: boolean;
+  consecutive_failures: number;
+  checked_at: string;
+  last_ok_at: string | null;
+}
+
+export interface NewsroomClaim {
+  id: string;
+  startup_id: string;
+  contact_id: string | null;
+  email: string;
+  created_by_id: string | null;
+  message_id: string | null;
+  expires_at: string;
+  claimed_at: string | null;
+  created_at: string;
+}
+
+export interface ClaimPreview {
+  startup_name: string;
+  contact_name: string;
+  expires_at: string;
+  claimed_at: string | null;
+}
+
+export interface StartupListParams {
+  page?: number;
+  page_size?: number;
+  search?: string;
+  status?: string;
+  category?: string;
+  next_step?: string;
+  admin_claimed?: boolean;
+  broken_links?: boolean;
+  owner_id?: string;
+  mine?: boolean;
+  last_contact_from?: string;
+  last_contact_to?: string;
+  sort_by?: string;
+  sort_order?: 'asc' | 'desc';
+  trashed?: boolean;
+  custom_fields?: string;
+  tags?: string;
+}
+
+export interface StartupListResponse {
+  total: number;
+  page: number;
+  page_size: number;
+  results: Startup[];
+}
+
 export interface CreateStartupRequest {
   name: string;
   category?: string;
   website?: string;
   newsroom_url?: string;
   status: string;
+  custom_fields?: Record<string, unknown>;
+  owner_id?: string;
+}
+
+export interface PipelineStageInfo {
+  stage: string;
+  position: number;
+  allowed_transitions: string[];
+}
+
+export interface StartupTransitionRequest {
+  to_stage: string;
+  reason?: string;
+}
+
+export interface FieldRevision {
+  id: string;
+  entity_type: 'startup' | 'contact';
+  entity_id: string;
+  entity_name: string | null;
+  field: string;
+  old_value: unknown;
+  new_value: unknown;
+  user_id: string | null;
+  user_name: string | null;
+  created_at: string;
+}
+
+export interface StartupHistoryResponse {
+  total: number;
+  page: number;
+  page_size: number;
+  results: FieldRevision[];
+}
+
+export interface BulkReassignOwnerResponse {
+  updated: Startup[];
+  unchanged: string[];
+}
+
+export type BulkStartupAction =
+  | { action: 'transition'; to_stage: string; reason?: string }
+  | { action: 'owner'; owner_id: string | null }
+  | { action: 'category'; category: string | null }
+  | {
+      action: 'tags';
+      add_tag_ids?: string[];
+      add_tag_names?: string[];
+      remove_tag_ids?: string[];
+      remove_tag_names?: string[];
+    }
+  | { action: 'trash' };
+
+export interface BulkStartupResult {
+  startup_id: string;
+  ok: boolean;
+  changed: boolean;
+  status: number;
+  error: string | null;
+  startup: Startup | null;
+}
+
+export interface BulkStartupResponse {
+  succeeded: number;
+  failed: number;
+  results: BulkStartupResult[];
 }
 
 export interface Contact {
@@ -33,9 +177,105 @@ export interface Contact {
   is_primary: boolean;
   notes: string | null;
   is_trashed: boolean;
+  trashed_at: string | null;
   owner_id: string | null;
   owner_name: string | null;
   owner_email: string | null;
+  custom_fields: Record<string, unknown>;
+}
+
+export interface ContactPurgeCandidate {
+  id: string;
+  name: string;
+  startup_id: string;
+  startup_name: string | null;
+  trashed_at: string;
+}
+
+export interface ContactPurgePreview {
+  enabled: boolean;
+  retention_days: number | null;
+  cutoff: string | null;
+  total: number;
+  contacts: ContactPurgeCandidate[];
+}
+
+export interface ContactPurgeRun {
+  id: string;
+  trigger: 'scheduled' | 'manual';
+  triggered_by_id: string | null;
+  retention_days: number;
+  cutoff: string;
+  purged_count: number;
+  contacts: ContactPurgeCandidate[];
+  error: string | null;
+  started_at: string;
+  finished_at: string;
+}
+
+export type ContactIdentityKind = 'email' | 'phone' | 'handle';
+
+export interface ContactIdentity {
+  id: string;
+  contact_id: string;
+  kind: ContactIdentityKind;
+  value: string;
+  label: string | null;
+  is_primary: boolean;
+  created_at: string;
+}
+
+export interface ContactIdentityInput {
+  kind: ContactIdentityKind;
+  value: string;
+  label?: string;
+  is_primary?: boolean;
+}
+
+/** A contact row as stored, without the owner details list endpoints add. */
+export type ContactRecord = Omit<Contact, 'owner_name' | 'owner_email'>;
+
+export type DuplicateReason = 'email' | 'phone' | 'name';
+
+export interface DuplicateContact extends ContactRecord {
+  startup_name: string | null;
+}
+
+export interface DuplicateGroup {
+  reason: DuplicateReason;
+  value: string;
+  contacts: DuplicateContact[];
+}
+
+export type ContactMergeField =
+  | 'name'
+  | 'role'
+  | 'email'
+  | 'phone'
+  | 'linkedin_url'
+  | 'notes'
+  | 'owner_id';
+
+export interface ContactMergeRequest {
+  source_id: string;
+  fields?: Partial<Record<ContactMergeField, 'target' | 'source'>>;
+  dry_run?: boolean;
+}
+
+export interface ContactMergeResponse {
+  dry_run: boolean;
+  contact: ContactRecord;
+  merged_contact: ContactRecord;
+  reparented: {
+    outreach_logs: number;
+    interviews: number;
+    activity_events: number;
+    tasks: number;
+    newsroom_claims: number;
+    intro_requests: number;
+    tags: number;
+    identities: number;
+  };
 }
 
 export interface CreateContactRequest {
@@ -47,6 +287,7 @@ export interface CreateContactRequest {
   linkedin_url?: string;
   is_primary?: boolean;
   notes?: string;
+  custom_fields?: Record<string, unknown>;
 }
 
 export interface UpdateContactRequest {
@@ -57,10 +298,33 @@ export interface UpdateContactRequest {
   linkedin_url?: string;
   is_primary?: boolean;
   notes?: string;
+  custom_fields?: Record<string, unknown>;
 }
 
 interface ContactListParams {
   trashed?: boolean;
+  tags?: string;
+}
+
+export interface VCardCardReport {
+  index: number;
+  name: string | null;
+  action: 'create' | 'update' | 'unchanged' | 'conflict';
+  contact_id: string | null;
+  messages: string[];
+}
+
+export interface VCardImportReport {
+  dry_run: boolean;
+  committed: boolean;
+  summary: {
+    total_cards: number;
+    contacts_created: number;
+    contacts_updated: number;
+    unchanged: number;
+    conflicts: number;
+  };
+  cards: VCardCardReport[];
 }
 
 export interface OutreachLog {
@@ -96,6 +360,8 @@ export interface SendContactEmailRequest {
   body_html?: string;
   body_text?: string;
   template?: EmailTemplateKey;
+  /** One of the contact's email identities; defaults to the primary address. */
+  identity_id?: string;
 }
 
 export interface SendContactEmailResponse {
@@ -112,14 +378,52 @@ export interface EmailStatusResponse {
 
 export const api = {
   // Startup methods
-  async getStartups(): Promise<Startup[]> {
-    const res = await fetch(`${API_BASE_URL}/api/startups`, {
+  async getStartupPage(params: StartupListParams = {}): Promise<StartupListResponse> {
+    const query = buildQueryString({
+      ...params,
+      admin_claimed: params.admin_claimed === undefined ? undefined : String(params.admin_claimed),
+      trashed: params.trashed === undefined ? undefined : String(params.trashed),
+      mine: params.mine ? 'true' : undefined,
+      broken_links: params.broken_links ? 'true' : undefined,
+    });
+    const res = await fetch(`${API_BASE_URL}/api/startups${query}`, {
       credentials: 'include',
     });
     if (!res.ok) throw new Error('Failed to fetch startups');
     return res.json();
   },
 
+  async exportStartups(
+    params: Omit<StartupListParams, 'page' | 'page_size'> = {},
+    format: 'csv' | 'xlsx' = 'csv'
+  ): Promise<Blob> {
+    const query = buildQueryString({
+      ...params,
+      admin_claimed: params.admin_claimed === undefined ? undefined : String(params.admin_claimed),
+      trashed: params.trashed === undefined ? undefined : String(params.trashed),
+      mine: params.mine ? 'true' : undefined,
+      broken_links: params.broken_links ? 'true' : undefined,
+      format,
+    });
+    const res = await fetch(`${API_BASE_URL}/api/startups/export${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to export startups');
+    return res.blob();
+  },
+
+  async getStartups(): Promise<Startup[]> {
+    const startups: Startup[] = [];
+    let page = 1;
+    for (;;) {
+      const response = await api.getStartupPage({ page, page_size: 100 });
+      startups.push(...response.results);
+      if (response.results.length === 0 || startups.length >= response.total) break;
+      page += 1;
+    }
+    return startups;
+  },
+
   async getStartup(id: string): Promise<Startup> {
     const res = await fetch(`${API_BASE_URL}/api/startups/${id}`, {
       credentials: 'include',
@@ -150,6 +454,26 @@ export const api = {
     return res.json();
   },
 
+  async transitionStartup(id: string, data: StartupTransitionRequest): Promise<Startup> {
+    const res = await fetch(`${API_BASE_URL}/api/startups/${id}/transition`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(data),
+    });
+    if (res.status === 422) throw new Error('This stage move is not allowed without a reason');
+    if (!res.ok) throw new Error('Failed to move startup');
+    return res.json();
+  },
+
+  async getPipelineStages(): Promise<PipelineStageInfo[]> {
+    const res = await fetch(`${API_BASE_URL}/api/pipeline/stages`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to fetch pipeline stages');
+    return res.json();
+  },
+
   async deleteStartup(id: string): Promise<void> {
     const res = await fetch(`${API_BASE_URL}/api/startups/${id}`, {
       method: 'DELETE',
@@ -158,9 +482,141 @@ export const api = {
     if (!res.ok) throw new Error('Failed to delete startup');
   },
 
+  async getStartupHistory(
+    id: string,
+    params: { entity_type?: 'startup' | 'contact'; page?: number; page_size?: number } = {}
+  ): Promise<StartupHistoryResponse> {
+    const query = buildQueryString(params);
+    const res = await fetch(`${API_BASE_URL}/api/startups/${id}/history${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to fetch startup history');
+    return res.json();
+  },
+
+  async revertFieldRevision(startupId: string, revisionId: string): Promise<void> {
+    const res = await fetch(
+      `${API_BASE_URL}/api/startups/${startupId}/history/${revisionId}/revert`,
+      {
+        method: 'POST',
+        credentials: 'include',
+      }
+    );
+    if (!res.ok) throw new Error('Failed to revert change');
+  },
+
+  async backfillContactFields(): Promise<{ scanned: number; updated: number }> {
+    const res = await fetch(`${API_BASE_URL}/api/admin/startups/backfill-contact-fields`, {
+      method: 'POST',
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to backfill contact fields');
+    return res.json();
+  },
+
+  async getStartupUrlHealth(id: string): Promise<UrlCheck[]> {
+    const res = await fetch(`${API_BASE_URL}/api/startups/${id}/url-health`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load URL health');
+    return res.json();
+  },
+
+  async checkStartupUrls(): Promise<{
+    checked: number;
+    broken: number;
+    newsrooms_down: number;
+    newsrooms_recovered: number;
+  }> {
+    const res = await fetch(`${API_BASE_URL}/api/admin/startups/check-urls`, {
+      method: 'POST',
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to check startup URLs');
+    return res.json();
+  },
+
+  async sendClaimLink(
+    startupId: string,
+    contactId: string,
+    expiresInDays?: number
+  ): Promise<{ claim: NewsroomClaim; claim_url: string }> {
+    const res = await fetch(
+      `${API_BASE_URL}/api/startups/${startupId}/contacts/${contactId}/claim-link`,
+      {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        credentials: 'include',
+        body: JSON.stringify({ expires_in_days: expiresInDays }),
+      }
+    );
+    if (res.status === 409) throw new Error('Startup is already claimed');
+    if (!res.ok) throw new Error('Failed to send claim link');
+    return res.json();
+  },
+
+  async getClaim(token: string): Promise<ClaimPreview> {
+    const res = await fetch(`${API_BASE_URL}/api/claims/${encodeURIComponent(token)}`);
+    if (res.status === 410) throw new Error('This claim link has expired');
+    if (!res.ok) throw new Error('Invalid claim link');
+    return res.json();
+  },
+
+  async acceptClaim(token: string): Promise<ClaimPreview> {
+    const res = await fetch(`${API_BASE_URL}/api/claims/${encodeURIComponent(token)}`, {
+      method: 'POST',
+    });
+    if (res.status === 409) throw new Error('This newsroom has already been claimed');
+    if (res.status === 410) throw new Error('This claim link has expired');
+    if (!res.ok) throw new Error('Failed to claim newsroom');
+    return res.json();
+  },
+
+  async reassignStartupOwner(id: string, ownerId: string | null): Promise<Startup> {
+    const res = await fetch(`${API_BASE_URL}/api/startups/${id}/owner`, {
+      method: 'PUT',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify({ owner_id: ownerId }),
+    });
+    if (!res.ok) throw new Error('Failed to reassign startup');
+    return res.json();
+  },
+
+  async bulkReassignStartupOwner(
+    startupIds: string[],
+    ownerId: string | null
+  ): Promise<BulkReassignOwnerResponse> {
+    const res = await fetch(`${API_BASE_URL}/api/admin/startups/owner`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify({ startup_ids: startupIds, owner_id: ownerId }),
+    });
+    if (!res.ok) throw new Error('Failed to reassign startups');
+    return res.json();
+  },
+
+  async bulkUpdateStartups(
+    startupIds: string[],
+    action: BulkStartupAction
+  ): Promise<BulkStartupResponse> {
+    const res = await fetch(`${API_BASE_URL}/api/startups/bulk`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify({ startup_ids: startupIds, ...action }),
+    });
+    if (!res.ok) throw new Error('Failed to update startups');
+    return res.json();
+  },
+
   // Contact methods
   async getContacts(params?: ContactListParams): Promise<Contact[]> {
-    const query = params?.trashed ? `?trashed=${params.trashed}` : '';
+    const query = buildQueryString({
+      trashed: params?.trashed ? String(params.trashed) : undefined,
+      tags: params?.tags,
+    });
     const res = await fetch(`${API_BASE_URL}/api/contacts${query}`, {
       credentials: 'include',
     });
@@ -169,7 +625,10 @@ export const api = {
   },
 
   async getContactsForStartup(startupId: string, params?: ContactListParams): Promise<Contact[]> {
-    const query = params?.trashed ? `?trashed=${params.trashed}` : '';
+    const query = buildQueryString({
+      trashed: params?.trashed ? String(params.trashed) : undefined,
+      tags: params?.tags,
+    });
     const res = await fetch(`${API_BASE_URL}/api/startups/${startupId}/contacts${query}`, {
       credentials: 'include',
     });
@@ -177,6 +636,39 @@ export const api = {
     return res.json();
   },
 
+  /** Downloads a `.vcf` of the matching contacts, or a startup's contacts. */
+  async exportContactVCards(params?: ContactListParams & { startup_id?: string }): Promise<Blob> {
+    const query = buildQueryString({
+      trashed: params?.trashed ? String(params.trashed) : undefined,
+      tags: params?.tags,
+    });
+    const path = params?.startup_id
+      ? `/api/startups/${params.startup_id}/contacts/vcard`
+      : '/api/contacts/vcard';
+    const res = await fetch(`${API_BASE_URL}${path}${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to export contacts');
+    return res.blob();
+  },
+
+  /** Imports a `.vcf` file's entries; a dry run unless `dryRun` is false. */
+  async importContactVCards(
+    startupId: string,
+    vcard: string,
+    dryRun = true
+  ): Promise<VCardImportReport> {
+    const res = await fetch(`${API_BASE_URL}/api/startups/${startupId}/contacts/vcard`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify({ vcard, dry_run: dryRun }),
+    });
+    if (res.status === 400) throw new Error('The file is not a valid vCard 3.0 or 4.0 file');
+    if (!res.ok) throw new Error('Failed to import contacts');
+    return res.json();
+  },
+
   async createContact(data: CreateContactRequest): Promise<Contact> {
     const res = await fetch(`${API_BASE_URL}/api/startups/${data.startup_id}/contacts`, {
       method: 'POST',
@@ -246,6 +738,101 @@ export const api = {
     if (!res.ok) throw new Error('Failed to delete contacts');
   },
 
+  async getContactPurgePreview(): Promise<ContactPurgePreview> {
+    const res = await fetch(`${API_BASE_URL}/api/admin/contacts/purge`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load purge preview');
+    return res.json();
+  },
+
+  async runContactPurge(): Promise<ContactPurgeRun> {
+    const res = await fetch(`${API_BASE_URL}/api/admin/contacts/purge`, {
+      method: 'POST',
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to purge trashed contacts');
+    return res.json();
+  },
+
+  async getContactPurgeRuns(limit?: number): Promise<ContactPurgeRun[]> {
+    const query = buildQueryString({ limit });
+    const res = await fetch(`${API_BASE_URL}/api/admin/contacts/purge/runs${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load purge runs');
+    return res.json();
+  },
+
+  async getContactIdentities(contactId: string): Promise<ContactIdentity[]> {
+    const res = await fetch(`${API_BASE_URL}/api/contacts/${contactId}/identities`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load contact identities');
+    return res.json();
+  },
+
+  async addContactIdentity(contactId: string, data: ContactIdentityInput): Promise<ContactIdentity> {
+    const res = await fetch(`${API_BASE_URL}/api/contacts/${contactId}/identities`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(data),
+    });
+    if (res.status === 409) throw new Error('The contact already has this identity');
+    if (res.status === 422) throw new Error('Invalid email, phone number or handle');
+    if (!res.ok) throw new Error('Failed to add contact identity');
+    return res.json();
+  },
+
+  async updateContactIdentity(
+    contactId: string,
+    identityId: string,
+    data: Partial<Omit<ContactIdentityInput, 'kind'>>
+  ): Promise<ContactIdentity> {
+    const res = await fetch(`${API_BASE_URL}/api/contacts/${contactId}/identities/${identityId}`, {
+      method: 'PUT',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(data),
+    });
+    if (res.status === 409) throw new Error('The contact already has this identity');
+    if (res.status === 422) throw new Error('Invalid email, phone number or handle');
+    if (!res.ok) throw new Error('Failed to update contact identity');
+    return res.json();
+  },
+
+  async deleteContactIdentity(contactId: string, identityId: string): Promise<void> {
+    const res = await fetch(`${API_BASE_URL}/api/contacts/${contactId}/identities/${identityId}`, {
+      method: 'DELETE',
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to delete contact identity');
+  },
+
+  async getContactDuplicates(params: {
+    startup_id?: string;
+    reason?: DuplicateReason;
+  } = {}): Promise<DuplicateGroup[]> {
+    const query = buildQueryString(params);
+    const res = await fetch(`${API_BASE_URL}/api/admin/contacts/duplicates${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load duplicate contacts');
+    return res.json();
+  },
+
+  async mergeContacts(targetId: string, data: ContactMergeRequest): Promise<ContactMergeResponse> {
+    const res = await fetch(`${API_BASE_URL}/api/admin/contacts/${targetId}/merge`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(data),
+    });
+    if (!res.ok) throw new Error('Failed to merge contacts');
+    return res.json();
+  },
+
   // OutreachLog methods
   async getOutreachForStartup(startupId: string): Promise<OutreachLog[]> {
     const res = await fetch(`${API_BASE_URL}/api/startups/${startupId}/outreach`, {
@@ -294,8 +881,9 @@ export const api = {
     return res.json();
   },
 
-  async getInterviews(): Promise<Interview[]> {
-    const res = await fetch(`${API_BASE_URL}/api/interviews`, {
+  async getInterviews(params: { tags?: string } = {}): Promise<Interview[]> {
+    const query = buildQueryString(params);
+    const res = await fetch(`${API_BASE_URL}/api/interviews${query}`, {
       credentials: 'include',
     });
     if (!res.ok) throw new Error('Failed to fetch interviews');
@@ -495,6 +1083,144 @@ export interface ActivityFeedResponse {
   results: ActivityEvent[];
 }
 
+export type IntroStatus = 'requested' | 'promised' | 'made' | 'declined';
+
+export interface IntroRequest {
+  id: string;
+  startup_id: string;
+  startup_name: string | null;
+  requester_contact_id: string | null;
+  requester_name: string | null;
+  target_contact_id: string | null;
+  target_name: string | null;
+  target_role: string | null;
+  status: IntroStatus;
+  notes: string | null;
+  introduced_email: string | null;
+  conversation_id: string | null;
+  requested_by_id: string | null;
+  outreach_log_ids: string[];
+  requested_at: string;
+  made_at: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface IntroListParams {
+  status?: IntroStatus;
+  startup_id?: string;
+  requester_contact_id?: string;
+}
+
+export interface IntroInput {
+  requester_contact_id: string;
+  target_contact_id?: string;
+  target_role?: string;
+  status?: IntroStatus;
+  notes?: string;
+  outreach_log_ids?: string[];
+}
+
+export interface IntroUpdate {
+  status?: IntroStatus;
+  target_contact_id?: string;
+  target_role?: string;
+  notes?: string;
+  outreach_log_ids?: string[];
+}
+
+export interface Task {
+  id: string;
+  title: string;
+  due_date: string;
+  status: 'open' | 'done';
+  source: string;
+  assignee_id: string | null;
+  assignee_name: string | null;
+  startup_id: string | null;
+  startup_name: string | null;
+  contact_id: string | null;
+  contact_name: string | null;
+  interview_id: string | null;
+  conversation_id: string | null;
+  created_by_id: string | null;
+  completed_at: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface TaskListParams {
+  status?: 'open' | 'done';
+  mine?: boolean;
+  assignee_id?: string;
+  due?: 'today' | 'week';
+  startup_id?: string;
+  contact_id?: string;
+  interview_id?: string;
+  conversation_id?: string;
+}
+
+export interface TaskInput {
+  title: string;
+  due_date: string;
+  assignee_id?: string;
+  startup_id?: string;
+  contact_id?: string;
+  interview_id?: string;
+  conversation_id?: string;
+}
+
+export interface TaskUpdate {
+  title?: string;
+  due_date?: string;
+  assignee_id?: string;
+  status?: 'open' | 'done';
+}
+
+export interface OverdueTaskParams {
+  assignee_id?: string;
+  mine?: boolean;
+}
+
+export interface FunnelTransition {
+  to: string;
+  count: number;
+  rate: number;
+}
+
+export interface FunnelStageMetrics {
+  stage: string;
+  entered: number;
+  advanced: number;
+  dropped_off: number;
+  moved_back: number;
+  still_in_stage: number;
+  conversion_rate: number;
+  median_days: number | null;
+  p90_days: number | null;
+  transitions: FunnelTransition[];
+}
+
+export interface FunnelCohort {
+  cohort: string;
+  startups: number;
+  reached: { stage: string; count: number }[];
+  dropped_off: number;
+}
+
+export interface FunnelResponse {
+  from: string;
+  to: string;
+  stages: FunnelStageMetrics[];
+  cohorts: FunnelCohort[];
+}
+
+export interface FunnelParams {
+  from?: string;
+  to?: string;
+  cohort?: 'week' | 'category';
+}
+
 export interface ActivityFeedParams {
   page?: number;
   page_size?: number;
@@ -629,6 +1355,246 @@ export const activityApi = {
   },
 };
 
+export const taskApi = {
+  async list(params: TaskListParams = {}): Promise<Task[]> {
+    const query = buildQueryString({ ...params, mine: params.mine ? 'true' : undefined });
+    const res = await fetch(`${API_BASE_URL}/api/tasks${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load tasks');
+    return res.json();
+  },
+
+  async get(id: string): Promise<Task> {
+    const res = await fetch(`${API_BASE_URL}/api/tasks/${id}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load task');
+    return res.json();
+  },
+
+  async create(payload: TaskInput): Promise<Task> {
+    const res = await fetch(`${API_BASE_URL}/api/tasks`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(payload),
+    });
+    if (!res.ok) throw new Error('Failed to create task');
+    return res.json();
+  },
+
+  async update(id: string, payload: TaskUpdate): Promise<Task> {
+    const res = await fetch(`${API_BASE_URL}/api/tasks/${id}`, {
+      method: 'PUT',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(payload),
+    });
+    if (!res.ok) throw new Error('Failed to update task');
+    return res.json();
+  },
+
+  async delete(id: string): Promise<void> {
+    const res = await fetch(`${API_BASE_URL}/api/tasks/${id}`, {
+      method: 'DELETE',
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to delete task');
+  },
+
+  async getOverdue(params: OverdueTaskParams = {}): Promise<Task[]> {
+    const query = buildQueryString({
+      assignee_id: params.assignee_id,
+      mine: params.mine ? 'true' : undefined,
+    });
+    const res = await fetch(`${API_BASE_URL}/api/tasks/overdue${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load overdue tasks');
+    return res.json();
+  },
+};
+
+export const introApi = {
+  async list(params: IntroListParams = {}): Promise<IntroRequest[]> {
+    const query = buildQueryString({ ...params });
+    const res = await fetch(`${API_BASE_URL}/api/intros${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load intros');
+    return res.json();
+  },
+
+  async listForStartup(startupId: string, status?: IntroStatus): Promise<IntroRequest[]> {
+    const query = buildQueryString({ status });
+    const res = await fetch(`${API_BASE_URL}/api/startups/${startupId}/intros${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load intros');
+    return res.json();
+  },
+
+  async create(startupId: string, payload: IntroInput): Promise<IntroRequest> {
+    const res = await fetch(`${API_BASE_URL}/api/startups/${startupId}/intros`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(payload),
+    });
+    if (!res.ok) throw new Error('Failed to create intro');
+    return res.json();
+  },
+
+  async update(id: string, payload: IntroUpdate): Promise<IntroRequest> {
+    const res = await fetch(`${API_BASE_URL}/api/intros/${id}`, {
+      method: 'PUT',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(payload),
+    });
+    if (!res.ok) throw new Error('Failed to update intro');
+    return res.json();
+  },
+
+  async delete(id: string): Promise<void> {
+    const res = await fetch(`${API_BASE_URL}/api/intros/${id}`, {
+      method: 'DELETE',
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to delete intro');
+  },
+};
+
+export const analyticsApi = {
+  async getFunnel(params: FunnelParams = {}): Promise<FunnelResponse> {
+    const query = buildQueryString({ ...params });
+    const res = await fetch(`${API_BASE_URL}/api/analytics/funnel${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load funnel analytics');
+    return res.json();
+  },
+};
+
+export type SavedViewEntity = 'startup' | 'contact' | 'conversation' | 'activity';
+
+export type SavedViewFilters = Record<string, string | number | boolean | string[] | null>;
+
+export interface SavedView {
+  id: string;
+  user_id: string;
+  owner_name: string | null;
+  name: string;
+  entity_type: SavedViewEntity;
+  filters: Record<string, string>;
+  is_shared: boolean;
+  can_edit: boolean;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface SavedViewInput {
+  name: string;
+  entity_type: SavedViewEntity;
+  filters: SavedViewFilters;
+  is_shared?: boolean;
+}
+
+export const savedViewApi = {
+  async list(entityType?: SavedViewEntity): Promise<SavedView[]> {
+    const query = buildQueryString({ entity_type: entityType });
+    const res = await fetch(`${API_BASE_URL}/api/views${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to load saved views');
+    return res.json();
+  },
+
+  async create(input: SavedViewInput): Promise<SavedView> {
+    const res = await fetch(`${API_BASE_URL}/api/views`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(input),
+    });
+    if (res.status === 409) throw new Error('A view with this name already exists');
+    if (!res.ok) throw new Error('Failed to save view');
+    return res.json();
+  },
+
+  async update(
+    id: string,
+    input: Partial<Pick<SavedViewInput, 'name' | 'filters' | 'is_shared'>>
+  ): Promise<SavedView> {
+    const res = await fetch(`${API_BASE_URL}/api/views/${id}`, {
+      method: 'PUT',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(input),
+    });
+    if (res.status === 409) throw new Error('A view with this name already exists');
+    if (!res.ok) throw new Error('Failed to update view');
+    return res.json();
+  },
+
+  async delete(id: string): Promise<void> {
+    const res = await fetch(`${API_BASE_URL}/api/views/${id}`, {
+      method: 'DELETE',
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to delete view');
+  },
+
+  /** Runs the view; the response has the shape of the matching list endpoint. */
+  async apply<T = unknown>(
+    id: string,
+    overrides: Record<string, string | number | undefined> = {}
+  ): Promise<T> {
+    const query = buildQueryString(overrides);
+    const res = await fetch(`${API_BASE_URL}/api/views/${id}/apply${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to apply view');
+    return res.json();
+  },
+};
+
+// Search types
+export type SearchKind = 'startup' | 'contact' | 'message' | 'interview' | 'insight';
+
+export interface SearchHit {
+  kind: SearchKind;
+  id: string;
+  title: string;
+  subtitle: string | null;
+  startup_id: string | null;
+  startup_name: string | null;
+  /** Conversation for message hits, interview for insight hits. */
+  parent_id: string | null;
+  /** Matches wrapped in `<mark>`; all other markup is escaped. */
+  highlight: string;
+  rank: number;
+  occurred_at: string | null;
+}
+
+export interface SearchResponse {
+  query: string;
+  total: number;
+  results: SearchHit[];
+}
+
+export const searchApi = {
+  async search(q: string, types?: SearchKind[], limit?: number): Promise<SearchResponse> {
+    const query = buildQueryString({ q, types: types?.join(','), limit });
+    const res = await fetch(`${API_BASE_URL}/api/search${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Search failed');
+    return res.json();
+  },
+};
+
 // Email & Conversation types
 export interface AdminEmailConfig {
   id: string;
@@ -772,9 +1738,14 @@ export interface ConversationListParams {
   has_attachments?: boolean;
   search?: string;
   participant?: string;
+  /** Conversations with any of this contact's email addresses. */
+  contact_id?: string;
   page?: number;
   page_size?: number;
   archived?: boolean;
+  tags?: string;
+  /** Comma-separated pipeline stages of the linked startup. */
+  startup_status?: string;
 }
 
 export const emailApi = {
@@ -927,3 +1898,115 @@ export const emailAdminApi = {
     return res.json();
   },
 };
+
+export type TaggableEntity = 'startup' | 'contact' | 'interview' | 'conversation';
+
+export interface Tag {
+  id: string;
+  name: string;
+  color: string | null;
+  created_at: string;
+}
+
+export interface TagWithUsage extends Tag {
+  usage: {
+    startup: number;
+    contact: number;
+    interview: number;
+    conversation: number;
+    total: number;
+  };
+}
+
+export interface TagListParams {
+  entity_type?: TaggableEntity;
+  from?: string;
+  to?: string;
+}
+
+export interface BulkTagRequest {
+  entity_type: TaggableEntity;
+  entity_ids: string[];
+  tag_ids?: string[];
+  tag_names?: string[];
+}
+
+export interface BulkTagResponse {
+  tags: Tag[];
+  affected: number;
+}
+
+export const tagApi = {
+  async listTags(params: TagListParams = {}): Promise<TagWithUsage[]> {
+    const query = buildQueryString({ ...params });
+    const res = await fetch(`${API_BASE_URL}/api/tags${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to fetch tags');
+    return res.json();
+  },
+
+  async createTag(data: { name: string; color?: string }): Promise<Tag> {
+    const res = await fetch(`${API_BASE_URL}/api/tags`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(data),
+    });
+    if (!res.ok) throw new Error('Failed to create tag');
+    return res.json();
+  },
+
+  async updateTag(id: string, data: { name?: string; color?: string }): Promise<Tag> {
+    const res = await fetch(`${API_BASE_URL}/api/admin/tags/${id}`, {
+      method: 'PUT',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(data),
+    });
+    if (!res.ok) throw new Error('Failed to update tag');
+    return res.json();
+  },
+
+  async deleteTag(id: string): Promise<void> {
+    const res = await fetch(`${API_BASE_URL}/api/admin/tags/${id}`, {
+      method: 'DELETE',
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to delete tag');
+  },
+
+  async assignTags(data: BulkTagRequest): Promise<BulkTagResponse> {
+    const res = await fetch(`${API_BASE_URL}/api/tags/assign`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(data),
+    });
+    if (!res.ok) throw new Error('Failed to assign tags');
+    return res.json();
+  },
+
+  async unassignTags(data: BulkTagRequest): Promise<BulkTagResponse> {
+    const res = await fetch(`${API_BASE_URL}/api/tags/unassign`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      credentials: 'include',
+      body: JSON.stringify(data),
+    });
+    if (!res.ok) throw new Error('Failed to remove tags');
+    return res.json();
+  },
+
+  async getAssignments(
+    entityType: TaggableEntity,
+    entityIds: string[]
+  ): Promise<Record<string, Tag[]>> {
+    const query = buildQueryString({ entity_type: entityType, entity_ids: entityIds.join(',') });
+    const res = await fetch(`${API_BASE_URL}/api/tags/assignments${query}`, {
+      credentials: 'include',
+    });
+    if (!res.ok) throw new Error('Failed to fetch tag assignments');
+    return res.json();
+  },
+};
@@ -1,7 +1,7 @@
 'use client';
 
 import { useQuery, useMutation, useQueryClient, type QueryClient } from '@tanstack/react-query';
-import { activityApi, api, userApi, type ActivityFeedParams, type CreateContactRequest, type CreateInterviewInsightRequest, type CreateInterviewRequest, type CreateOutreachLogRequest, type CreateStartupRequest, type SendContactEmailRequest, type UpdateContactRequest, type WeeklyPlanInput } from '@/lib/api';
+import { activityApi, api, userApi, type ActivityFeedParams, type CreateContactRequest, type CreateInterviewInsightRequest, type CreateInterviewRequest, type CreateOutreachLogRequest, type CreateStartupRequest, type StartupTransitionRequest, type SendContactEmailRequest, type UpdateContactRequest, type WeeklyPlanInput } from '@/lib/api';
 
 function invalidateContactQueries(queryClient: QueryClient, startupId?: string) {
   queryClient.invalidateQueries({ queryKey: ['contacts'] });
@@ -50,6 +50,25 @@ export function useUpdateStartup() {
   });
 }
 
+export function useTransitionStartup() {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ id, data }: { id: string; data: StartupTransitionRequest }) =>
+      api.transitionStartup(id, data),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['startups'] });
+    },
+  });
+}
+
+export function usePipelineStages() {
+  return useQuery({
+    queryKey: ['pipeline-stages'],
+    queryFn: api.getPipelineStages,
+  });
+}
+
 export function useDeleteStartup() {
   const queryClient = useQueryClient();
   
@@ -269,7 +288,7 @@ export function useInterviewsForStartup(startupId: string) {
 export function useInterviews() {
   return useQuery({
     queryKey: ['interviews'],
-    queryFn: api.getInterviews,
+    queryFn: () => api.getInterviews(),
   });
 }
 
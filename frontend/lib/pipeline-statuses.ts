@@ -1,3 +1,5 @@
+import type { PipelineStageInfo } from '@/lib/api';
+
 export const PIPELINE_STATUSES = [
     'Lead',
     'Contacted',
@@ -13,3 +15,26 @@ export const PIPELINE_STATUSES = [
 ] as const;
 
 export type PipelineStatus = typeof PIPELINE_STATUSES[number];
+
+/**
+ * Stages a startup in `from` may move to, per the server's transition graph.
+ * A status the server does not recognise may move anywhere so it can be repaired.
+ */
+export function allowedTransitions(stages: PipelineStageInfo[] | undefined, from: string): string[] {
+    if (!stages) return [];
+    const current = stages.find(stage => stage.stage === from);
+    if (!current) return stages.map(stage => stage.stage);
+    return current.allowed_transitions;
+}
+
+/** Backward moves and moves to "Not a Fit" must carry a reason. */
+export function transitionNeedsReason(
+    stages: PipelineStageInfo[] | undefined,
+    from: string,
+    to: string,
+): boolean {
+    if (to === 'Not a Fit') return true;
+    const fromPosition = stages?.find(stage => stage.stage === from)?.position;
+    const toPosition = stages?.find(stage => stage.stage === to)?.position;
+    return fromPosition !== undefined && toPosition !== undefined && toPosition < fromPosition;
+}